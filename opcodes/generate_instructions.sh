@@ -1,2 +1 @@
-node instr_gen.js instr_dict.json ../src/data/instructions.rs
-
+node instr_gen.js instr_dict.json ../src/data/instructions.rs ../src/data/vector.rs ../src/data/float.rs
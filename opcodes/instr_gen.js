@@ -4,23 +4,172 @@ const valid_masks = new Set([
     "0xfe00707f",
     "0x707f",
     "0x7f",
-    // "0xfc00707f"
+    "0x600007f",
 ]);
 
+// Masks processed only for the listed opcodes, as vector instructions share them.
+const opcode_masks = {
+    // RV64 shift-immediates keep funct6 in the upper bits (the lowest bit of funct7
+    // belongs to the 6-bit shamt)
+    "0xfc00707f": ["0010011", "0011011"],
+    // atomic instructions keep aq and rl bits in funct7 (and lr has rs2 fixed to zero)
+    "0xf800707f": ["0101111"],
+    "0xf9f0707f": ["0101111"],
+};
+
+const isValidMask = (mask, encoding) => {
+    if (mask in opcode_masks) {
+        return opcode_masks[mask].includes(encoding.slice(25));
+    }
+    return valid_masks.has(mask);
+};
+
 const loadData = (file) => {
     const data = readFileSync(file, { encoding: 'utf8', flag: 'r' });
     return JSON.parse(data);
 }
 
+// Vector instructions (OP-V, OP-VE and vector loads/stores) are matched against
+// their whole mask instead of the key, and they are generated to a separate table.
+const VECTOR_OPCODES = ["1010111", "1110111"];
+const VECTOR_MEM_OPCODES = ["0000111", "0100111"];
+const VECTOR_MEM_WIDTHS = ["000", "101", "110", "111"];
+
+const isVector = encoding => {
+    const opcode = encoding.slice(25);
+    return VECTOR_OPCODES.includes(opcode) ||
+        (VECTOR_MEM_OPCODES.includes(opcode) && VECTOR_MEM_WIDTHS.includes(encoding.slice(17, 20)));
+};
+
+// Floating-point instructions are matched against the whole mask as well, as rs2
+// selects the operation of conversions and funct3 holds the rounding mode (rm).
+const FLOAT_OPCODES = ["1000011", "1000111", "1001011", "1001111", "1010011"];
+
+const isFloat = encoding => {
+    const opcode = encoding.slice(25);
+    return FLOAT_OPCODES.includes(opcode) ||
+        (VECTOR_MEM_OPCODES.includes(opcode) && !isVector(encoding));
+};
+
 const processFile = (isa) => {
     return Object.entries(isa)
+        .filter(([_, val]) => !isVector(val.encoding) && !isFloat(val.encoding))
         .map(processEntry)
         .filter(x => !!x);
 };
 
+const processVectorFile = (isa) => {
+    const popcount = mask => parseInt(mask, 16).toString(2).replace(/0/g, "").length;
+    return Object.entries(isa)
+        .filter(([_, val]) => isVector(val.encoding))
+        .map(processVectorEntry)
+        .sort((a, b) => popcount(b.mask) - popcount(a.mask) || a.mnemonic.localeCompare(b.mnemonic));
+};
+
+const processFloatFile = (isa) => {
+    return Object.entries(isa)
+        .filter(([_, val]) => isFloat(val.encoding))
+        .map(([key, val]) => ({
+            mnemonic: key,
+            extension: val.extension.map(e => e.replace(/^.+_/, "")),
+            arch: val.extension.every(e => e.indexOf("rv64") == 0) ? 64 : 32,
+            mask: val.mask,
+            match: val.match,
+            classes: floatClasses(key, val),
+            rm: val.variable_fields.includes("rm"),
+        }))
+        .sort((a, b) => a.mnemonic.localeCompare(b.mnemonic));
+};
+
+const INTEGER_TYPES = ["w", "wu", "l", "lu"];
+
+// Register classes of rd, rs1, rs2 and rs3 ("X" for integer, "F" for floating-point
+// registers, null if the field isn't a register operand)
+const floatClasses = (mnemonic, { encoding, variable_fields: fields }) => {
+    const [op, ...types] = mnemonic.split("_");
+    const isInteger = type => INTEGER_TYPES.includes(type);
+    const classes = { rd: "F", rs1: "F", rs2: "F", rs3: "F" };
+
+    if (VECTOR_MEM_OPCODES.includes(encoding.slice(25))) {
+        // base address of loads and stores
+        classes.rs1 = "X";
+    } else if (op == "fcvt" || op == "fcvtmod") {
+        classes.rd = isInteger(types[0]) ? "X" : "F";
+        classes.rs1 = isInteger(types[1]) ? "X" : "F";
+    } else if ((op == "fmv" || op == "fmvh") && types[0] == "x") {
+        classes.rd = "X";
+    } else if (op == "fmv" || op == "fmvp") {
+        classes.rs1 = "X";
+        classes.rs2 = "X";
+    } else if (["fclass", "feq", "flt", "fle", "fltq", "fleq"].includes(op)) {
+        classes.rd = "X";
+    } else if (op == "fli") {
+        // rs1 selects one of the constants
+        classes.rs1 = null;
+    }
+
+    return ["rd", "rs1", "rs2", "rs3"]
+        .map(field => fields.includes(field) ? classes[field] : null);
+};
+
+const processVectorEntry = ([key, val]) => ({
+    mnemonic: key,
+    extension: val.extension.map(e => e.replace(/^.+_/, "")),
+    arch: val.extension.every(e => e.indexOf("rv64") == 0) ? 64 : 32,
+    mask: val.mask,
+    match: val.match,
+    operands: vectorOperands(key, val),
+});
+
+// Multiply-add operations take the multiplier (vs1/rs1) before vs2
+const MULTIPLY_ADD = /^v(f?w?n?m(acc|sac|add|sub)|wmacc)/;
+
+// The simm5 field of shifts, gathers and slides holds an unsigned value
+const UNSIGNED_IMM = /^v(n?s(ll|rl|ra)|ss(rl|ra)|nclipu?|rgather|slide(up|down))_/;
+
+// Operands in the order of the assembly form, e.g. `vadd.vv vd, vs2, vs1, v0.t`
+const vectorOperands = (mnemonic, { encoding, variable_fields: fields }) => {
+    const opcode = encoding.slice(25);
+    const funct3 = encoding.slice(17, 20);
+    const has = field => fields.includes(field);
+    let operands;
+
+    if (VECTOR_MEM_OPCODES.includes(opcode)) {
+        operands = [opcode == "0000111" ? "Vd" : "Vs3", "Base"];
+        if (has("rs2")) operands.push("Rs2");
+        if (has("vs2")) operands.push("Vs2");
+    } else if (has("zimm11")) {
+        operands = ["Rd", "Rs1", "Vtype"];
+    } else if (has("zimm10")) {
+        operands = ["Rd", "Uimm5", "Vtype"];
+    } else if (has("rs2")) {
+        operands = ["Rd", "Rs1", "Rs2"];
+    } else {
+        // scalar operands of OPFVF are floating-point registers, and so is rd of OPFVV
+        const dest = has("vd") ? "Vd" : (funct3 == "001" ? "Fd" : "Rd");
+        const src = has("vs1") ? "Vs1"
+            : has("rs1") ? (funct3 == "101" ? "Fs1" : "Rs1")
+            : has("simm5") ? (UNSIGNED_IMM.test(mnemonic) ? "Uimm5" : "Simm5")
+            : has("zimm5") ? "Uimm5"
+            : has("zimm6hi") ? "Uimm6"
+            : null;
+        const vs2 = has("vs2") ? "Vs2" : null;
+        const sources = MULTIPLY_ADD.test(mnemonic) ? [src, vs2] : [vs2, src];
+        operands = [dest, ...sources.filter(x => !!x)];
+    }
+
+    if (has("vm")) {
+        operands.push("Mask");
+    } else if (opcode == "1010111" && funct3 != "111" && encoding[6] == "0") {
+        // vm cleared in the encoding selects v0 as carry-in or merge mask
+        operands.push("Carry");
+    }
+    return operands;
+};
+
 const processEntry = ([key, val]) => {
-    const { mask } = val;
-    if (!valid_masks.has(mask)) {
+    const { mask, encoding } = val;
+    if (!isValidMask(mask, encoding)) {
         console.log(`Unprocessed instruction: ${key}`);
         return null;
     }
@@ -28,15 +177,26 @@ const processEntry = ([key, val]) => {
     const res = {
         mnemonic: key,
         extension: val.extension.map(e => e.replace(/^.+_/, "")),
-        arch: val.extension[0].indexOf("rv64") == 0 ? 64 : 32,
+        arch: val.extension.every(e => e.indexOf("rv64") == 0) ? 64 : 32,
         key: parseKey(val.encoding)
     };
 
     return res;
 };
 
+// The key consists of opcode (without the two lowest bits), funct3 and funct7
+// (in this order, starting from the lowest bit). Variable bits count as zeros,
+// so for R4-type instructions funct7 holds the fmt field (funct2) only, and for
+// atomic instructions it holds funct5 (with aq and rl cleared).
+const parseField = (encoding, start, end) => {
+    return parseInt(encoding.slice(start, end).replace(/-/g, "0"), 2);
+};
+
 const parseKey = encoding => {
-    return (parseInt(encoding.replace(/-/g, ""), 2) >> 2);
+    const opcode = parseField(encoding, 25, 30);
+    const funct3 = parseField(encoding, 17, 20);
+    const funct7 = parseField(encoding, 0, 7);
+    return opcode | (funct3 << 5) | (funct7 << 8);
 };
 
 const processDef = def => {
@@ -47,12 +207,79 @@ const processDef = def => {
     return rust;
 }
 
+const processVectorDef = def => {
+    const ext_raw = def.extension[0];
+    const ext = String(ext_raw[0]).toUpperCase() + ext_raw.slice(1);
+    const operands = def.operands.map(op => `VOP::${op}`).join(", ");
+    return `    (${def.mask}, ${def.match}, ("${def.mnemonic}", EXT::${ext}, ${def.arch}, &[${operands}])),`;
+}
+
+const processFloatDef = def => {
+    const ext_raw = def.extension[0];
+    const ext = String(ext_raw[0]).toUpperCase() + ext_raw.slice(1);
+    const classes = def.classes.map(c => c ? `Some(${c})` : "None").join(", ");
+    return `    (${def.mask}, ${def.match}, ("${def.mnemonic}", EXT::${ext}, ${def.arch}, [${classes}], ${def.rm})),`;
+}
+
+const getFloatRustCode = entries => {
+    const header = `
+// this is generated code, don't modify it manually!
+
+use crate::model::RISCVExtension as EXT;
+use crate::model::RegisterClass::{self, Float as F, Integer as X};
+
+/// Register classes of rd, rs1, rs2 and rs3 (\`None\` if not a register operand)
+type Classes = [Option<RegisterClass>; 4];
+
+/// Mnemonic, extension, XLEN, register classes and whether funct3 is the rounding mode
+type Row = (&'static str, EXT, u8, Classes, bool);
+
+/// Floating-point instructions as \`(mask, match, row)\`.
+pub (crate) static FLOAT_INSTRUCTIONS: &[(u32, u32, Row)] = &[
+`;
+
+    const footer = `
+];
+`;
+
+    const lines = entries
+        .map(processFloatDef)
+        .reduce((str, line) => `${str}\n${line}`, "");
+
+    return header + lines + footer;
+}
+
+const getVectorRustCode = entries => {
+    const header = `
+// this is generated code, don't modify it manually!
+
+use crate::model::RISCVExtension as EXT;
+use crate::model::VectorOperand as VOP;
+
+type Row = (&'static str, EXT, u8, &'static [VOP]);
+
+/// Vector instructions as \`(mask, match, row)\`, the most specific masks first.
+pub (crate) static VECTOR_INSTRUCTIONS: &[(u32, u32, Row)] = &[
+`;
+
+    const footer = `
+];
+`;
+
+    const lines = entries
+        .map(processVectorDef)
+        .reduce((str, line) => `${str}\n${line}`, "");
+
+    return header + lines + footer;
+}
+
 const getRustCode = entries => {
     const header = `
 // this is generated code, don't modify it manually!
 
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+
 use crate::model::RISCVExtension as EXT;
 
 type Row = (&'static str, EXT, u8);
@@ -76,8 +303,8 @@ pub (crate) static INSTRUCTIONS: Lazy<HashMap<u16, Row>> = Lazy::new(|| {
 
 try {
 
-    if (process.argv.length != 4) {
-        console.log("node <script> <input_file> <output_file>");
+    if (process.argv.length != 6) {
+        console.log("node <script> <input_file> <output_file> <vector_output_file> <float_output_file>");
         process.exit(1);
     }
 
@@ -87,6 +314,12 @@ try {
 
     writeFileSync(process.argv[3], rust);
 
+    const vectorEntries = processVectorFile(data);
+    writeFileSync(process.argv[4], getVectorRustCode(vectorEntries));
+
+    const floatEntries = processFloatFile(data);
+    writeFileSync(process.argv[5], getFloatRustCode(floatEntries));
+
 } catch(e) {
     console.error("ERROR", e);
 }
@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+
+use super::{
+    parser::{check_arity, Arg, Cursor, RawOperand},
+    program::ProgramSection,
+    AsmParser, AsmSymbol, ParseError, ParseErrorKind, Program, SectionKind,
+};
+use crate::{
+    disasm::{Address, Relocation},
+    error::RISCVError,
+    model::{Csr, FRegister, Register, RoundingMode, Xlen},
+};
+
+/// `addi zero, zero, 0`, padding the code aligned with `.align`
+const NOP: u32 = 0x00000013;
+
+/// Alignment of the code, unless a larger one is requested with `.align`
+const TEXT_ALIGNMENT: Address = 4;
+
+/// Alignment of the data placed after the code, unless a larger one is requested with `.align`
+const DATA_ALIGNMENT: Address = 8;
+
+/// The largest power of two accepted by `.align`
+const MAX_ALIGN: i64 = 12;
+
+/// Relocation of the labels placed with `.word`, so they can be moved by a linker
+const WORD_RELOCATION: &str = "R_RISCV_32";
+
+/// A statement laid out by the first pass and encoded by the second one.
+enum Item {
+    Instruction {
+        name: String,
+        column: usize,
+        args: Vec<Arg>,
+        end: usize,
+    },
+    /// Values of `.byte`, `.half` or `.word`, of the size in bytes
+    Data { size: usize, args: Vec<Arg> },
+    /// Padding of `.align`, of the size in bytes
+    Padding(usize),
+}
+
+struct Statement {
+    line: usize,
+    section: SectionKind,
+    offset: usize,
+    item: Item,
+}
+
+/// The result of the first pass: statements placed in the sections, and the symbols,
+/// whose labels are offsets in their sections.
+struct Layout {
+    section: SectionKind,
+    statements: Vec<Statement>,
+    symbols: Vec<AsmSymbol>,
+    index: HashMap<String, usize>,
+    /// Symbols declared with `.globl`, with their line and column
+    globals: Vec<(String, usize, usize)>,
+    text_size: usize,
+    data_size: usize,
+    text_alignment: Address,
+    data_alignment: Address,
+}
+
+impl Layout {
+    fn new() -> Self {
+        Self {
+            section: SectionKind::Text,
+            statements: Vec::new(),
+            symbols: Vec::new(),
+            index: HashMap::new(),
+            globals: Vec::new(),
+            text_size: 0,
+            data_size: 0,
+            text_alignment: TEXT_ALIGNMENT,
+            data_alignment: DATA_ALIGNMENT,
+        }
+    }
+
+    fn size(&mut self) -> &mut usize {
+        match self.section {
+            SectionKind::Text => &mut self.text_size,
+            SectionKind::Data => &mut self.data_size,
+        }
+    }
+
+    /// Places an item of the size at the end of the current section.
+    fn push(&mut self, line: usize, size: usize, item: Item) {
+        let offset = *self.size();
+        self.statements.push(Statement {
+            line,
+            section: self.section,
+            offset,
+            item,
+        });
+        *self.size() += size;
+    }
+
+    fn define(&mut self, symbol: AsmSymbol, column: usize) -> Result<(), ParseError> {
+        if self.index.contains_key(&symbol.name) {
+            return Err(ParseError::new(
+                column,
+                ParseErrorKind::DuplicateSymbol(symbol.name),
+            ));
+        }
+        self.index.insert(symbol.name.clone(), self.symbols.len());
+        self.symbols.push(symbol);
+        Ok(())
+    }
+
+    /// Lays out a line of the source: its labels, followed by an instruction or a directive.
+    fn line(&mut self, line: usize, source: &str) -> Result<(), ParseError> {
+        let mut cursor = Cursor::new(source)?;
+        while let Some((name, column)) = cursor.label() {
+            let label = AsmSymbol {
+                name,
+                value: *self.size() as i64,
+                section: Some(self.section),
+                global: false,
+            };
+            self.define(label, column)?;
+        }
+        if cursor.is_empty() {
+            return Ok(());
+        }
+
+        let (name, column) = cursor.ident("instruction")?;
+        let args = cursor.operands()?;
+        let end = cursor.end();
+        if name.starts_with('.') {
+            return self.directive(line, name, column, args, end);
+        }
+        let item = Item::Instruction {
+            name,
+            column,
+            args,
+            end,
+        };
+        self.push(line, 4, item);
+        Ok(())
+    }
+
+    fn directive(
+        &mut self,
+        line: usize,
+        name: String,
+        column: usize,
+        args: Vec<Arg>,
+        end: usize,
+    ) -> Result<(), ParseError> {
+        match name.to_lowercase().as_str() {
+            ".text" => {
+                check_arity(&args, 0, 0, "operand", end)?;
+                self.section = SectionKind::Text;
+            }
+            ".data" => {
+                check_arity(&args, 0, 0, "operand", end)?;
+                self.section = SectionKind::Data;
+            }
+            ".globl" | ".global" => {
+                check_arity(&args, 1, usize::MAX, "symbol", end)?;
+                for arg in args {
+                    let name = arg.ident("symbol")?.to_string();
+                    self.globals.push((name, line, arg.column));
+                }
+            }
+            ".equ" => {
+                check_arity(&args, 2, 2, "operand", end)?;
+                let constant = AsmSymbol {
+                    name: args[0].ident("symbol")?.to_string(),
+                    value: self.constant(&args[1])?,
+                    section: None,
+                    global: false,
+                };
+                self.define(constant, args[0].column)?;
+            }
+            directive @ (".byte" | ".half" | ".word") => {
+                check_arity(&args, 1, usize::MAX, "value", end)?;
+                let size = match directive {
+                    ".byte" => 1,
+                    ".half" => 2,
+                    _ => 4,
+                };
+                self.push(line, size * args.len(), Item::Data { size, args });
+            }
+            ".align" => {
+                check_arity(&args, 1, 1, "immediate", end)?;
+                let power = self.constant(&args[0])?;
+                if !(0..=MAX_ALIGN).contains(&power) {
+                    let error = RISCVError::ImmediateOutOfRange(0, MAX_ALIGN as i32);
+                    return Err(args[0].invalid(error));
+                }
+                let alignment = 1 << power;
+                let padding = (alignment - *self.size() % alignment) % alignment;
+                let section_alignment = match self.section {
+                    SectionKind::Text => &mut self.text_alignment,
+                    SectionKind::Data => &mut self.data_alignment,
+                };
+                *section_alignment = (*section_alignment).max(alignment as Address);
+                self.push(line, padding, Item::Padding(padding));
+            }
+            _ => {
+                return Err(ParseError::new(
+                    column,
+                    ParseErrorKind::UnknownDirective(name),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the value of a number, or of a constant defined earlier in the source.
+    fn constant(&self, arg: &Arg) -> Result<i64, ParseError> {
+        let RawOperand::Ident(name) = &arg.operand else {
+            return arg.number();
+        };
+        match self.index.get(name).map(|i| &self.symbols[*i]) {
+            Some(AsmSymbol {
+                section: None,
+                value,
+                ..
+            }) => Ok(*value),
+            Some(_) => Err(arg.expected("constant")),
+            None => Err(ParseError::new(
+                arg.column,
+                ParseErrorKind::UndefinedSymbol(name.clone()),
+            )),
+        }
+    }
+}
+
+/// A two-pass assembler of programs with labels and directives, producing the contents
+/// of the `.text` and `.data` sections.
+///
+/// The first pass places the instructions and data in the sections and assigns addresses
+/// to labels, so the second one can encode instructions (with [`AsmParser`]) referring
+/// to labels defined later in the source. The targets of branches and jumps are given
+/// as labels (or as offsets relative to the instruction), and must be in range of the
+/// instruction's immediate. Names used as operands that aren't registers, CSRs
+/// or rounding modes are symbols.
+///
+/// Supported directives:
+/// - `.text` and `.data` switch the section the following statements are placed in,
+/// - `.byte`, `.half` and `.word` place values (numbers or symbols) of 1, 2 and 4 bytes,
+/// - `.align n` aligns the next statement to 2<sup>n</sup> bytes (padding code with `nop`s),
+/// - `.globl` (or `.global`) declares global symbols,
+/// - `.equ name, value` defines a constant.
+///
+/// # Example
+///
+/// ```
+/// # use std::io::Cursor;
+/// # use riscv_isa::{Assembler, Disasm, DisasmConfig, ParseError, Xlen};
+/// # fn test() -> Result<(), ParseError> {
+/// let source = "
+///     .equ COUNT, 10
+///     .globl _start
+/// _start:
+///     li a0, COUNT
+/// loop:
+///     addi a0, a0, -1
+///     bnez a0, loop
+///     ret
+/// ";
+/// let program = Assembler::new(Xlen::Rv32)
+///     .with_text_address(0x10000)
+///     .assemble(source)?;
+/// assert_eq!(16, program.text().len());
+///
+/// let config = DisasmConfig::with_address(program.text_address());
+/// let disasm = Disasm::with_config(Cursor::new(program.to_bytes()), config);
+/// assert_eq!(4, disasm.count());
+/// # Ok(())
+/// # }
+/// # test().unwrap();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Assembler {
+    parser: AsmParser,
+    text_address: Address,
+    data_address: Option<Address>,
+}
+
+impl Assembler {
+    pub fn new(xlen: Xlen) -> Self {
+        Self {
+            parser: AsmParser::new(xlen),
+            ..Default::default()
+        }
+    }
+
+    /// Places the code at the address (0 by default).
+    pub fn with_text_address(self, address: Address) -> Self {
+        Self {
+            text_address: address,
+            ..self
+        }
+    }
+
+    /// Places the data at the address, instead of following the code.
+    pub fn with_data_address(self, address: Address) -> Self {
+        Self {
+            data_address: Some(address),
+            ..self
+        }
+    }
+
+    pub fn assemble(&self, source: &str) -> Result<Program, ParseError> {
+        let mut layout = Layout::new();
+        for (i, line) in source.lines().enumerate() {
+            layout.line(i + 1, line).map_err(|e| e.at_line(i + 1))?;
+        }
+
+        let text_address = self.text_address;
+        let data_address = self.data_address.unwrap_or_else(|| {
+            let end = text_address + layout.text_size as Address;
+            end.next_multiple_of(layout.data_alignment)
+        });
+        let base = |section| match section {
+            SectionKind::Text => text_address,
+            SectionKind::Data => data_address,
+        };
+
+        let mut symbols = layout.symbols;
+        for symbol in symbols.iter_mut() {
+            if let Some(section) = symbol.section {
+                symbol.value += base(section) as i64;
+            }
+        }
+        for (name, line, column) in layout.globals {
+            let symbol = layout.index.get(&name).map(|i| &mut symbols[*i]);
+            let Some(symbol) = symbol else {
+                let kind = ParseErrorKind::UndefinedSymbol(name);
+                return Err(ParseError::new(column, kind).at_line(line));
+            };
+            symbol.global = true;
+        }
+
+        let index: HashMap<&str, &AsmSymbol> =
+            symbols.iter().map(|s| (s.name.as_str(), s)).collect();
+        let mut text = vec![0; layout.text_size];
+        let mut data = vec![0; layout.data_size];
+        let mut relocations = Vec::new();
+        for statement in layout.statements {
+            let address = base(statement.section) + statement.offset as Address;
+            let bytes = match statement.section {
+                SectionKind::Text => &mut text[statement.offset..],
+                SectionKind::Data => &mut data[statement.offset..],
+            };
+            let item_relocations = self
+                .encode(statement.item, statement.section, address, &index, bytes)
+                .map_err(|e| e.at_line(statement.line))?;
+            relocations.extend(item_relocations);
+        }
+
+        Ok(Program::new(
+            self.parser.xlen(),
+            ProgramSection::new(text, text_address, layout.text_alignment),
+            ProgramSection::new(data, data_address, layout.data_alignment),
+            symbols,
+        )
+        .with_relocations(relocations))
+    }
+
+    /// Writes the bytes of the item placed at the address, returning the relocations
+    /// of the labels it refers to.
+    fn encode(
+        &self,
+        item: Item,
+        section: SectionKind,
+        address: Address,
+        symbols: &HashMap<&str, &AsmSymbol>,
+        bytes: &mut [u8],
+    ) -> Result<Vec<Relocation>, ParseError> {
+        let mut relocations = Vec::new();
+        match item {
+            Item::Instruction {
+                name,
+                column,
+                args,
+                end,
+            } => {
+                let args = args
+                    .into_iter()
+                    .map(|arg| resolve(arg, symbols))
+                    .collect::<Result<Vec<Arg>, ParseError>>()?;
+                let instr = self.parser.instruction(&name, column, args, end, address)?;
+                bytes[..4].copy_from_slice(&u32::from(instr).to_le_bytes());
+            }
+            Item::Data { size, args } => {
+                let bits = size as u32 * 8;
+                for (i, (arg, chunk)) in args.into_iter().zip(bytes.chunks_mut(size)).enumerate() {
+                    let arg = resolve(arg, symbols)?;
+                    let value = arg.number()?;
+                    if value < -(1 << (bits - 1)) || value >= 1 << bits {
+                        let kind = ParseErrorKind::ValueOutOfRange { value, bits };
+                        return Err(ParseError::new(arg.column, kind));
+                    }
+                    chunk.copy_from_slice(&value.to_le_bytes()[..size]);
+
+                    if let RawOperand::Symbol(name, _) = &arg.operand {
+                        if size == 4 && symbols[name.as_str()].section.is_some() {
+                            let address = address + (i * size) as Address;
+                            relocations.push(Relocation::new(address, WORD_RELOCATION, name, 0));
+                        }
+                    }
+                }
+            }
+            Item::Padding(size)
+                if section == SectionKind::Text
+                    && address.is_multiple_of(4)
+                    && size.is_multiple_of(4) =>
+            {
+                for chunk in bytes[..size].chunks_mut(4) {
+                    chunk.copy_from_slice(&NOP.to_le_bytes());
+                }
+            }
+            Item::Padding(_) => {}
+        }
+        Ok(relocations)
+    }
+}
+
+/// Replaces a name that isn't a register, CSR or rounding mode with the symbol's value.
+fn resolve(arg: Arg, symbols: &HashMap<&str, &AsmSymbol>) -> Result<Arg, ParseError> {
+    let RawOperand::Ident(name) = &arg.operand else {
+        return Ok(arg);
+    };
+    if Register::from_name(name).is_some()
+        || FRegister::from_name(name).is_some()
+        || Csr::from_name(name).is_some()
+        || RoundingMode::from_name(name).is_some()
+    {
+        return Ok(arg);
+    }
+    match symbols.get(name.as_str()) {
+        Some(symbol) => Ok(Arg::new(
+            RawOperand::Symbol(name.clone(), symbol.value),
+            arg.column,
+        )),
+        None => Err(ParseError::new(
+            arg.column,
+            ParseErrorKind::UndefinedSymbol(name.clone()),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        disasm::{Disasm, DisasmConfig, InstructionFormatter},
+        instr::Instruction,
+    };
+    use std::io::Cursor;
+
+    fn words(bytes: &[u8]) -> Vec<u32> {
+        bytes
+            .chunks(4)
+            .map(|w| u32::from_le_bytes(w.try_into().unwrap()))
+            .collect()
+    }
+
+    fn error(source: &str) -> (usize, usize, String) {
+        let e = Assembler::default().assemble(source).unwrap_err();
+        (e.line, e.column, e.kind.to_string())
+    }
+
+    #[test]
+    fn test_labels() {
+        let source = "
+            .equ COUNT, 3
+            j start         # forward reference
+        loop:
+            addi a0, a0, -1
+            bnez a0, loop
+            ret
+        start: li a0, COUNT
+            jal zero, loop
+        ";
+        let program = Assembler::default().assemble(source).unwrap();
+        let parse = |line: &str| u32::from(line.parse::<Instruction>().unwrap());
+        assert_eq!(
+            vec![
+                parse("jal zero, 16"),
+                parse("addi a0, a0, -1"),
+                parse("bne a0, zero, -4"),
+                parse("jalr zero, 0(ra)"),
+                parse("addi a0, zero, 3"),
+                parse("jal zero, -16"),
+            ],
+            words(program.text())
+        );
+        assert_eq!(Some(4), program.symbol("loop").map(|s| s.value));
+        assert_eq!(Some(16), program.symbol("start").map(|s| s.value));
+        assert_eq!(Some(None), program.symbol("COUNT").map(|s| s.section));
+    }
+
+    #[test]
+    fn test_directives() {
+        let source = "
+            .globl _start, table
+            .text
+        _start:
+            nop
+            .align 4
+            ret
+            .data
+        bytes: .byte 1, -1, 0xff
+            .align 2
+        table:
+            .half 0x1234
+            .word _start, bytes
+        ";
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x1000)
+            .assemble(source)
+            .unwrap();
+        assert_eq!(
+            vec![0x00000013, 0x00000013, 0x00000013, 0x00000013, 0x00008067],
+            words(program.text())
+        );
+        assert_eq!(0x1018, program.data_address());
+        assert_eq!(
+            vec![
+                0x01, 0xff, 0xff, 0x00, 0x34, 0x12, 0x00, 0x10, 0x00, 0x00, 0x18, 0x10, 0x00, 0x00
+            ],
+            program.data()
+        );
+        assert_eq!(0x1000, program.entry());
+        assert_eq!(
+            vec![
+                Relocation::new(0x101e, "R_RISCV_32", "_start", 0),
+                Relocation::new(0x1022, "R_RISCV_32", "bytes", 0),
+            ],
+            program.relocations()
+        );
+        assert_eq!(
+            vec![("_start", true), ("bytes", false), ("table", true)],
+            program
+                .symbols()
+                .iter()
+                .map(|s| (s.name.as_str(), s.global))
+                .collect::<Vec<_>>()
+        );
+
+        let image = program.to_bytes();
+        assert_eq!(0x18 + 14, image.len());
+        assert_eq!(program.data(), &image[0x18..]);
+
+        let program = Assembler::default()
+            .with_data_address(0x100)
+            .assemble(".data\n.word 7\n.text\nnop")
+            .unwrap();
+        assert_eq!(0x104, program.to_bytes().len());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let source = "
+        main:
+            addi sp, sp, -16
+            sw ra, 12(sp)
+            beqz a0, done
+            jal ra, main
+        done:
+            lw ra, 12(sp)
+            addi sp, sp, 16
+            ret
+        ";
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x10000)
+            .assemble(source)
+            .unwrap();
+
+        let config = DisasmConfig::with_address(program.text_address());
+        let formatter = InstructionFormatter::new(config.clone());
+        let lines: Vec<String> = Disasm::with_config(Cursor::new(program.to_bytes()), config)
+            .map(|r| formatter.record(&r.unwrap()))
+            .collect();
+        assert_eq!(
+            vec![
+                "0x00010000:   addi sp, sp, 0xfffffff0",
+                "0x00010004:   sw ra, 0xc(sp)",
+                "0x00010008:   beqz a0, 0x10010",
+                "0x0001000c:   jal 0x10000",
+                "0x00010010:   lw ra, sp, 0xc",
+                "0x00010014:   addi sp, sp, 0x10",
+                "0x00010018:   ret",
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            (2, 10, "undefined symbol `done`".into()),
+            error("nop\nbeqz a0, done")
+        );
+        assert_eq!(
+            (3, 1, "symbol `a` is already defined".into()),
+            error("a: nop\n\na: nop")
+        );
+        assert_eq!(
+            (
+                1,
+                13,
+                "target `far` is out of range of the instruction (offset 4096)".into()
+            ),
+            error("beq a0, a1, far\n.align 12\nfar: ret")
+        );
+        assert_eq!((1, 1, "unknown directive `.bss`".into()), error(".bss"));
+        assert_eq!(
+            (1, 10, "value 256 doesn't fit in 8 bits".into()),
+            error(".byte 1, 256")
+        );
+        assert_eq!(
+            (1, 8, "undefined symbol `main`".into()),
+            error(".globl main")
+        );
+        assert_eq!(
+            (2, 8, "expected constant, found `a`".into()),
+            error("a: nop\n.align a")
+        );
+        assert_eq!(
+            (1, 12, "expected register, found `loop`".into()),
+            error("loop: addi loop, a0, 1")
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::fmt;
+
+use super::ParseError;
+
+/// A token of a line of assembly.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Token {
+    /// Mnemonic, register, CSR or any other name, which may include dots (e.g. `fence.i`)
+    Ident(String),
+    Number(i64),
+    Comma,
+    /// Ends a label, e.g. `loop:`
+    Colon,
+    LParen,
+    RParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Ident(name) => write!(f, "`{}`", name),
+            Token::Number(n) => write!(f, "`{}`", n),
+            Token::Comma => write!(f, "`,`"),
+            Token::Colon => write!(f, "`:`"),
+            Token::LParen => write!(f, "`(`"),
+            Token::RParen => write!(f, "`)`"),
+        }
+    }
+}
+
+/// A token with its (1-based) column.
+pub(crate) type Spanned = (Token, usize);
+
+/// Splits a line into tokens, skipping whitespace and the comment starting with `#`.
+pub(crate) fn tokenize(line: &str) -> Result<Vec<Spanned>, ParseError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        let next_is_digit = chars.get(i + 1).is_some_and(char::is_ascii_digit);
+        match c {
+            '#' => break,
+            c if c.is_whitespace() => i += 1,
+            ',' | ':' | '(' | ')' => {
+                tokens.push((
+                    match c {
+                        ',' => Token::Comma,
+                        ':' => Token::Colon,
+                        '(' => Token::LParen,
+                        _ => Token::RParen,
+                    },
+                    column,
+                ));
+                i += 1;
+            }
+            c if c.is_ascii_digit() || ((c == '-' || c == '+') && next_is_digit) => {
+                let len = word_len(&chars[i + 1..]) + 1;
+                let word: String = chars[i..i + len].iter().collect();
+                let number = parse_number(&word)
+                    .ok_or_else(|| ParseError::expected(column, "number", format!("`{}`", word)))?;
+                tokens.push((Token::Number(number), column));
+                i += len;
+            }
+            c if is_word_char(c) => {
+                let len = word_len(&chars[i..]);
+                tokens.push((Token::Ident(chars[i..i + len].iter().collect()), column));
+                i += len;
+            }
+            c => return Err(ParseError::expected(column, "token", format!("`{}`", c))),
+        }
+    }
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '$'
+}
+
+fn word_len(chars: &[char]) -> usize {
+    chars.iter().take_while(|c| is_word_char(**c)).count()
+}
+
+/// Parses a decimal, hexadecimal (`0x`), binary (`0b`) or octal (`0o`) number,
+/// optionally preceded by a sign.
+pub(crate) fn parse_number(word: &str) -> Option<i64> {
+    let (negative, digits) = match word.as_bytes().first()? {
+        b'-' => (true, &word[1..]),
+        b'+' => (false, &word[1..]),
+        _ => (false, word),
+    };
+    let lower = digits.to_lowercase();
+    let (radix, digits) = match lower.get(..2) {
+        Some("0x") => (16, &lower[2..]),
+        Some("0b") => (2, &lower[2..]),
+        Some("0o") => (8, &lower[2..]),
+        _ => (10, lower.as_str()),
+    };
+    let value = i64::try_from(u64::from_str_radix(digits, radix).ok()?).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens = tokenize("sw ra, -12(sp) # save").unwrap();
+        assert_eq!(
+            vec![
+                (Token::Ident("sw".into()), 1),
+                (Token::Ident("ra".into()), 4),
+                (Token::Comma, 6),
+                (Token::Number(-12), 8),
+                (Token::LParen, 11),
+                (Token::Ident("sp".into()), 12),
+                (Token::RParen, 14),
+            ],
+            tokens
+        );
+        assert_eq!(
+            vec![
+                (Token::Ident("loop".into()), 1),
+                (Token::Colon, 5),
+                (Token::Ident("j".into()), 7),
+                (Token::Ident("loop".into()), 9),
+            ],
+            tokenize("loop: j loop").unwrap()
+        );
+        assert_eq!(
+            Err(ParseError::expected(8, "number", "`12a`")),
+            tokenize("li a0, 12a")
+        );
+        assert_eq!(
+            Err(ParseError::expected(7, "token", "`*`")),
+            tokenize("li a0 * 2")
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(Some(255), parse_number("0xFF"));
+        assert_eq!(Some(-5), parse_number("-0b101"));
+        assert_eq!(Some(8), parse_number("+0o10"));
+        assert_eq!(Some(10), parse_number("10"));
+        assert_eq!(None, parse_number("0x"));
+        assert_eq!(None, parse_number("0xffffffffffffffffff"));
+    }
+}
@@ -0,0 +1,10 @@
+mod assembler;
+mod lexer;
+mod parse_error;
+mod parser;
+mod program;
+
+pub use assembler::*;
+pub use parse_error::*;
+pub use parser::*;
+pub use program::*;
@@ -0,0 +1,67 @@
+use thiserror::Error;
+
+use crate::error::RISCVError;
+
+/// What went wrong while parsing a line of assembly.
+#[derive(Debug, PartialEq, Error)]
+pub enum ParseErrorKind {
+    #[error("expected {expected}, found {found}")]
+    Expected { expected: String, found: String },
+
+    #[error("unknown or unsupported instruction `{0}`")]
+    UnknownMnemonic(String),
+
+    #[error("{0}")]
+    InvalidOperand(RISCVError),
+
+    #[error("unknown directive `{0}`")]
+    UnknownDirective(String),
+
+    #[error("undefined symbol `{0}`")]
+    UndefinedSymbol(String),
+
+    #[error("symbol `{0}` is already defined")]
+    DuplicateSymbol(String),
+
+    #[error("target `{symbol}` is out of range of the instruction (offset {offset})")]
+    TargetOutOfRange { symbol: String, offset: i64 },
+
+    #[error("value {value} doesn't fit in {bits} bits")]
+    ValueOutOfRange { value: i64, bits: u32 },
+}
+
+/// An error of the assembly parser, with the position (1-based line and column)
+/// of the offending token.
+#[derive(Debug, PartialEq, Error)]
+#[error("{line}:{column}: {kind}")]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    pub fn new(column: usize, kind: ParseErrorKind) -> Self {
+        Self {
+            line: 1,
+            column,
+            kind,
+        }
+    }
+
+    /// Creates an error of an unexpected token, e.g. `expected register, found `x32``.
+    pub fn expected(column: usize, expected: impl Into<String>, found: impl Into<String>) -> Self {
+        Self::new(
+            column,
+            ParseErrorKind::Expected {
+                expected: expected.into(),
+                found: found.into(),
+            },
+        )
+    }
+
+    /// Moves the error to the given line of a multi-line source.
+    pub fn at_line(self, line: usize) -> Self {
+        Self { line, ..self }
+    }
+}
@@ -0,0 +1,887 @@
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    lexer::{parse_number, tokenize, Spanned, Token},
+    ParseError, ParseErrorKind,
+};
+use crate::{
+    data::{
+        find_float_instr_from_mnemonic, find_float_register_classes, find_instr_from_mnemonic,
+        find_mnemonic_from_name, find_system_instr_from_mnemonic,
+    },
+    disasm::Address,
+    error::RISCVError,
+    instr::{IInstruction, Instruction, InstructionBuilder, AMO_OPCODE},
+    model::{
+        Csr, FRegister, Immediate, InstructionFormat, Mnemonic, Register, RegisterClass,
+        RoundingMode, Xlen,
+    },
+};
+
+const LOAD: u8 = 0b0000011;
+const LOAD_FP: u8 = 0b0000111;
+const STORE_FP: u8 = 0b0100111;
+const JALR: u8 = 0b1100111;
+const SYSTEM: u8 = 0b1110011;
+const MISC_MEM: u8 = 0b0001111;
+
+/// Pseudo-instructions standing for a single instruction, as `(name, instruction, operands)`,
+/// where `$n` is the n-th operand of the pseudo-instruction.
+#[rustfmt::skip]
+const PSEUDO_INSTRUCTIONS: &[(&str, &str, &[&str])] = &[
+    ("nop", "addi", &["zero", "zero", "0"]),
+    ("li", "addi", &["$0", "zero", "$1"]),
+    ("mv", "addi", &["$0", "$1", "0"]),
+    ("not", "xori", &["$0", "$1", "-1"]),
+    ("neg", "sub", &["$0", "zero", "$1"]),
+    ("negw", "subw", &["$0", "zero", "$1"]),
+    ("sext.w", "addiw", &["$0", "$1", "0"]),
+    ("seqz", "sltiu", &["$0", "$1", "1"]),
+    ("snez", "sltu", &["$0", "zero", "$1"]),
+    ("sltz", "slt", &["$0", "$1", "zero"]),
+    ("sgtz", "slt", &["$0", "zero", "$1"]),
+    ("beqz", "beq", &["$0", "zero", "$1"]),
+    ("bnez", "bne", &["$0", "zero", "$1"]),
+    ("blez", "bge", &["zero", "$0", "$1"]),
+    ("bgez", "bge", &["$0", "zero", "$1"]),
+    ("bltz", "blt", &["$0", "zero", "$1"]),
+    ("bgtz", "blt", &["zero", "$0", "$1"]),
+    ("bgt", "blt", &["$1", "$0", "$2"]),
+    ("ble", "bge", &["$1", "$0", "$2"]),
+    ("bgtu", "bltu", &["$1", "$0", "$2"]),
+    ("bleu", "bgeu", &["$1", "$0", "$2"]),
+    ("j", "jal", &["zero", "$0"]),
+    ("jal", "jal", &["ra", "$0"]),
+    ("jr", "jalr", &["zero", "0($0)"]),
+    ("jalr", "jalr", &["ra", "0($0)"]),
+    ("jalr", "jalr", &["$0", "$2($1)"]),
+    ("ret", "jalr", &["zero", "0(ra)"]),
+    ("csrr", "csrrs", &["$0", "$1", "zero"]),
+    ("csrw", "csrrw", &["zero", "$0", "$1"]),
+    ("csrs", "csrrs", &["zero", "$0", "$1"]),
+    ("csrc", "csrrc", &["zero", "$0", "$1"]),
+    ("csrwi", "csrrwi", &["zero", "$0", "$1"]),
+    ("csrsi", "csrrsi", &["zero", "$0", "$1"]),
+    ("csrci", "csrrci", &["zero", "$0", "$1"]),
+    ("fmv.s", "fsgnj.s", &["$0", "$1", "$1"]),
+    ("fneg.s", "fsgnjn.s", &["$0", "$1", "$1"]),
+    ("fabs.s", "fsgnjx.s", &["$0", "$1", "$1"]),
+    ("fmv.d", "fsgnj.d", &["$0", "$1", "$1"]),
+    ("fneg.d", "fsgnjn.d", &["$0", "$1", "$1"]),
+    ("fabs.d", "fsgnjx.d", &["$0", "$1", "$1"]),
+];
+
+/// An operand as written in assembly, before it's matched with the operands
+/// of the instruction.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum RawOperand {
+    /// A register, CSR, rounding mode or any other name
+    Ident(String),
+    Number(i64),
+    /// `offset(base)`, with the offset omitted in `(base)`, and the column of the base
+    Memory(Option<i64>, String, usize),
+    /// A label or constant, resolved by the assembler to its value
+    Symbol(String, i64),
+}
+
+/// An operand with its (1-based) column.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct Arg {
+    pub operand: RawOperand,
+    pub column: usize,
+}
+
+impl fmt::Display for Arg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.operand {
+            RawOperand::Ident(name) => write!(f, "`{}`", name),
+            RawOperand::Number(n) => write!(f, "`{}`", n),
+            RawOperand::Memory(Some(offset), base, _) => write!(f, "`{}({})`", offset, base),
+            RawOperand::Memory(None, base, _) => write!(f, "`({})`", base),
+            RawOperand::Symbol(name, _) => write!(f, "`{}`", name),
+        }
+    }
+}
+
+impl Arg {
+    pub fn new(operand: RawOperand, column: usize) -> Self {
+        Self { operand, column }
+    }
+
+    pub fn expected(&self, expected: &str) -> ParseError {
+        ParseError::expected(self.column, expected, self.to_string())
+    }
+
+    pub fn invalid(&self, error: RISCVError) -> ParseError {
+        ParseError::new(self.column, ParseErrorKind::InvalidOperand(error))
+    }
+
+    pub fn ident(&self, expected: &str) -> Result<&str, ParseError> {
+        match &self.operand {
+            RawOperand::Ident(name) => Ok(name),
+            _ => Err(self.expected(expected)),
+        }
+    }
+
+    pub fn register(&self) -> Result<Register, ParseError> {
+        let name = self.ident("register")?;
+        Register::from_name(name).ok_or_else(|| self.expected("register"))
+    }
+
+    pub fn fregister(&self) -> Result<FRegister, ParseError> {
+        let name = self.ident("floating-point register")?;
+        FRegister::from_name(name).ok_or_else(|| self.expected("floating-point register"))
+    }
+
+    pub fn number(&self) -> Result<i64, ParseError> {
+        match self.operand {
+            RawOperand::Number(n) | RawOperand::Symbol(_, n) => Ok(n),
+            _ => Err(self.expected("immediate")),
+        }
+    }
+
+    pub fn immediate(&self) -> Result<i32, ParseError> {
+        i32::try_from(self.number()?)
+            .map_err(|_| self.invalid(RISCVError::ImmediateOutOfRange(i32::MIN, i32::MAX)))
+    }
+
+    /// Returns the offset and the base register of a memory operand.
+    pub fn memory(&self) -> Result<(i32, Register), ParseError> {
+        let RawOperand::Memory(offset, base, base_column) = &self.operand else {
+            return Err(self.expected("`offset(register)`"));
+        };
+        let offset = i32::try_from(offset.unwrap_or(0))
+            .map_err(|_| self.invalid(RISCVError::ImmediateOutOfRange(i32::MIN, i32::MAX)))?;
+        let base = Register::from_name(base)
+            .ok_or_else(|| ParseError::expected(*base_column, "register", format!("`{}`", base)))?;
+        Ok((offset, base))
+    }
+
+    /// Returns the offset of a branch or jump (at the address) to the target, checked
+    /// against the range of the instruction's immediate. Numbers are offsets already,
+    /// while symbols are addresses.
+    fn offset<const END: u8>(&self, address: Address) -> Result<i32, ParseError> {
+        let offset = match &self.operand {
+            RawOperand::Symbol(_, target) => target.wrapping_sub(address as i64),
+            _ => self.number()?,
+        };
+        i32::try_from(offset)
+            .map_err(|_| RISCVError::ImmediateOutOfRange(i32::MIN, i32::MAX))
+            .and_then(|imm| Immediate::<1, END>::try_from(imm).map(|_| imm))
+            .map_err(|e| match (&self.operand, e) {
+                (RawOperand::Symbol(symbol, _), RISCVError::ImmediateOutOfRange(..)) => {
+                    ParseError::new(
+                        self.column,
+                        ParseErrorKind::TargetOutOfRange {
+                            symbol: symbol.clone(),
+                            offset,
+                        },
+                    )
+                }
+                (_, e) => self.invalid(e),
+            })
+    }
+}
+
+/// An operand of an instruction in the order of the assembly form.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Slot {
+    Rd(RegisterClass),
+    Rs1(RegisterClass),
+    Rs2(RegisterClass),
+    Rs3(RegisterClass),
+    Immediate,
+    ShiftAmount,
+    /// PC-relative offset of a branch
+    Branch,
+    /// PC-relative offset of `jal`
+    Jump,
+    /// 20-bit immediate of `lui` and `auipc`
+    Upper,
+    /// `offset(rs1)` of loads, stores and `jalr`
+    Memory,
+    /// `(rs1)` of atomic instructions
+    Address,
+    Csr,
+    /// 5-bit unsigned immediate of `csrrwi`, `csrrsi` and `csrrci`
+    CsrImmediate,
+    /// Optional rounding mode of floating-point operations
+    RoundingMode,
+}
+
+impl Slot {
+    fn describe(&self) -> &'static str {
+        match self {
+            Slot::Rd(RegisterClass::Float)
+            | Slot::Rs1(RegisterClass::Float)
+            | Slot::Rs2(RegisterClass::Float)
+            | Slot::Rs3(RegisterClass::Float) => "floating-point register",
+            Slot::Rd(_) | Slot::Rs1(_) | Slot::Rs2(_) | Slot::Rs3(_) => "register",
+            Slot::Immediate | Slot::ShiftAmount | Slot::Upper | Slot::CsrImmediate => "immediate",
+            Slot::Branch | Slot::Jump => "offset",
+            Slot::Memory => "`offset(register)`",
+            Slot::Address => "`(register)`",
+            Slot::Csr => "CSR",
+            Slot::RoundingMode => "rounding mode",
+        }
+    }
+
+    fn is_immediate(&self) -> bool {
+        matches!(
+            self,
+            Slot::Immediate
+                | Slot::ShiftAmount
+                | Slot::Branch
+                | Slot::Jump
+                | Slot::Upper
+                | Slot::Memory
+                | Slot::CsrImmediate
+        )
+    }
+}
+
+/// Tokens of a line, consumed by the parser.
+pub(crate) struct Cursor {
+    tokens: Vec<Spanned>,
+    pos: usize,
+    /// Column following the last token (ignoring the comment)
+    end: usize,
+}
+
+impl Cursor {
+    pub fn new(line: &str) -> Result<Self, ParseError> {
+        let code = line.split('#').next().unwrap_or_default().trim_end();
+        Ok(Self {
+            tokens: tokenize(line)?,
+            pos: 0,
+            end: code.chars().count() + 1,
+        })
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    pub fn peek(&self) -> Option<&Spanned> {
+        self.tokens.get(self.pos)
+    }
+
+    /// Reports an unexpected token, or the end of the line, at the current position.
+    pub fn unexpected(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some((token, column)) => ParseError::expected(*column, expected, token.to_string()),
+            None => ParseError::expected(self.end, expected, "end of line"),
+        }
+    }
+
+    /// Consumes a label, i.e. a name followed by `:`.
+    pub fn label(&mut self) -> Option<(String, usize)> {
+        match (self.tokens.get(self.pos), self.tokens.get(self.pos + 1)) {
+            (Some((Token::Ident(name), column)), Some((Token::Colon, _))) => {
+                let label = (name.clone(), *column);
+                self.pos += 2;
+                Some(label)
+            }
+            _ => None,
+        }
+    }
+
+    /// Consumes a name, e.g. a mnemonic.
+    pub fn ident(&mut self, expected: &str) -> Result<(String, usize), ParseError> {
+        match self.peek() {
+            Some((Token::Ident(name), column)) => {
+                let ident = (name.clone(), *column);
+                self.pos += 1;
+                Ok(ident)
+            }
+            _ => Err(self.unexpected(expected)),
+        }
+    }
+
+    /// Consumes the comma-separated operands up to the end of the line.
+    pub fn operands(&mut self) -> Result<Vec<Arg>, ParseError> {
+        let mut args = Vec::new();
+        if self.is_empty() {
+            return Ok(args);
+        }
+        loop {
+            args.push(self.operand()?);
+            match self.peek() {
+                None => return Ok(args),
+                Some((Token::Comma, _)) => self.pos += 1,
+                Some(_) => return Err(self.unexpected("`,`")),
+            }
+        }
+    }
+
+    fn operand(&mut self) -> Result<Arg, ParseError> {
+        match self.peek().cloned() {
+            Some((Token::Ident(name), column)) => {
+                self.pos += 1;
+                Ok(Arg::new(RawOperand::Ident(name), column))
+            }
+            Some((Token::Number(n), column)) => {
+                self.pos += 1;
+                if let Some((Token::LParen, _)) = self.peek() {
+                    let (base, base_column) = self.base()?;
+                    return Ok(Arg::new(
+                        RawOperand::Memory(Some(n), base, base_column),
+                        column,
+                    ));
+                }
+                Ok(Arg::new(RawOperand::Number(n), column))
+            }
+            Some((Token::LParen, column)) => {
+                let (base, base_column) = self.base()?;
+                Ok(Arg::new(
+                    RawOperand::Memory(None, base, base_column),
+                    column,
+                ))
+            }
+            _ => Err(self.unexpected("operand")),
+        }
+    }
+
+    /// Consumes the base register of a memory operand, i.e. `(reg)`.
+    fn base(&mut self) -> Result<(String, usize), ParseError> {
+        self.pos += 1;
+        let base = self.ident("register")?;
+        match self.peek() {
+            Some((Token::RParen, _)) => {
+                self.pos += 1;
+                Ok(base)
+            }
+            _ => Err(self.unexpected("`)`")),
+        }
+    }
+}
+
+/// A parser of assembly instructions, in the syntax of the GNU assembler
+/// (e.g. `addi a0, sp, -16` or `sw ra, 12(sp)`).
+///
+/// Instructions are recognized by the mnemonic tables of the [`data`](crate::data) module,
+/// along with common pseudo-instructions standing for a single instruction (e.g. `mv`, `ret`
+/// or `beqz`). Operands are laid out as by the disassembler, and the targets of branches
+/// and jumps are offsets relative to the instruction. Instructions that aren't defined
+/// for the base ISA (e.g. `ld` in RV32) are rejected.
+///
+/// # Example
+///
+/// ```
+/// # use riscv_isa::{AsmParser, Instruction, ParseError, Xlen};
+/// # fn test() -> Result<(), ParseError> {
+/// let parser = AsmParser::new(Xlen::Rv64);
+/// let instr = parser.parse_line("sd ra, 8(sp)  # save the return address")?.unwrap();
+/// assert_eq!(0x00113423, u32::from(instr));
+///
+/// let error = parser.parse_line("addi a0, x32, 1").unwrap_err();
+/// assert_eq!("1:10: expected register, found `x32`", error.to_string());
+///
+/// // or for RV32
+/// let instr: Instruction = "addi a0, sp, -16".parse()?;
+/// # Ok(())
+/// # }
+/// # test().unwrap();
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct AsmParser {
+    xlen: Xlen,
+}
+
+impl AsmParser {
+    pub fn new(xlen: Xlen) -> Self {
+        Self { xlen }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.xlen
+    }
+
+    /// Parses a line with a single instruction. Returns `None` for lines without
+    /// any instruction (empty or with a comment only).
+    pub fn parse_line(&self, line: &str) -> Result<Option<Instruction>, ParseError> {
+        let mut cursor = Cursor::new(line)?;
+        if cursor.is_empty() {
+            return Ok(None);
+        }
+        let (mnemonic, column) = cursor.ident("instruction")?;
+        let args = cursor.operands()?;
+        self.instruction(&mnemonic, column, args, cursor.end(), 0)
+            .map(Some)
+    }
+
+    /// Parses the instructions of a multi-line source, one instruction per line.
+    pub fn parse(&self, source: &str) -> Result<Vec<Instruction>, ParseError> {
+        source
+            .lines()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                self.parse_line(line)
+                    .map_err(|e| e.at_line(i + 1))
+                    .transpose()
+            })
+            .collect()
+    }
+
+    /// Builds an instruction of the mnemonic (at the column) and its operands, where `end`
+    /// is the column reported when operands are missing. The address is the one symbolic
+    /// targets of branches and jumps are relative to.
+    pub(crate) fn instruction(
+        &self,
+        name: &str,
+        column: usize,
+        args: Vec<Arg>,
+        end: usize,
+        address: Address,
+    ) -> Result<Instruction, ParseError> {
+        let (name, args) = expand_pseudo(name, column, args, end)?;
+        let unknown = || ParseError::new(column, ParseErrorKind::UnknownMnemonic(name.clone()));
+
+        // memory ordering suffix of atomic instructions, e.g. `amoadd.w.aqrl`
+        let (base, aq, rl) = [
+            (".aqrl", true, true),
+            (".aq", true, false),
+            (".rl", false, true),
+        ]
+        .into_iter()
+        .find_map(|(suffix, aq, rl)| Some((name.strip_suffix(suffix)?, aq, rl)))
+        .filter(|(base, ..)| find_mnemonic_from_name(base).is_some())
+        .unwrap_or((&name, false, false));
+        let mnemonic = find_mnemonic_from_name(base).ok_or_else(unknown)?;
+
+        if let Some(bits) = find_system_instr_from_mnemonic(&mnemonic) {
+            check_arity(&args, 0, 0, "operand", end)?;
+            return Instruction::decode(bits, self.xlen)
+                .map_err(|e| ParseError::new(column, ParseErrorKind::InvalidOperand(e)));
+        }
+
+        let (mut builder, slots, amo) = layout(mnemonic).ok_or_else(unknown)?;
+        if aq || rl {
+            if !amo {
+                return Err(unknown());
+            }
+            builder.set_ordering(aq, rl);
+        }
+        let required = slots.iter().filter(|s| **s != Slot::RoundingMode).count();
+        let missing = slots.get(args.len()).map_or("operand", Slot::describe);
+        check_arity(&args, required, slots.len(), missing, end)?;
+
+        let mut imm_column = column;
+        for (slot, arg) in slots.iter().zip(&args) {
+            if slot.is_immediate() {
+                imm_column = arg.column;
+            }
+            self.fill(&mut builder, *slot, arg, address)?;
+        }
+
+        let instr = builder.build().map_err(|e| {
+            let column = match e {
+                RISCVError::ImmediateOutOfRange(..) | RISCVError::ImmediateBitsBeforeStart(_) => {
+                    imm_column
+                }
+                _ => column,
+            };
+            ParseError::new(column, ParseErrorKind::InvalidOperand(e))
+        })?;
+        Instruction::decode(u32::from(instr), self.xlen)
+            .map_err(|e| ParseError::new(column, ParseErrorKind::InvalidOperand(e)))
+    }
+
+    /// Sets the field of the builder matching the operand of the instruction at the address.
+    fn fill(
+        &self,
+        b: &mut InstructionBuilder,
+        slot: Slot,
+        arg: &Arg,
+        address: Address,
+    ) -> Result<(), ParseError> {
+        use RegisterClass::*;
+        match slot {
+            Slot::Rd(Integer) => b.set_rd(arg.register()?),
+            Slot::Rd(Float) => b.set_frd(arg.fregister()?),
+            Slot::Rs1(Integer) => b.set_rs1(arg.register()?),
+            Slot::Rs1(Float) => b.set_frs1(arg.fregister()?),
+            Slot::Rs2(Integer) => b.set_rs2(arg.register()?),
+            Slot::Rs2(Float) => b.set_frs2(arg.fregister()?),
+            Slot::Rs3(Integer) => b.set_rs3(arg.register()?),
+            Slot::Rs3(Float) => b.set_frs3(arg.fregister()?),
+            Slot::Immediate | Slot::CsrImmediate => b.set_immediate(arg.immediate()?),
+            Slot::Branch => b.set_immediate(arg.offset::<12>(address)?),
+            Slot::Jump => b.set_immediate(arg.offset::<20>(address)?),
+            Slot::ShiftAmount => {
+                let max = i64::from(self.xlen.bits()) - 1;
+                match arg.number()? {
+                    shamt @ 0.. if shamt <= max => b.set_immediate(shamt as i32),
+                    _ => return Err(arg.invalid(RISCVError::ImmediateOutOfRange(0, max as i32))),
+                }
+            }
+            Slot::Upper => match arg.number()? {
+                imm @ 0..=0xfffff => b.set_immediate((imm << 12) as u32 as i32),
+                _ => return Err(arg.invalid(RISCVError::ImmediateOutOfRange(0, 0xfffff))),
+            },
+            Slot::Memory => {
+                let (offset, base) = arg.memory()?;
+                b.set_rs1(base).set_immediate(offset)
+            }
+            Slot::Address => match arg.memory()? {
+                (0, base) => b.set_rs1(base),
+                _ => return Err(arg.expected("`(register)`")),
+            },
+            Slot::Csr => {
+                let csr = match &arg.operand {
+                    RawOperand::Ident(name) => {
+                        Csr::from_name(name).ok_or_else(|| arg.expected("CSR"))?
+                    }
+                    RawOperand::Number(n) => u16::try_from(*n)
+                        .map_err(|_| RISCVError::InvalidCsr)
+                        .and_then(Csr::try_from)
+                        .map_err(|e| arg.invalid(e))?,
+                    _ => return Err(arg.expected("CSR")),
+                };
+                b.set_csr(csr)
+            }
+            Slot::RoundingMode => {
+                let name = arg.ident("rounding mode")?;
+                let rm =
+                    RoundingMode::from_name(name).ok_or_else(|| arg.expected("rounding mode"))?;
+                b.set_rounding_mode(rm)
+            }
+        };
+        Ok(())
+    }
+}
+
+/// Returns the builder preset for the instruction, its operands in the order of
+/// the assembly form, and whether it's an atomic instruction. Fences (whose operands
+/// are sets of memory accesses) aren't supported.
+fn layout(mnemonic: Mnemonic) -> Option<(InstructionBuilder, Vec<Slot>, bool)> {
+    use RegisterClass::{Float as F, Integer as X};
+    use Slot::*;
+
+    let name: &'static str = mnemonic.into();
+    let builder = InstructionBuilder::from_mnemonic(name.into()).ok()?;
+
+    if let Some((_, bits, rm)) = find_float_instr_from_mnemonic(&name.into()) {
+        let slots = match (bits & 0x7f) as u8 {
+            LOAD_FP => vec![Rd(F), Memory],
+            STORE_FP => vec![Rs2(F), Memory],
+            _ => {
+                let classes = find_float_register_classes(&name.into()).unwrap_or_default();
+                let fields: [fn(RegisterClass) -> Slot; 4] = [Rd, Rs1, Rs2, Rs3];
+                let mut slots: Vec<Slot> = fields
+                    .iter()
+                    .zip(classes)
+                    .filter_map(|(field, class)| class.map(field))
+                    .collect();
+                if rm {
+                    slots.push(RoundingMode);
+                }
+                slots
+            }
+        };
+        return Some((builder, slots, false));
+    }
+
+    let (opcode, funct3, _) = find_instr_from_mnemonic(name.into())?;
+    let op = u8::from(opcode);
+    if op == MISC_MEM {
+        return None;
+    }
+    let slots = match opcode.format() {
+        InstructionFormat::R if op == AMO_OPCODE && name.starts_with("lr_") => {
+            return Some((builder, vec![Rd(X), Address], true))
+        }
+        InstructionFormat::R if op == AMO_OPCODE => {
+            return Some((builder, vec![Rd(X), Rs2(X), Address], true))
+        }
+        InstructionFormat::R => vec![Rd(X), Rs1(X), Rs2(X)],
+        InstructionFormat::I if op == LOAD || op == JALR => vec![Rd(X), Memory],
+        InstructionFormat::I if op == SYSTEM && u8::from(funct3) & 0b100 != 0 => {
+            vec![Rd(X), Csr, CsrImmediate]
+        }
+        InstructionFormat::I if op == SYSTEM => vec![Rd(X), Csr, Rs1(X)],
+        InstructionFormat::I if IInstruction::has_shamt(opcode, funct3) => {
+            vec![Rd(X), Rs1(X), ShiftAmount]
+        }
+        InstructionFormat::I => vec![Rd(X), Rs1(X), Immediate],
+        InstructionFormat::S => vec![Rs2(X), Memory],
+        InstructionFormat::B => vec![Rs1(X), Rs2(X), Branch],
+        InstructionFormat::U => vec![Rd(X), Upper],
+        InstructionFormat::J => vec![Rd(X), Jump],
+        _ => return None,
+    };
+    Some((builder, slots, false))
+}
+
+/// Checks the number of operands, between `min` and `max`, where `missing` describes
+/// the first missing operand.
+pub(crate) fn check_arity(
+    args: &[Arg],
+    min: usize,
+    max: usize,
+    missing: &str,
+    end: usize,
+) -> Result<(), ParseError> {
+    if args.len() < min {
+        return Err(ParseError::expected(end, missing, "end of line"));
+    }
+    match args.get(max) {
+        Some(extra) => Err(extra.expected("end of line")),
+        None => Ok(()),
+    }
+}
+
+/// Replaces a pseudo-instruction with the instruction it stands for. Instructions
+/// with a pseudo form (e.g. `jal offset`) are only replaced if the number of operands
+/// matches the pseudo-instruction.
+fn expand_pseudo(
+    name: &str,
+    column: usize,
+    args: Vec<Arg>,
+    end: usize,
+) -> Result<(String, Vec<Arg>), ParseError> {
+    let lower = name.to_lowercase();
+    let mut candidates = PSEUDO_INSTRUCTIONS.iter().filter(|(n, ..)| *n == lower);
+    let Some(first) = candidates.clone().next() else {
+        return Ok((name.to_string(), args));
+    };
+    let Some((_, base, template)) = candidates.find(|(_, _, t)| arity(t) == args.len()) else {
+        if find_mnemonic_from_name(&lower).is_some() {
+            return Ok((name.to_string(), args));
+        }
+        let n = arity(first.2);
+        return Err(check_arity(&args, n, n, "operand", end)
+            .expect_err("the number of operands doesn't match the pseudo-instruction"));
+    };
+
+    let operands = template
+        .iter()
+        .map(|op| match op.split_once('(') {
+            Some((offset, base)) => {
+                let base = substitute(base.trim_end_matches(')'), column, &args);
+                let RawOperand::Ident(name) = base.operand else {
+                    return Err(base.expected("register"));
+                };
+                let offset = match substitute(offset, column, &args) {
+                    Arg {
+                        operand: RawOperand::Number(n) | RawOperand::Symbol(_, n),
+                        ..
+                    } => n,
+                    arg => return Err(arg.expected("immediate")),
+                };
+                Ok(Arg::new(
+                    RawOperand::Memory(Some(offset), name, base.column),
+                    base.column,
+                ))
+            }
+            None => Ok(substitute(op, column, &args)),
+        })
+        .collect::<Result<Vec<Arg>, ParseError>>()?;
+    Ok((base.to_string(), operands))
+}
+
+/// Returns the number of operands of a pseudo-instruction, referred to by its template.
+fn arity(template: &[&str]) -> usize {
+    template
+        .iter()
+        .flat_map(|op| op.split('$').skip(1))
+        .filter_map(|rest| rest.get(..1)?.parse::<usize>().ok())
+        .map(|index| index + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the operand of a template: the n-th operand for `$n`, or the literal
+/// register or number placed at the column of the mnemonic.
+fn substitute(template: &str, column: usize, args: &[Arg]) -> Arg {
+    if let Some(index) = template
+        .strip_prefix('$')
+        .and_then(|i| i.parse::<usize>().ok())
+    {
+        return args[index].clone();
+    }
+    match parse_number(template) {
+        Some(n) => Arg::new(RawOperand::Number(n), column),
+        None => Arg::new(RawOperand::Ident(template.to_string()), column),
+    }
+}
+
+/// Parses a single RV32 instruction, see [`AsmParser`].
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AsmParser::default()
+            .parse_line(s)?
+            .ok_or_else(|| ParseError::expected(1, "instruction", "end of line"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str, xlen: Xlen) -> Result<u32, ParseError> {
+        AsmParser::new(xlen)
+            .parse_line(line)
+            .map(|instr| u32::from(instr.unwrap()))
+    }
+
+    fn error(line: &str, xlen: Xlen) -> (usize, String) {
+        let e = parse(line, xlen).unwrap_err();
+        (e.column, e.kind.to_string())
+    }
+
+    #[test]
+    fn test_formats() {
+        let rv32 = |line| parse(line, Xlen::Rv32).unwrap();
+        assert_eq!(0xff010513, rv32("addi a0, sp, -16"));
+        assert_eq!(0x00112623, rv32("sw ra, 12(sp)"));
+        assert_eq!(0x00628533, rv32("ADD a0, t0, x6"));
+        assert_eq!(0x40355513, rv32("srai a0, a0, 3"));
+        assert_eq!(0x1ca5c263, rv32("blt a1, a0, 0x1c4"));
+        assert_eq!(0x1c40006f, rv32("jal zero, 0x1c4"));
+        assert_eq!(0x00008067, rv32("jalr zero, 0(ra)"));
+        assert_eq!(0x00002517, rv32("auipc a0, 0x2"));
+        assert_eq!(0x12345537, rv32("lui a0, 0x12345"));
+        assert_eq!(0x30002573, rv32("csrrs a0, mstatus, zero"));
+        assert_eq!(0x3052d073, rv32("csrrwi zero, 0x305, 5"));
+        assert_eq!(0x00000073, rv32("ecall"));
+        assert_eq!(0x06c5a52f, rv32("amoadd.w.aqrl a0, a2, (a1)"));
+
+        let rv64 = |line| parse(line, Xlen::Rv64).unwrap();
+        assert_eq!(0x00853503, rv64("ld a0, 8(a0)"));
+        assert_eq!(0x62855513, rv64("rori a0, a0, 40"));
+        assert_eq!(0x1405b52f, rv64("lr.d.aq a0, 0(a1)"));
+    }
+
+    #[test]
+    fn test_float() {
+        let rv32 = |line| parse(line, Xlen::Rv32).unwrap();
+        assert_eq!(0x00c5f553, rv32("fadd.s fa0, fa1, fa2"));
+        assert_eq!(0x00c5f553, rv32("fadd.s f10, f11, f12, dyn"));
+        assert_eq!(0xc0159553, rv32("fcvt.wu.s a0, fa1, rtz"));
+        assert_eq!(0x00412507, rv32("flw fa0, 4(sp)"));
+        assert_eq!(0x68c5f543, rv32("fmadd.s fa0, fa1, fa2, fa3"));
+        assert_eq!(
+            (
+                18,
+                "expected floating-point register, found `a2`".to_string()
+            ),
+            error("fadd.s fa0, fa1, a2", Xlen::Rv32)
+        );
+    }
+
+    #[test]
+    fn test_pseudo_instructions() {
+        let rv32 = |line| parse(line, Xlen::Rv32).unwrap();
+        assert_eq!(0x00000013, rv32("nop"));
+        assert_eq!(0xff000513, rv32("li a0, -16"));
+        assert_eq!(0x00058513, rv32("mv a0, a1"));
+        assert_eq!(0x00008067, rv32("ret"));
+        assert_eq!(0x1c40006f, rv32("j 0x1c4"));
+        assert_eq!(rv32("jal ra, 0x1c4"), rv32("jal 0x1c4"));
+        assert_eq!(rv32("jalr ra, 0(a0)"), rv32("jalr a0"));
+        assert_eq!(rv32("jalr ra, 4(a0)"), rv32("jalr ra, a0, 4"));
+        assert_eq!(rv32("blt a1, a0, 0x1c4"), rv32("bgt a0, a1, 0x1c4"));
+        assert_eq!(rv32("bne a0, zero, -8"), rv32("bnez a0, -8"));
+        assert_eq!(0x30002573, rv32("csrr a0, mstatus"));
+        assert_eq!(
+            (6, "expected operand, found end of line".to_string()),
+            error("mv a0", Xlen::Rv32)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let rv32 = |line| error(line, Xlen::Rv32);
+        assert_eq!(
+            (10, "expected register, found `x32`".into()),
+            rv32("addi a0, x32, 1")
+        );
+        assert_eq!(
+            (12, "expected immediate, found end of line".into()),
+            rv32("addi a0, sp")
+        );
+        assert_eq!(
+            (17, "expected end of line, found `2`".into()),
+            rv32("addi a0, sp, 1, 2")
+        );
+        assert_eq!(
+            (13, "expected `,`, found `sp`".into()),
+            rv32("addi a0, sp sp")
+        );
+        assert_eq!(
+            (13, "expected `)`, found end of line".into()),
+            rv32("sw ra, 12(sp")
+        );
+        assert_eq!(
+            (10, "expected register, found `x99`".into()),
+            rv32("lw a0, 4(x99)")
+        );
+        assert_eq!(
+            (8, "expected `offset(register)`, found `a1`".into()),
+            rv32("lw a0, a1")
+        );
+        assert_eq!(
+            (1, "unknown or unsupported instruction `foo`".into()),
+            rv32("foo a0")
+        );
+        assert_eq!(
+            (1, "unknown or unsupported instruction `fence`".into()),
+            rv32("fence")
+        );
+        assert_eq!(
+            (1, "unknown or unsupported instruction `add.aq`".into()),
+            rv32("add.aq a0, a1, a2")
+        );
+        assert_eq!((1, "expected instruction, found `1`".into()), rv32("1 a0"));
+        assert_eq!(
+            (21, "expected `(register)`, found `4(a2)`".into()),
+            rv32("amoadd.w.aq a0, a1, 4(a2)")
+        );
+
+        let invalid = |line| {
+            let e = parse(line, Xlen::Rv32).unwrap_err();
+            match e.kind {
+                ParseErrorKind::InvalidOperand(error) => (e.column, error),
+                kind => panic!("unexpected error: {}", kind),
+            }
+        };
+        use RISCVError::*;
+        assert_eq!(
+            (14, ImmediateOutOfRange(-2048, 2047)),
+            invalid("addi a0, sp, 2048")
+        );
+        assert_eq!((13, ImmediateBitsBeforeStart(1)), invalid("beq a0, a1, 3"));
+        assert_eq!((14, ImmediateOutOfRange(0, 31)), invalid("slli a0, a0, 32"));
+        assert_eq!(
+            (9, ImmediateOutOfRange(0, 0xfffff)),
+            invalid("lui a0, 0x100000")
+        );
+        assert_eq!(
+            (1, UnsupportedInstruction(Xlen::Rv32)),
+            invalid("ld a0, 0(a0)")
+        );
+    }
+
+    #[test]
+    fn test_source() {
+        let source = "# prologue\n\naddi sp, sp, -16\nsw ra, 12(sp)  # save ra\n";
+        let instrs = AsmParser::default().parse(source).unwrap();
+        assert_eq!(2, instrs.len());
+        assert_eq!(Ok(instrs[1]), "sw ra, 12(sp)".parse());
+
+        let e = AsmParser::default()
+            .parse("nop\n\n  addi a0, a1\n")
+            .unwrap_err();
+        assert_eq!((3, 14), (e.line, e.column));
+        assert_eq!("3:14: expected immediate, found end of line", e.to_string());
+        assert!("# nothing".parse::<Instruction>().is_err());
+    }
+}
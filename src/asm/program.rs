@@ -0,0 +1,204 @@
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::{
+    disasm::{Address, Relocation, Symbol, SymbolTable},
+    model::Xlen,
+};
+
+/// A section of an assembled program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SectionKind {
+    Text,
+    Data,
+}
+
+impl SectionKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SectionKind::Text => ".text",
+            SectionKind::Data => ".data",
+        }
+    }
+}
+
+impl fmt::Display for SectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A symbol defined in assembly: a label of a section, or a constant defined with `.equ`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsmSymbol {
+    pub name: String,
+    /// Address of a label, or value of a constant
+    pub value: i64,
+    /// Section of a label, or `None` for a constant
+    pub section: Option<SectionKind>,
+    /// Declared with `.globl`
+    pub global: bool,
+}
+
+/// Contents of a section placed at the address.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ProgramSection {
+    bytes: Vec<u8>,
+    address: Address,
+    alignment: Address,
+}
+
+impl ProgramSection {
+    pub fn new(bytes: Vec<u8>, address: Address, alignment: Address) -> Self {
+        Self {
+            bytes,
+            address,
+            alignment,
+        }
+    }
+}
+
+/// Output of the [`Assembler`](super::Assembler): the contents of the `.text` and `.data`
+/// sections, placed at their addresses, and the symbols defined by the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Program {
+    xlen: Xlen,
+    text: ProgramSection,
+    data: ProgramSection,
+    symbols: Vec<AsmSymbol>,
+    relocations: Vec<Relocation>,
+}
+
+impl Program {
+    pub(crate) fn new(
+        xlen: Xlen,
+        text: ProgramSection,
+        data: ProgramSection,
+        symbols: Vec<AsmSymbol>,
+    ) -> Self {
+        Self {
+            xlen,
+            text,
+            data,
+            symbols,
+            relocations: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_relocations(self, relocations: Vec<Relocation>) -> Self {
+        Self {
+            relocations,
+            ..self
+        }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.xlen
+    }
+
+    pub fn text(&self) -> &[u8] {
+        &self.text.bytes
+    }
+
+    pub fn text_address(&self) -> Address {
+        self.text.address
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data.bytes
+    }
+
+    pub fn data_address(&self) -> Address {
+        self.data.address
+    }
+
+    /// Returns the contents and the address of the section.
+    pub fn section(&self, kind: SectionKind) -> (&[u8], Address) {
+        let section = self.program_section(kind);
+        (&section.bytes, section.address)
+    }
+
+    /// Returns the alignment the section requires, as requested with `.align`.
+    pub fn alignment(&self, kind: SectionKind) -> Address {
+        self.program_section(kind).alignment
+    }
+
+    fn program_section(&self, kind: SectionKind) -> &ProgramSection {
+        match kind {
+            SectionKind::Text => &self.text,
+            SectionKind::Data => &self.data,
+        }
+    }
+
+    /// Returns the symbols in the order of their definition.
+    pub fn symbols(&self) -> &[AsmSymbol] {
+        &self.symbols
+    }
+
+    pub fn symbol(&self, name: &str) -> Option<&AsmSymbol> {
+        self.symbols.iter().find(|s| s.name == name)
+    }
+
+    /// Returns the address of the `_start` label, or the start of `.text` if there's none.
+    pub fn entry(&self) -> Address {
+        self.symbol("_start")
+            .filter(|s| s.section.is_some())
+            .map_or(self.text.address, |s| s.value as Address)
+    }
+
+    /// Returns the labels, e.g. to print them in the output of [`Disasm`](crate::Disasm).
+    pub fn symbol_table(&self) -> SymbolTable {
+        self.symbols
+            .iter()
+            .filter(|s| s.section.is_some())
+            .map(|s| Symbol::new(s.name.clone(), s.value as Address))
+            .collect()
+    }
+
+    /// Returns the references to labels a linker has to update if the sections were moved
+    /// (labels placed with `.word`). Labels used as immediates of instructions aren't
+    /// relocated.
+    pub fn relocations(&self) -> &[Relocation] {
+        &self.relocations
+    }
+
+    /// Returns the section holding the address, if any.
+    pub fn section_at(&self, address: Address) -> Option<SectionKind> {
+        [SectionKind::Text, SectionKind::Data]
+            .into_iter()
+            .find(|kind| {
+                let (bytes, start) = self.section(*kind);
+                (start..start + bytes.len() as Address).contains(&address)
+            })
+    }
+
+    /// Returns the flat binary image of the program, starting at the lowest address
+    /// of the (non-empty) sections, with the gap between the sections filled with zeros.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let sections: Vec<(&[u8], Address)> = [SectionKind::Text, SectionKind::Data]
+            .into_iter()
+            .map(|kind| self.section(kind))
+            .filter(|(bytes, _)| !bytes.is_empty())
+            .collect();
+        let Some(start) = sections.iter().map(|(_, address)| *address).min() else {
+            return Vec::new();
+        };
+        let end = sections
+            .iter()
+            .map(|(bytes, address)| address + bytes.len() as Address)
+            .max()
+            .unwrap_or(start);
+
+        let mut image = vec![0; (end - start) as usize];
+        for (bytes, address) in sections {
+            let offset = (address - start) as usize;
+            image[offset..offset + bytes.len()].copy_from_slice(bytes);
+        }
+        image
+    }
+
+    /// Writes the flat binary image of the program (see [`to_bytes`](Self::to_bytes)).
+    pub fn write_binary(&self, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+}
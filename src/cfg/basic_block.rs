@@ -0,0 +1,50 @@
+use crate::disasm::{Address, InstructionRecord};
+
+/// A sequence of instructions executed one after another: only the first one is
+/// the target of branches and jumps, and only the last one (the terminator)
+/// transfers control elsewhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicBlock {
+    records: Vec<InstructionRecord>,
+}
+
+impl BasicBlock {
+    /// Creates a block of the records, which must be consecutive and non-empty.
+    pub(crate) fn new(records: Vec<InstructionRecord>) -> Self {
+        debug_assert!(!records.is_empty());
+        Self { records }
+    }
+
+    /// Returns the address of the first instruction.
+    pub fn start(&self) -> Address {
+        self.records[0].address()
+    }
+
+    /// Returns the address following the last instruction.
+    pub fn end(&self) -> Address {
+        let last = self.terminator();
+        last.address() + last.size()
+    }
+
+    pub fn contains(&self, address: Address) -> bool {
+        (self.start()..self.end()).contains(&address)
+    }
+
+    pub fn records(&self) -> &[InstructionRecord] {
+        &self.records
+    }
+
+    /// Returns the last instruction, which decides where control goes next.
+    pub fn terminator(&self) -> &InstructionRecord {
+        &self.records[self.records.len() - 1]
+    }
+
+    /// Returns the number of instructions (fused records count as one).
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
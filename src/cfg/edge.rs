@@ -0,0 +1,50 @@
+use std::fmt;
+
+use crate::disasm::Address;
+
+/// How control passes from one basic block to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// To the next instruction: after an instruction which isn't a jump, when a branch
+    /// isn't taken, or when a call returns
+    Fallthrough,
+    /// To the target of a taken branch or a jump
+    Taken,
+    /// To the function called by `jal`/`jalr` linking the return address
+    Call,
+    /// From a return of a called function back to the instruction after the call
+    Return,
+}
+
+impl EdgeKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            EdgeKind::Fallthrough => "fallthrough",
+            EdgeKind::Taken => "taken",
+            EdgeKind::Call => "call",
+            EdgeKind::Return => "return",
+        }
+    }
+}
+
+impl fmt::Display for EdgeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// An edge of the control-flow graph, between the start addresses of basic blocks.
+/// The target of a jump or a call may lie outside the disassembled code, so it
+/// doesn't have to be a block of the graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub from: Address,
+    pub to: Address,
+    pub kind: EdgeKind,
+}
+
+impl Edge {
+    pub fn new(from: Address, to: Address, kind: EdgeKind) -> Self {
+        Self { from, to, kind }
+    }
+}
@@ -0,0 +1,412 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::{self, Write};
+
+use super::{BasicBlock, Edge, EdgeKind};
+use crate::disasm::{Address, DisasmError, Flow, InstructionFormatter, InstructionRecord};
+
+/// Control-flow graph of disassembled code: the instructions split into basic blocks,
+/// connected by the edges control can take between them.
+///
+/// Blocks start at the first instruction, at the targets of branches, jumps and calls,
+/// after instructions transferring control elsewhere (including calls), and after gaps
+/// between the instructions. Calls are followed by the called function (a `Call` edge)
+/// and by the instruction after them (a `Fallthrough` edge, taken once the function
+/// returns). The returns of functions whose entries are called within the graph are
+/// connected to the instructions following the calls (`Return` edges). Targets of
+/// indirect jumps and calls (`jalr` other than returns) aren't known, so they don't
+/// have edges.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ControlFlowGraph {
+    blocks: BTreeMap<Address, BasicBlock>,
+    edges: Vec<Edge>,
+}
+
+impl ControlFlowGraph {
+    /// Builds the graph of the records, which are ordered by their addresses.
+    /// Fused records (see [`Disasm::peephole`](crate::Disasm::peephole)) are single
+    /// instructions, so `call` and `tail` made of `auipc` and `jalr` have known targets.
+    pub fn from_records(records: &[InstructionRecord]) -> Self {
+        let mut records = records.to_vec();
+        records.sort_by_key(|r| r.address());
+        records.dedup_by_key(|r| r.address());
+
+        let mut graph = Self {
+            blocks: split(records),
+            edges: Vec::new(),
+        };
+        graph.connect();
+        graph
+    }
+
+    /// Builds the graph of all instructions read by the disassembler (or another
+    /// source of records, like [`Peephole`](crate::Peephole)).
+    pub fn from_disasm(
+        disasm: impl IntoIterator<Item = Result<InstructionRecord, DisasmError>>,
+    ) -> Result<Self, DisasmError> {
+        let records = disasm.into_iter().collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_records(&records))
+    }
+
+    /// Returns the blocks ordered by their addresses.
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values()
+    }
+
+    /// Returns the block starting at the address.
+    pub fn block(&self, start: Address) -> Option<&BasicBlock> {
+        self.blocks.get(&start)
+    }
+
+    /// Returns the block holding the instruction at the address.
+    pub fn block_containing(&self, address: Address) -> Option<&BasicBlock> {
+        self.blocks
+            .range(..=address)
+            .next_back()
+            .map(|(_, block)| block)
+            .filter(|block| block.contains(address))
+    }
+
+    /// Returns the block of the first instruction.
+    pub fn entry(&self) -> Option<&BasicBlock> {
+        self.blocks.values().next()
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns the edges leaving the block starting at the address.
+    pub fn successors(&self, start: Address) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.from == start)
+    }
+
+    /// Returns the edges entering the block starting at the address.
+    pub fn predecessors(&self, start: Address) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.to == start)
+    }
+
+    /// Exports the graph in the Graphviz DOT language, with the instructions of each
+    /// block formatted by the formatter. Targets outside the graph are dashed nodes,
+    /// calls are dashed edges and returns dotted ones.
+    pub fn to_dot(&self, formatter: &InstructionFormatter) -> String {
+        let mut out =
+            String::from("digraph cfg {\n    node [shape=box, fontname=\"monospace\"];\n");
+
+        for block in self.blocks.values() {
+            let mut lines: Vec<String> =
+                formatter.symbol_header(block.start()).into_iter().collect();
+            lines.extend(block.records().iter().map(|r| formatter.record(r)));
+            let label: String = lines.iter().map(|line| escape(line) + "\\l").collect();
+            out += &format!("    {} [label=\"{}\"];\n", node(block.start()), label);
+        }
+
+        let external: BTreeSet<Address> = self
+            .edges
+            .iter()
+            .map(|edge| edge.to)
+            .filter(|to| !self.blocks.contains_key(to))
+            .collect();
+        for address in external {
+            let label = formatter.target(address);
+            out += &format!(
+                "    {} [label=\"{}\", style=dashed];\n",
+                node(address),
+                escape(&label)
+            );
+        }
+
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Call => ", style=dashed",
+                EdgeKind::Return => ", style=dotted",
+                EdgeKind::Fallthrough | EdgeKind::Taken => "",
+            };
+            out += &format!(
+                "    {} -> {} [label=\"{}\"{}];\n",
+                node(edge.from),
+                node(edge.to),
+                edge.kind,
+                style
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Writes the graph in the Graphviz DOT language (see [`to_dot`](Self::to_dot)).
+    pub fn write_dot(
+        &self,
+        formatter: &InstructionFormatter,
+        mut writer: impl Write,
+    ) -> io::Result<()> {
+        writer.write_all(self.to_dot(formatter).as_bytes())
+    }
+
+    /// Connects the blocks: first by their terminators, then the returns of called
+    /// functions back to the callers.
+    fn connect(&mut self) {
+        for block in self.blocks.values() {
+            let flow = Flow::of(block.terminator());
+            if flow.falls_through() && self.blocks.contains_key(&block.end()) {
+                self.edges
+                    .push(Edge::new(block.start(), block.end(), EdgeKind::Fallthrough));
+            }
+            let kind = match flow {
+                Flow::Call(_) => EdgeKind::Call,
+                _ => EdgeKind::Taken,
+            };
+            if let Some(target) = flow.target() {
+                self.edges.push(Edge::new(block.start(), target, kind));
+            }
+        }
+
+        let calls: Vec<(Address, Address)> = self
+            .edges
+            .iter()
+            .filter(|edge| edge.kind == EdgeKind::Call)
+            .map(|edge| (edge.to, self.blocks[&edge.from].end()))
+            .filter(|(_, site)| self.blocks.contains_key(site))
+            .collect();
+        for (callee, site) in calls {
+            for block in self.function_blocks(callee) {
+                let edge = Edge::new(block, site, EdgeKind::Return);
+                if Flow::of(self.blocks[&block].terminator()) == Flow::Return
+                    && !self.edges.contains(&edge)
+                {
+                    self.edges.push(edge);
+                }
+            }
+        }
+    }
+
+    /// Returns the blocks reachable from the entry of a function without following
+    /// calls (but including the code after them).
+    fn function_blocks(&self, entry: Address) -> BTreeSet<Address> {
+        let mut visited = BTreeSet::new();
+        let mut pending = vec![entry];
+        while let Some(start) = pending.pop() {
+            if !self.blocks.contains_key(&start) || !visited.insert(start) {
+                continue;
+            }
+            pending.extend(
+                self.successors(start)
+                    .filter(|edge| matches!(edge.kind, EdgeKind::Fallthrough | EdgeKind::Taken))
+                    .map(|edge| edge.to),
+            );
+        }
+        visited
+    }
+}
+
+/// Splits the records, ordered by their addresses, into basic blocks.
+fn split(records: Vec<InstructionRecord>) -> BTreeMap<Address, BasicBlock> {
+    let addresses: HashSet<Address> = records.iter().map(|r| r.address()).collect();
+    let mut leaders = BTreeSet::new();
+    let mut next = None;
+    for record in &records {
+        if next != Some(record.address()) {
+            leaders.insert(record.address());
+        }
+        let flow = Flow::of(record);
+        let end = record.address() + record.size();
+        if flow != Flow::Next {
+            leaders.insert(end);
+        }
+        if let Some(target) = flow.target().filter(|target| addresses.contains(target)) {
+            leaders.insert(target);
+        }
+        next = Some(end);
+    }
+
+    let mut blocks = BTreeMap::new();
+    let mut current: Vec<InstructionRecord> = Vec::new();
+    for record in records {
+        if leaders.contains(&record.address()) && !current.is_empty() {
+            let block = BasicBlock::new(std::mem::take(&mut current));
+            blocks.insert(block.start(), block);
+        }
+        current.push(record);
+    }
+    if !current.is_empty() {
+        let block = BasicBlock::new(current);
+        blocks.insert(block.start(), block);
+    }
+    blocks
+}
+
+fn node(address: Address) -> String {
+    format!("\"0x{:x}\"", address)
+}
+
+/// Escapes a line for a DOT label, where each line is left-aligned.
+fn escape(line: &str) -> String {
+    line.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\l")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::Assembler;
+    use crate::disasm::{Disasm, DisasmConfig, SymbolTable};
+    use crate::model::Xlen;
+    use std::io::Cursor;
+
+    const SOURCE: &str = "
+    _start:
+        li a0, 10
+    loop:
+        addi a0, a0, -1
+        bnez a0, loop
+        jal ra, inc
+        j done
+    inc:
+        addi a1, a1, 1
+        ret
+    done:
+        ecall
+    ";
+
+    fn graph(source: &str) -> (ControlFlowGraph, SymbolTable) {
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x1000)
+            .assemble(source)
+            .unwrap();
+        let config = DisasmConfig::with_address(program.text_address());
+        let disasm = Disasm::with_config(Cursor::new(program.text().to_vec()), config);
+        (
+            ControlFlowGraph::from_disasm(disasm).unwrap(),
+            program.symbol_table(),
+        )
+    }
+
+    fn successors(graph: &ControlFlowGraph, start: Address) -> Vec<(Address, EdgeKind)> {
+        graph.successors(start).map(|e| (e.to, e.kind)).collect()
+    }
+
+    #[test]
+    fn test_blocks() {
+        let (graph, _) = graph(SOURCE);
+        let blocks: Vec<(Address, Address)> =
+            graph.blocks().map(|b| (b.start(), b.end())).collect();
+        assert_eq!(
+            vec![
+                (0x1000, 0x1004), // li
+                (0x1004, 0x100c), // loop: addi, bnez
+                (0x100c, 0x1010), // jal inc
+                (0x1010, 0x1014), // j done
+                (0x1014, 0x101c), // inc: addi, ret
+                (0x101c, 0x1020), // done: ecall
+            ],
+            blocks
+        );
+        assert_eq!(Some(0x1000), graph.entry().map(|b| b.start()));
+        assert_eq!(
+            Some(0x1014),
+            graph.block_containing(0x1018).map(|b| b.start())
+        );
+        assert_eq!(None, graph.block_containing(0x1020));
+        assert_eq!(2, graph.block(0x1004).unwrap().len());
+    }
+
+    #[test]
+    fn test_edges() {
+        use EdgeKind::*;
+
+        let (graph, _) = graph(SOURCE);
+        assert_eq!(vec![(0x1004, Fallthrough)], successors(&graph, 0x1000));
+        assert_eq!(
+            vec![(0x100c, Fallthrough), (0x1004, Taken)],
+            successors(&graph, 0x1004)
+        );
+        assert_eq!(
+            vec![(0x1010, Fallthrough), (0x1014, Call)],
+            successors(&graph, 0x100c)
+        );
+        assert_eq!(vec![(0x101c, Taken)], successors(&graph, 0x1010));
+        assert_eq!(vec![(0x1010, Return)], successors(&graph, 0x1014));
+        assert!(successors(&graph, 0x101c).is_empty());
+
+        let predecessors: Vec<(Address, EdgeKind)> = graph
+            .predecessors(0x1010)
+            .map(|e| (e.from, e.kind))
+            .collect();
+        assert_eq!(vec![(0x100c, Fallthrough), (0x1014, Return)], predecessors);
+    }
+
+    #[test]
+    fn test_indirect_and_external() {
+        use EdgeKind::*;
+
+        // a jump table (jr) and a call to a function outside the code
+        let (graph, _) = graph(
+            "
+            beqz a0, skip
+            jr a1
+        skip:
+            jal ra, 0x100
+            mret
+            ",
+        );
+        assert_eq!(
+            vec![(0x1004, Fallthrough), (0x1008, Taken)],
+            successors(&graph, 0x1000)
+        );
+        assert!(successors(&graph, 0x1004).is_empty());
+        assert_eq!(
+            vec![(0x100c, Fallthrough), (0x1108, Call)],
+            successors(&graph, 0x1008)
+        );
+        assert!(successors(&graph, 0x100c).is_empty());
+    }
+
+    #[test]
+    fn test_fused_call() {
+        use EdgeKind::*;
+
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x1000)
+            .assemble("auipc ra, 0\njalr ra, 12(ra)\nebreak\nret")
+            .unwrap();
+        let config = DisasmConfig::with_address(program.text_address());
+        let disasm = Disasm::with_config(Cursor::new(program.text().to_vec()), config);
+        let graph = ControlFlowGraph::from_disasm(disasm.peephole()).unwrap();
+
+        assert_eq!(
+            vec![(0x1008, Fallthrough), (0x100c, Call)],
+            successors(&graph, 0x1000)
+        );
+        assert_eq!(vec![(0x1008, Return)], successors(&graph, 0x100c));
+    }
+
+    #[test]
+    fn test_dot() {
+        let (graph, symbols) = graph(
+            "
+        main:
+            jal ra, 0x100
+            ret
+            ",
+        );
+        let config = DisasmConfig {
+            show_addr: false,
+            ..DisasmConfig::default()
+        };
+        let formatter = InstructionFormatter::new(config).with_symbols(symbols);
+        let mut dot = Vec::new();
+        graph.write_dot(&formatter, &mut dot).unwrap();
+        assert_eq!(
+            "digraph cfg {
+    node [shape=box, fontname=\"monospace\"];
+    \"0x1000\" [label=\"00001000 <main>:\\ljal 0x1100 <main+0x100>\\l\"];
+    \"0x1004\" [label=\"ret\\l\"];
+    \"0x1100\" [label=\"0x1100 <main+0x100>\", style=dashed];
+    \"0x1000\" -> \"0x1004\" [label=\"fallthrough\"];
+    \"0x1000\" -> \"0x1100\" [label=\"call\", style=dashed];
+}
+",
+            String::from_utf8(dot).unwrap()
+        );
+    }
+}
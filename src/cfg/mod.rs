@@ -0,0 +1,7 @@
+mod basic_block;
+mod edge;
+mod graph;
+
+pub use basic_block::*;
+pub use edge::*;
+pub use graph::*;
@@ -1,5 +1,8 @@
-use super::{INSTRUCTIONS, SYSTEM_INSTRUCTIONS};
-use crate::model::{Funct3, Funct7, Mnemonic, Opcode};
+use super::{
+    COMPRESSED_INSTRUCTIONS, CSR_NAMES, FLOAT_INSTRUCTIONS, INSTRUCTIONS, SYSTEM_INSTRUCTIONS,
+    VECTOR_INSTRUCTIONS,
+};
+use crate::model::{CompressedOp, Funct3, Funct7, Mnemonic, Opcode, RegisterClass, VectorOperand};
 
 pub fn get_mnemonic(
     opcode: Opcode,
@@ -14,16 +17,95 @@ pub fn get_mnemonic(
     INSTRUCTIONS.get(&code).map(|res| res.0.into())
 }
 
+pub fn get_compressed_mnemonic(op: CompressedOp) -> Option<Mnemonic> {
+    COMPRESSED_INSTRUCTIONS.get(&op).map(|res| res.0.into())
+}
+
 pub fn find_system_mnemonic(instr: u32) -> Option<Mnemonic> {
     SYSTEM_INSTRUCTIONS.get(&instr).map(|res| res.0.into())
 }
 
+/// Finds the name of a standard CSR by its address.
+pub fn find_csr_name(csr: u16) -> Option<&'static str> {
+    CSR_NAMES
+        .binary_search_by_key(&csr, |(addr, _)| *addr)
+        .ok()
+        .map(|index| CSR_NAMES[index].1)
+}
+
+/// Finds the address of a standard CSR by its name.
+pub fn find_csr_from_name(name: &str) -> Option<u16> {
+    CSR_NAMES
+        .iter()
+        .find(|(_, csr_name)| csr_name.eq_ignore_ascii_case(name))
+        .map(|(addr, _)| *addr)
+}
+
+/// Finds a vector instruction matching the encoding, along with its operands
+/// in the order of the assembly form.
+pub fn find_vector_instruction(instr: u32) -> Option<(Mnemonic, &'static [VectorOperand])> {
+    VECTOR_INSTRUCTIONS
+        .iter()
+        .find(|(mask, matching, _)| instr & mask == *matching)
+        .map(|(_, _, row)| (row.0.into(), row.3))
+}
+
+/// Finds a floating-point instruction matching the encoding, along with the register
+/// classes of rd, rs1, rs2 and rs3 (`None` if the field isn't a register operand),
+/// and whether funct3 holds the rounding mode.
+pub fn find_float_instruction(instr: u32) -> Option<(Mnemonic, [Option<RegisterClass>; 4], bool)> {
+    FLOAT_INSTRUCTIONS
+        .iter()
+        .find(|(mask, matching, _)| instr & mask == *matching)
+        .map(|(_, _, row)| (row.0.into(), row.3, row.4))
+}
+
+/// Finds the fixed bits of a floating-point instruction as `(mask, match)`, and whether
+/// funct3 holds the rounding mode.
+pub fn find_float_instr_from_mnemonic(mnemonic: &Mnemonic) -> Option<(u32, u32, bool)> {
+    FLOAT_INSTRUCTIONS
+        .iter()
+        .find(|(_, _, row)| Mnemonic::from(row.0) == *mnemonic)
+        .map(|(mask, matching, row)| (*mask, *matching, row.4))
+}
+
+/// Finds the mnemonic of a scalar (non-vector, non-compressed) instruction by its name
+/// as written in assembly (e.g. `fence.i` or `fadd.s`), case-insensitively.
+pub fn find_mnemonic_from_name(name: &str) -> Option<Mnemonic> {
+    let name = name.to_lowercase().replace('.', "_");
+    INSTRUCTIONS
+        .values()
+        .map(|row| row.0)
+        .chain(FLOAT_INSTRUCTIONS.iter().map(|(_, _, row)| row.0))
+        .chain(SYSTEM_INSTRUCTIONS.values().map(|row| row.0))
+        .find(|mnemonic| *mnemonic == name)
+        .map(Mnemonic::from)
+}
+
+/// Finds the encoding of a system instruction without operands (e.g. `ecall`).
+pub fn find_system_instr_from_mnemonic(mnemonic: &Mnemonic) -> Option<u32> {
+    SYSTEM_INSTRUCTIONS
+        .iter()
+        .find(|(_, row)| Mnemonic::from(row.0) == *mnemonic)
+        .map(|(instr, _)| *instr)
+}
+
+/// Finds the register classes of rd, rs1, rs2 and rs3 of a floating-point instruction
+/// (`None` if the field isn't a register operand).
+pub fn find_float_register_classes(mnemonic: &Mnemonic) -> Option<[Option<RegisterClass>; 4]> {
+    FLOAT_INSTRUCTIONS
+        .iter()
+        .find(|(_, _, row)| Mnemonic::from(row.0) == *mnemonic)
+        .map(|(_, _, row)| row.3)
+}
+
 pub fn find_instr_from_mnemonic(mnemonic: Mnemonic) -> Option<(Opcode, Funct3, Funct7)> {
+    // entries of opcodes that aren't modelled are skipped
     INSTRUCTIONS.iter().find_map(|(k, v)| {
         if Mnemonic::from(v.0) == mnemonic {
-            let opcode = Opcode::try_from((0b11 | ((k & 0b11111) << 2)) as u8).unwrap();
-            let funct3 = Funct3::try_from(((k >> 5) & 0b111) as u8).unwrap();
-            let funct7 = Funct7::try_from((k >> 8) as u8).unwrap();
+            let opcode = Opcode::try_from((0b11 | ((k & 0b11111) << 2)) as u8).ok()?;
+            let funct3 = Funct3::try_from(((k >> 5) & 0b111) as u8).ok()?;
+            let funct7 = Funct7::try_from((k >> 8) as u8).ok()?;
             Some((opcode, funct3, funct7))
         } else {
             None
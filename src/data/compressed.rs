@@ -0,0 +1,66 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use crate::model::CompressedOp as OP;
+use crate::model::RISCVExtension as EXT;
+
+type Row = (&'static str, EXT, u8);
+
+pub(crate) static COMPRESSED_INSTRUCTIONS: Lazy<HashMap<OP, Row>> = Lazy::new(|| {
+    HashMap::from([
+        (OP::Addi4spn, ("c_addi4spn", EXT::C, 32)),
+        (OP::Fld, ("c_fld", EXT::C, 32)),
+        (OP::Lw, ("c_lw", EXT::C, 32)),
+        (OP::Flw, ("c_flw", EXT::C, 32)),
+        (OP::Lbu, ("c_lbu", EXT::Zcb, 32)),
+        (OP::Lhu, ("c_lhu", EXT::Zcb, 32)),
+        (OP::Lh, ("c_lh", EXT::Zcb, 32)),
+        (OP::Sb, ("c_sb", EXT::Zcb, 32)),
+        (OP::Sh, ("c_sh", EXT::Zcb, 32)),
+        (OP::Fsd, ("c_fsd", EXT::C, 32)),
+        (OP::Sw, ("c_sw", EXT::C, 32)),
+        (OP::Fsw, ("c_fsw", EXT::C, 32)),
+        (OP::Nop, ("c_nop", EXT::C, 32)),
+        (OP::Addi, ("c_addi", EXT::C, 32)),
+        (OP::Jal, ("c_jal", EXT::C, 32)),
+        (OP::Li, ("c_li", EXT::C, 32)),
+        (OP::Addi16sp, ("c_addi16sp", EXT::C, 32)),
+        (OP::Lui, ("c_lui", EXT::C, 32)),
+        (OP::Srli, ("c_srli", EXT::C, 32)),
+        (OP::Srai, ("c_srai", EXT::C, 32)),
+        (OP::Andi, ("c_andi", EXT::C, 32)),
+        (OP::Sub, ("c_sub", EXT::C, 32)),
+        (OP::Xor, ("c_xor", EXT::C, 32)),
+        (OP::Or, ("c_or", EXT::C, 32)),
+        (OP::And, ("c_and", EXT::C, 32)),
+        (OP::Mul, ("c_mul", EXT::Zcb, 32)),
+        (OP::ZextB, ("c_zext_b", EXT::Zcb, 32)),
+        (OP::SextB, ("c_sext_b", EXT::Zcb, 32)),
+        (OP::ZextH, ("c_zext_h", EXT::Zcb, 32)),
+        (OP::SextH, ("c_sext_h", EXT::Zcb, 32)),
+        (OP::Not, ("c_not", EXT::Zcb, 32)),
+        (OP::J, ("c_j", EXT::C, 32)),
+        (OP::Beqz, ("c_beqz", EXT::C, 32)),
+        (OP::Bnez, ("c_bnez", EXT::C, 32)),
+        (OP::Slli, ("c_slli", EXT::C, 32)),
+        (OP::Fldsp, ("c_fldsp", EXT::C, 32)),
+        (OP::Lwsp, ("c_lwsp", EXT::C, 32)),
+        (OP::Flwsp, ("c_flwsp", EXT::C, 32)),
+        (OP::Jr, ("c_jr", EXT::C, 32)),
+        (OP::Mv, ("c_mv", EXT::C, 32)),
+        (OP::Ebreak, ("c_ebreak", EXT::C, 32)),
+        (OP::Jalr, ("c_jalr", EXT::C, 32)),
+        (OP::Add, ("c_add", EXT::C, 32)),
+        (OP::Fsdsp, ("c_fsdsp", EXT::C, 32)),
+        (OP::Swsp, ("c_swsp", EXT::C, 32)),
+        (OP::Fswsp, ("c_fswsp", EXT::C, 32)),
+        (OP::Ld, ("c_ld", EXT::C, 64)),
+        (OP::Sd, ("c_sd", EXT::C, 64)),
+        (OP::Addiw, ("c_addiw", EXT::C, 64)),
+        (OP::Subw, ("c_subw", EXT::C, 64)),
+        (OP::Addw, ("c_addw", EXT::C, 64)),
+        (OP::ZextW, ("c_zext_w", EXT::Zcb, 64)),
+        (OP::Ldsp, ("c_ldsp", EXT::C, 64)),
+        (OP::Sdsp, ("c_sdsp", EXT::C, 64)),
+    ])
+});
@@ -0,0 +1,1607 @@
+// this is generated code, don't modify it manually!
+
+use crate::model::RISCVExtension as EXT;
+use crate::model::RegisterClass::{self, Float as F, Integer as X};
+
+/// Register classes of rd, rs1, rs2 and rs3 (`None` if not a register operand)
+type Classes = [Option<RegisterClass>; 4];
+
+/// Mnemonic, extension, XLEN, register classes and whether funct3 is the rounding mode
+type Row = (&'static str, EXT, u8, Classes, bool);
+
+/// Floating-point instructions as `(mask, match, row)`.
+pub(crate) static FLOAT_INSTRUCTIONS: &[(u32, u32, Row)] = &[
+    (
+        0xfe00007f,
+        0x2000053,
+        (
+            "fadd_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x4000053,
+        (
+            "fadd_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x6000053,
+        (
+            "fadd_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x53,
+        (
+            "fadd_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xe2001053,
+        (
+            "fclass_d",
+            EXT::D,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xe4001053,
+        (
+            "fclass_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xe6001053,
+        (
+            "fclass_q",
+            EXT::Q,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xe0001053,
+        (
+            "fclass_s",
+            EXT::F,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x44800053,
+        (
+            "fcvt_bf16_s",
+            EXT::Zfbfmin,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x42200053,
+        (
+            "fcvt_d_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd2200053,
+        ("fcvt_d_l", EXT::D, 64, [Some(F), Some(X), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd2300053,
+        (
+            "fcvt_d_lu",
+            EXT::D,
+            64,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x42300053,
+        ("fcvt_d_q", EXT::Q, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0x42000053,
+        ("fcvt_d_s", EXT::D, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd2000053,
+        ("fcvt_d_w", EXT::D, 32, [Some(F), Some(X), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd2100053,
+        (
+            "fcvt_d_wu",
+            EXT::D,
+            32,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x44100053,
+        (
+            "fcvt_h_d",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd4200053,
+        (
+            "fcvt_h_l",
+            EXT::Zfh,
+            64,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd4300053,
+        (
+            "fcvt_h_lu",
+            EXT::Zfh,
+            64,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x44300053,
+        (
+            "fcvt_h_q",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x44000053,
+        (
+            "fcvt_h_s",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd4000053,
+        (
+            "fcvt_h_w",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd4100053,
+        (
+            "fcvt_h_wu",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc2200053,
+        ("fcvt_l_d", EXT::D, 64, [Some(X), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xc4200053,
+        (
+            "fcvt_l_h",
+            EXT::Zfh,
+            64,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc6200053,
+        ("fcvt_l_q", EXT::Q, 64, [Some(X), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xc0200053,
+        ("fcvt_l_s", EXT::F, 64, [Some(X), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xc2300053,
+        (
+            "fcvt_lu_d",
+            EXT::D,
+            64,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc4300053,
+        (
+            "fcvt_lu_h",
+            EXT::Zfh,
+            64,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc6300053,
+        (
+            "fcvt_lu_q",
+            EXT::Q,
+            64,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc0300053,
+        (
+            "fcvt_lu_s",
+            EXT::F,
+            64,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x46100053,
+        ("fcvt_q_d", EXT::Q, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0x46200053,
+        (
+            "fcvt_q_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd6200053,
+        ("fcvt_q_l", EXT::Q, 64, [Some(F), Some(X), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd6300053,
+        (
+            "fcvt_q_lu",
+            EXT::Q,
+            64,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x46000053,
+        ("fcvt_q_s", EXT::Q, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd6000053,
+        ("fcvt_q_w", EXT::Q, 32, [Some(F), Some(X), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd6100053,
+        (
+            "fcvt_q_wu",
+            EXT::Q,
+            32,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x40600053,
+        (
+            "fcvt_s_bf16",
+            EXT::Zfbfmin,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x40100053,
+        ("fcvt_s_d", EXT::D, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0x40200053,
+        (
+            "fcvt_s_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xd0200053,
+        ("fcvt_s_l", EXT::F, 64, [Some(F), Some(X), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd0300053,
+        (
+            "fcvt_s_lu",
+            EXT::F,
+            64,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x40300053,
+        ("fcvt_s_q", EXT::Q, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd0000053,
+        ("fcvt_s_w", EXT::F, 32, [Some(F), Some(X), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xd0100053,
+        (
+            "fcvt_s_wu",
+            EXT::F,
+            32,
+            [Some(F), Some(X), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc2000053,
+        ("fcvt_w_d", EXT::D, 32, [Some(X), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xc4000053,
+        (
+            "fcvt_w_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc6000053,
+        ("fcvt_w_q", EXT::Q, 32, [Some(X), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xc0000053,
+        ("fcvt_w_s", EXT::F, 32, [Some(X), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0xc2100053,
+        (
+            "fcvt_wu_d",
+            EXT::D,
+            32,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc4100053,
+        (
+            "fcvt_wu_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc6100053,
+        (
+            "fcvt_wu_q",
+            EXT::Q,
+            32,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0xc0100053,
+        (
+            "fcvt_wu_s",
+            EXT::F,
+            32,
+            [Some(X), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xc2801053,
+        (
+            "fcvtmod_w_d",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x1a000053,
+        (
+            "fdiv_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x1c000053,
+        (
+            "fdiv_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x1e000053,
+        (
+            "fdiv_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x18000053,
+        (
+            "fdiv_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa2002053,
+        (
+            "feq_d",
+            EXT::D,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa4002053,
+        (
+            "feq_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa6002053,
+        (
+            "feq_q",
+            EXT::Q,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa0002053,
+        (
+            "feq_s",
+            EXT::F,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0x707f,
+        0x3007,
+        ("fld", EXT::D, 32, [Some(F), Some(X), None, None], false),
+    ),
+    (
+        0xfe00707f,
+        0xa2000053,
+        (
+            "fle_d",
+            EXT::D,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa4000053,
+        (
+            "fle_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa6000053,
+        (
+            "fle_q",
+            EXT::Q,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa0000053,
+        (
+            "fle_s",
+            EXT::F,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa2004053,
+        (
+            "fleq_d",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa4004053,
+        (
+            "fleq_h",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa6004053,
+        (
+            "fleq_q",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa0004053,
+        (
+            "fleq_s",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0x707f,
+        0x1007,
+        ("flh", EXT::Zfh, 32, [Some(F), Some(X), None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xf2100053,
+        ("fli_d", EXT::Zfa, 32, [Some(F), None, None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xf4100053,
+        ("fli_h", EXT::Zfa, 32, [Some(F), None, None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xf6100053,
+        ("fli_q", EXT::Zfa, 32, [Some(F), None, None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xf0100053,
+        ("fli_s", EXT::Zfa, 32, [Some(F), None, None, None], false),
+    ),
+    (
+        0x707f,
+        0x4007,
+        ("flq", EXT::Q, 32, [Some(F), Some(X), None, None], false),
+    ),
+    (
+        0xfe00707f,
+        0xa2001053,
+        (
+            "flt_d",
+            EXT::D,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa4001053,
+        (
+            "flt_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa6001053,
+        (
+            "flt_q",
+            EXT::Q,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa0001053,
+        (
+            "flt_s",
+            EXT::F,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa2005053,
+        (
+            "fltq_d",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa4005053,
+        (
+            "fltq_h",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa6005053,
+        (
+            "fltq_q",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xa0005053,
+        (
+            "fltq_s",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0x707f,
+        0x2007,
+        ("flw", EXT::F, 32, [Some(F), Some(X), None, None], false),
+    ),
+    (
+        0x600007f,
+        0x2000043,
+        (
+            "fmadd_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x4000043,
+        (
+            "fmadd_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x6000043,
+        (
+            "fmadd_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x43,
+        (
+            "fmadd_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2a001053,
+        (
+            "fmax_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2c001053,
+        (
+            "fmax_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2e001053,
+        (
+            "fmax_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x28001053,
+        (
+            "fmax_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2a003053,
+        (
+            "fmaxm_d",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2c003053,
+        (
+            "fmaxm_h",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2e003053,
+        (
+            "fmaxm_q",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x28003053,
+        (
+            "fmaxm_s",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2a000053,
+        (
+            "fmin_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2c000053,
+        (
+            "fmin_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2e000053,
+        (
+            "fmin_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x28000053,
+        (
+            "fmin_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2a002053,
+        (
+            "fminm_d",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2c002053,
+        (
+            "fminm_h",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x2e002053,
+        (
+            "fminm_q",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x28002053,
+        (
+            "fminm_s",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0x600007f,
+        0x2000047,
+        (
+            "fmsub_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x4000047,
+        (
+            "fmsub_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x6000047,
+        (
+            "fmsub_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x47,
+        (
+            "fmsub_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x12000053,
+        (
+            "fmul_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x14000053,
+        (
+            "fmul_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x16000053,
+        (
+            "fmul_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x10000053,
+        (
+            "fmul_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xf2000053,
+        ("fmv_d_x", EXT::D, 64, [Some(F), Some(X), None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xf4000053,
+        (
+            "fmv_h_x",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(X), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xf0000053,
+        ("fmv_w_x", EXT::F, 32, [Some(F), Some(X), None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xe2000053,
+        ("fmv_x_d", EXT::D, 64, [Some(X), Some(F), None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xe4000053,
+        (
+            "fmv_x_h",
+            EXT::Zfh,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xe0000053,
+        ("fmv_x_w", EXT::F, 32, [Some(X), Some(F), None, None], false),
+    ),
+    (
+        0xfff0707f,
+        0xe2100053,
+        (
+            "fmvh_x_d",
+            EXT::Zfa,
+            32,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfff0707f,
+        0xe6100053,
+        (
+            "fmvh_x_q",
+            EXT::Zfa,
+            64,
+            [Some(X), Some(F), None, None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xb2000053,
+        (
+            "fmvp_d_x",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(X), Some(X), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xb6000053,
+        (
+            "fmvp_q_x",
+            EXT::Zfa,
+            64,
+            [Some(F), Some(X), Some(X), None],
+            false,
+        ),
+    ),
+    (
+        0x600007f,
+        0x200004f,
+        (
+            "fnmadd_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x400004f,
+        (
+            "fnmadd_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x600004f,
+        (
+            "fnmadd_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x4f,
+        (
+            "fnmadd_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x200004b,
+        (
+            "fnmsub_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x400004b,
+        (
+            "fnmsub_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x600004b,
+        (
+            "fnmsub_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0x600007f,
+        0x4b,
+        (
+            "fnmsub_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), Some(F)],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x42400053,
+        (
+            "fround_d",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x44400053,
+        (
+            "fround_h",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x46400053,
+        (
+            "fround_q",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x40400053,
+        (
+            "fround_s",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x42500053,
+        (
+            "froundnx_d",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x44500053,
+        (
+            "froundnx_h",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x46500053,
+        (
+            "froundnx_q",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x40500053,
+        (
+            "froundnx_s",
+            EXT::Zfa,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0x707f,
+        0x3027,
+        ("fsd", EXT::D, 32, [None, Some(X), Some(F), None], false),
+    ),
+    (
+        0xfe00707f,
+        0x22000053,
+        (
+            "fsgnj_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x24000053,
+        (
+            "fsgnj_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x26000053,
+        (
+            "fsgnj_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x20000053,
+        (
+            "fsgnj_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x22001053,
+        (
+            "fsgnjn_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x24001053,
+        (
+            "fsgnjn_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x26001053,
+        (
+            "fsgnjn_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x20001053,
+        (
+            "fsgnjn_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x22002053,
+        (
+            "fsgnjx_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x24002053,
+        (
+            "fsgnjx_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x26002053,
+        (
+            "fsgnjx_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x20002053,
+        (
+            "fsgnjx_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            false,
+        ),
+    ),
+    (
+        0x707f,
+        0x1027,
+        ("fsh", EXT::Zfh, 32, [None, Some(X), Some(F), None], false),
+    ),
+    (
+        0x707f,
+        0x4027,
+        ("fsq", EXT::Q, 32, [None, Some(X), Some(F), None], false),
+    ),
+    (
+        0xfff0007f,
+        0x5a000053,
+        ("fsqrt_d", EXT::D, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0x5c000053,
+        (
+            "fsqrt_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), None, None],
+            true,
+        ),
+    ),
+    (
+        0xfff0007f,
+        0x5e000053,
+        ("fsqrt_q", EXT::Q, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfff0007f,
+        0x58000053,
+        ("fsqrt_s", EXT::F, 32, [Some(F), Some(F), None, None], true),
+    ),
+    (
+        0xfe00007f,
+        0xa000053,
+        (
+            "fsub_d",
+            EXT::D,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0xc000053,
+        (
+            "fsub_h",
+            EXT::Zfh,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0xe000053,
+        (
+            "fsub_q",
+            EXT::Q,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0xfe00007f,
+        0x8000053,
+        (
+            "fsub_s",
+            EXT::F,
+            32,
+            [Some(F), Some(F), Some(F), None],
+            true,
+        ),
+    ),
+    (
+        0x707f,
+        0x2027,
+        ("fsw", EXT::F, 32, [None, Some(X), Some(F), None], false),
+    ),
+];
@@ -11,6 +11,37 @@ pub(crate) static INSTRUCTIONS: Lazy<HashMap<u16, Row>> = Lazy::new(|| {
     HashMap::from([
         (0xc, ("add", EXT::I, 32)),
         (0x4, ("addi", EXT::I, 32)),
+        (0xb, ("amoadd_b", EXT::Zabha, 32)),
+        (0x2b, ("amoadd_h", EXT::Zabha, 32)),
+        (0x4b, ("amoadd_w", EXT::A, 32)),
+        (0x300b, ("amoand_b", EXT::Zabha, 32)),
+        (0x302b, ("amoand_h", EXT::Zabha, 32)),
+        (0x304b, ("amoand_w", EXT::A, 32)),
+        (0x140b, ("amocas_b", EXT::Zabha, 32)),
+        (0x146b, ("amocas_d", EXT::Zacas, 32)),
+        (0x142b, ("amocas_h", EXT::Zabha, 32)),
+        (0x144b, ("amocas_w", EXT::Zacas, 32)),
+        (0x500b, ("amomax_b", EXT::Zabha, 32)),
+        (0x502b, ("amomax_h", EXT::Zabha, 32)),
+        (0x504b, ("amomax_w", EXT::A, 32)),
+        (0x700b, ("amomaxu_b", EXT::Zabha, 32)),
+        (0x702b, ("amomaxu_h", EXT::Zabha, 32)),
+        (0x704b, ("amomaxu_w", EXT::A, 32)),
+        (0x400b, ("amomin_b", EXT::Zabha, 32)),
+        (0x402b, ("amomin_h", EXT::Zabha, 32)),
+        (0x404b, ("amomin_w", EXT::A, 32)),
+        (0x600b, ("amominu_b", EXT::Zabha, 32)),
+        (0x602b, ("amominu_h", EXT::Zabha, 32)),
+        (0x604b, ("amominu_w", EXT::A, 32)),
+        (0x200b, ("amoor_b", EXT::Zabha, 32)),
+        (0x202b, ("amoor_h", EXT::Zabha, 32)),
+        (0x204b, ("amoor_w", EXT::A, 32)),
+        (0x40b, ("amoswap_b", EXT::Zabha, 32)),
+        (0x42b, ("amoswap_h", EXT::Zabha, 32)),
+        (0x44b, ("amoswap_w", EXT::A, 32)),
+        (0x100b, ("amoxor_b", EXT::Zabha, 32)),
+        (0x102b, ("amoxor_h", EXT::Zabha, 32)),
+        (0x104b, ("amoxor_w", EXT::A, 32)),
         (0xec, ("and", EXT::I, 32)),
         (0xe4, ("andi", EXT::I, 32)),
         (0x20ec, ("andn", EXT::Zbb, 32)),
@@ -40,68 +71,13 @@ pub(crate) static INSTRUCTIONS: Lazy<HashMap<u16, Row>> = Lazy::new(|| {
         (0x1ac, ("divu", EXT::M, 32)),
         (0x3, ("fence", EXT::I, 32)),
         (0x23, ("fence_i", EXT::Zifencei, 32)),
-        (0x5154, ("feq_d", EXT::D, 32)),
-        (0x5254, ("feq_h", EXT::Zfh, 32)),
-        (0x5354, ("feq_q", EXT::Q, 32)),
-        (0x5054, ("feq_s", EXT::F, 32)),
-        (0x61, ("fld", EXT::D, 32)),
-        (0x5114, ("fle_d", EXT::D, 32)),
-        (0x5214, ("fle_h", EXT::Zfh, 32)),
-        (0x5314, ("fle_q", EXT::Q, 32)),
-        (0x5014, ("fle_s", EXT::F, 32)),
-        (0x5194, ("fleq_d", EXT::Zfa, 32)),
-        (0x5294, ("fleq_h", EXT::Zfa, 32)),
-        (0x5394, ("fleq_q", EXT::Zfa, 32)),
-        (0x5094, ("fleq_s", EXT::Zfa, 32)),
-        (0x21, ("flh", EXT::Zfh, 32)),
-        (0x81, ("flq", EXT::Q, 32)),
-        (0x5134, ("flt_d", EXT::D, 32)),
-        (0x5234, ("flt_h", EXT::Zfh, 32)),
-        (0x5334, ("flt_q", EXT::Q, 32)),
-        (0x5034, ("flt_s", EXT::F, 32)),
-        (0x51b4, ("fltq_d", EXT::Zfa, 32)),
-        (0x52b4, ("fltq_h", EXT::Zfa, 32)),
-        (0x53b4, ("fltq_q", EXT::Zfa, 32)),
-        (0x50b4, ("fltq_s", EXT::Zfa, 32)),
-        (0x41, ("flw", EXT::F, 32)),
-        (0x1534, ("fmax_d", EXT::D, 32)),
-        (0x1634, ("fmax_h", EXT::Zfh, 32)),
-        (0x1734, ("fmax_q", EXT::Q, 32)),
-        (0x1434, ("fmax_s", EXT::F, 32)),
-        (0x1574, ("fmaxm_d", EXT::Zfa, 32)),
-        (0x1674, ("fmaxm_h", EXT::Zfa, 32)),
-        (0x1774, ("fmaxm_q", EXT::Zfa, 32)),
-        (0x1474, ("fmaxm_s", EXT::Zfa, 32)),
-        (0x1514, ("fmin_d", EXT::D, 32)),
-        (0x1614, ("fmin_h", EXT::Zfh, 32)),
-        (0x1714, ("fmin_q", EXT::Q, 32)),
-        (0x1414, ("fmin_s", EXT::F, 32)),
-        (0x1554, ("fminm_d", EXT::Zfa, 32)),
-        (0x1654, ("fminm_h", EXT::Zfa, 32)),
-        (0x1754, ("fminm_q", EXT::Zfa, 32)),
-        (0x1454, ("fminm_s", EXT::Zfa, 32)),
-        (0x69, ("fsd", EXT::D, 32)),
-        (0x1114, ("fsgnj_d", EXT::D, 32)),
-        (0x1214, ("fsgnj_h", EXT::Zfh, 32)),
-        (0x1314, ("fsgnj_q", EXT::Q, 32)),
-        (0x1014, ("fsgnj_s", EXT::F, 32)),
-        (0x1134, ("fsgnjn_d", EXT::D, 32)),
-        (0x1234, ("fsgnjn_h", EXT::Zfh, 32)),
-        (0x1334, ("fsgnjn_q", EXT::Q, 32)),
-        (0x1034, ("fsgnjn_s", EXT::F, 32)),
-        (0x1154, ("fsgnjx_d", EXT::D, 32)),
-        (0x1254, ("fsgnjx_h", EXT::Zfh, 32)),
-        (0x1354, ("fsgnjx_q", EXT::Q, 32)),
-        (0x1054, ("fsgnjx_s", EXT::F, 32)),
-        (0x29, ("fsh", EXT::Zfh, 32)),
-        (0x89, ("fsq", EXT::Q, 32)),
-        (0x49, ("fsw", EXT::F, 32)),
         (0x1b, ("jal", EXT::I, 32)),
         (0x19, ("jalr", EXT::I, 32)),
         (0x0, ("lb", EXT::I, 32)),
         (0x80, ("lbu", EXT::I, 32)),
         (0x20, ("lh", EXT::I, 32)),
         (0xa0, ("lhu", EXT::I, 32)),
+        (0x84b, ("lr_w", EXT::A, 32)),
         (0xd, ("lui", EXT::I, 32)),
         (0x40, ("lw", EXT::I, 32)),
         (0x5cc, ("max", EXT::Zbb, 32)),
@@ -117,11 +93,13 @@ pub(crate) static INSTRUCTIONS: Lazy<HashMap<u16, Row>> = Lazy::new(|| {
         (0x20cc, ("orn", EXT::Zbb, 32)),
         (0x48c, ("pack", EXT::Zbkb, 32)),
         (0x4ec, ("packh", EXT::Zbkb, 32)),
+        (0x48e, ("packw", EXT::Zbkb, 64)),
         (0x1cc, ("rem", EXT::M, 32)),
         (0x1ec, ("remu", EXT::M, 32)),
         (0x302c, ("rol", EXT::Zbb, 32)),
         (0x30ac, ("ror", EXT::Zbb, 32)),
         (0x8, ("sb", EXT::I, 32)),
+        (0xc4b, ("sc_w", EXT::A, 32)),
         (0x28, ("sh", EXT::I, 32)),
         (0x104c, ("sh1add", EXT::Zba, 32)),
         (0x108c, ("sh2add", EXT::Zba, 32)),
@@ -133,50 +111,59 @@ pub(crate) static INSTRUCTIONS: Lazy<HashMap<u16, Row>> = Lazy::new(|| {
         (0x6c, ("sltu", EXT::I, 32)),
         (0x20ac, ("sra", EXT::I, 32)),
         (0xac, ("srl", EXT::I, 32)),
+        (0x246b, ("ssamoswap_d", EXT::Zicfiss, 32)),
+        (0x244b, ("ssamoswap_w", EXT::Zicfiss, 32)),
         (0x200c, ("sub", EXT::I, 32)),
         (0x48, ("sw", EXT::I, 32)),
-        (0x2075, ("vadc_vim", EXT::V, 32)),
-        (0x2015, ("vadc_vvm", EXT::V, 32)),
-        (0x2095, ("vadc_vxm", EXT::V, 32)),
-        (0x455d, ("vaeskf1_vi", EXT::Zvkned, 32)),
-        (0x555d, ("vaeskf2_vi", EXT::Zvkned, 32)),
-        (0x2f55, ("vcompress_vm", EXT::V, 32)),
-        (0x2eb5, ("vfmerge_vfm", EXT::V, 32)),
-        (0x595d, ("vghsh_vv", EXT::Zvkg, 32)),
-        (0x2375, ("vmadc_vi", EXT::V, 32)),
-        (0x2275, ("vmadc_vim", EXT::V, 32)),
-        (0x2315, ("vmadc_vv", EXT::V, 32)),
-        (0x2215, ("vmadc_vvm", EXT::V, 32)),
-        (0x2395, ("vmadc_vx", EXT::V, 32)),
-        (0x2295, ("vmadc_vxm", EXT::V, 32)),
-        (0x3355, ("vmand_mm", EXT::V, 32)),
-        (0x3155, ("vmandn_mm", EXT::V, 32)),
-        (0x2e75, ("vmerge_vim", EXT::V, 32)),
-        (0x2e15, ("vmerge_vvm", EXT::V, 32)),
-        (0x2e95, ("vmerge_vxm", EXT::V, 32)),
-        (0x3b55, ("vmnand_mm", EXT::V, 32)),
-        (0x3d55, ("vmnor_mm", EXT::V, 32)),
-        (0x3555, ("vmor_mm", EXT::V, 32)),
-        (0x3955, ("vmorn_mm", EXT::V, 32)),
-        (0x2715, ("vmsbc_vv", EXT::V, 32)),
-        (0x2615, ("vmsbc_vvm", EXT::V, 32)),
-        (0x2795, ("vmsbc_vx", EXT::V, 32)),
-        (0x2695, ("vmsbc_vxm", EXT::V, 32)),
-        (0x3f55, ("vmxnor_mm", EXT::V, 32)),
-        (0x3755, ("vmxor_mm", EXT::V, 32)),
-        (0x2415, ("vsbc_vvm", EXT::V, 32)),
-        (0x2495, ("vsbc_vxm", EXT::V, 32)),
-        (0x40f5, ("vsetvl", EXT::V, 32)),
-        (0x5d5d, ("vsha2ch_vv", EXT::Zvknha, 32)),
-        (0x5f5d, ("vsha2cl_vv", EXT::Zvknha, 32)),
-        (0x5b5d, ("vsha2ms_vv", EXT::Zvknha, 32)),
-        (0x575d, ("vsm3c_vi", EXT::Zvksh, 32)),
-        (0x415d, ("vsm3me_vv", EXT::Zvksh, 32)),
-        (0x435d, ("vsm4k_vi", EXT::Zvksed, 32)),
         (0x208c, ("xnor", EXT::Zbb, 32)),
         (0x8c, ("xor", EXT::I, 32)),
         (0x84, ("xori", EXT::I, 32)),
         (0x144c, ("xperm4", EXT::Zbkx, 32)),
         (0x148c, ("xperm8", EXT::Zbkx, 32)),
+        (0x6, ("addiw", EXT::I, 64)),
+        (0x26, ("slliw", EXT::I, 64)),
+        (0xa6, ("srliw", EXT::I, 64)),
+        (0x20a6, ("sraiw", EXT::I, 64)),
+        (0xe, ("addw", EXT::I, 64)),
+        (0x200e, ("subw", EXT::I, 64)),
+        (0x2e, ("sllw", EXT::I, 64)),
+        (0xae, ("srlw", EXT::I, 64)),
+        (0x20ae, ("sraw", EXT::I, 64)),
+        (0x60, ("ld", EXT::I, 64)),
+        (0xc0, ("lwu", EXT::I, 64)),
+        (0x68, ("sd", EXT::I, 64)),
+        (0x24, ("slli", EXT::I, 32)),
+        (0xa4, ("srli", EXT::I, 32)),
+        (0x20a4, ("srai", EXT::I, 32)),
+        (0x10e, ("mulw", EXT::M, 64)),
+        (0x18e, ("divw", EXT::M, 64)),
+        (0x1ae, ("divuw", EXT::M, 64)),
+        (0x1ce, ("remw", EXT::M, 64)),
+        (0x1ee, ("remuw", EXT::M, 64)),
+        (0x40e, ("add_uw", EXT::Zba, 64)),
+        (0x104e, ("sh1add_uw", EXT::Zba, 64)),
+        (0x108e, ("sh2add_uw", EXT::Zba, 64)),
+        (0x10ce, ("sh3add_uw", EXT::Zba, 64)),
+        (0x302e, ("rolw", EXT::Zbb, 64)),
+        (0x30ae, ("rorw", EXT::Zbb, 64)),
+        (0x30a4, ("rori", EXT::Zbb, 32)),
+        (0x30a6, ("roriw", EXT::Zbb, 64)),
+        (0x426, ("slli_uw", EXT::Zba, 64)),
+        (0x2424, ("bclri", EXT::Zbs, 32)),
+        (0x24a4, ("bexti", EXT::Zbs, 32)),
+        (0x3424, ("binvi", EXT::Zbs, 32)),
+        (0x1424, ("bseti", EXT::Zbs, 32)),
+        (0x86b, ("lr_d", EXT::A, 64)),
+        (0xc6b, ("sc_d", EXT::A, 64)),
+        (0x46b, ("amoswap_d", EXT::A, 64)),
+        (0x6b, ("amoadd_d", EXT::A, 64)),
+        (0x106b, ("amoxor_d", EXT::A, 64)),
+        (0x306b, ("amoand_d", EXT::A, 64)),
+        (0x206b, ("amoor_d", EXT::A, 64)),
+        (0x406b, ("amomin_d", EXT::A, 64)),
+        (0x506b, ("amomax_d", EXT::A, 64)),
+        (0x606b, ("amominu_d", EXT::A, 64)),
+        (0x706b, ("amomaxu_d", EXT::A, 64)),
+        (0x148b, ("amocas_q", EXT::Zacas, 64)),
     ])
 });
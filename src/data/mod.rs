@@ -1,7 +1,15 @@
-mod instructions;
 mod access;
+mod compressed;
+mod csr;
+mod float;
+mod instructions;
 mod system;
+mod vector;
 
-pub (crate) use instructions::INSTRUCTIONS;
-pub (crate) use system::SYSTEM_INSTRUCTIONS;
 pub use access::*;
+pub(crate) use compressed::COMPRESSED_INSTRUCTIONS;
+pub(crate) use csr::CSR_NAMES;
+pub(crate) use float::FLOAT_INSTRUCTIONS;
+pub(crate) use instructions::INSTRUCTIONS;
+pub(crate) use system::SYSTEM_INSTRUCTIONS;
+pub(crate) use vector::VECTOR_INSTRUCTIONS;
@@ -0,0 +1,5840 @@
+// this is generated code, don't modify it manually!
+
+use crate::model::RISCVExtension as EXT;
+use crate::model::VectorOperand as VOP;
+
+type Row = (&'static str, EXT, u8, &'static [VOP]);
+
+/// Vector instructions as `(mask, match, row)`, the most specific masks first.
+pub(crate) static VECTOR_INSTRUCTIONS: &[(u32, u32, Row)] = &[
+    (
+        0xfdfff07f,
+        0x5008a057,
+        ("vid_v", EXT::V, 32, &[VOP::Vd, VOP::Mask]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa600a077,
+        ("vaesdf_vs", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa200a077,
+        ("vaesdf_vv", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa6002077,
+        ("vaesdm_vs", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa2002077,
+        ("vaesdm_vv", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa601a077,
+        ("vaesef_vs", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa201a077,
+        ("vaesef_vv", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa6012077,
+        ("vaesem_vs", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa2012077,
+        ("vaesem_vv", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa603a077,
+        ("vaesz_vs", EXT::Zvkned, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0x42001057,
+        ("vfmv_f_s", EXT::V, 32, &[VOP::Fd, VOP::Vs2]),
+    ),
+    (
+        0xfff0707f,
+        0x42005057,
+        ("vfmv_s_f", EXT::V, 32, &[VOP::Vd, VOP::Fs1]),
+    ),
+    (
+        0xfff0707f,
+        0x5e005057,
+        ("vfmv_v_f", EXT::V, 32, &[VOP::Vd, VOP::Fs1]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa208a077,
+        ("vgmul_vv", EXT::Zvkg, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfff0707f,
+        0x2805007,
+        ("vl1re16_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x2806007,
+        ("vl1re32_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x2807007,
+        ("vl1re64_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x2800007,
+        ("vl1re8_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x22805007,
+        ("vl2re16_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x22806007,
+        ("vl2re32_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x22807007,
+        ("vl2re64_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x22800007,
+        ("vl2re8_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x62805007,
+        ("vl4re16_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x62806007,
+        ("vl4re32_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x62807007,
+        ("vl4re64_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x62800007,
+        ("vl4re8_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0xe2805007,
+        ("vl8re16_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0xe2806007,
+        ("vl8re32_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0xe2807007,
+        ("vl8re64_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0xe2800007,
+        ("vl8re8_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x2b00007,
+        ("vlm_v", EXT::V, 32, &[VOP::Vd, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x42006057,
+        ("vmv_s_x", EXT::V, 32, &[VOP::Vd, VOP::Rs1]),
+    ),
+    (
+        0xfff0707f,
+        0x5e003057,
+        ("vmv_v_i", EXT::V, 32, &[VOP::Vd, VOP::Simm5]),
+    ),
+    (
+        0xfff0707f,
+        0x5e000057,
+        ("vmv_v_v", EXT::V, 32, &[VOP::Vd, VOP::Vs1]),
+    ),
+    (
+        0xfff0707f,
+        0x5e004057,
+        ("vmv_v_x", EXT::V, 32, &[VOP::Vd, VOP::Rs1]),
+    ),
+    (
+        0xfe0ff07f,
+        0x42002057,
+        ("vmv_x_s", EXT::V, 32, &[VOP::Rd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0x9e003057,
+        ("vmv1r_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0x9e00b057,
+        ("vmv2r_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0x9e01b057,
+        ("vmv4r_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0x9e03b057,
+        ("vmv8r_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfff0707f,
+        0x2800027,
+        ("vs1r_v", EXT::V, 32, &[VOP::Vs3, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x22800027,
+        ("vs2r_v", EXT::V, 32, &[VOP::Vs3, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x62800027,
+        ("vs4r_v", EXT::V, 32, &[VOP::Vs3, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0xe2800027,
+        ("vs8r_v", EXT::V, 32, &[VOP::Vs3, VOP::Base]),
+    ),
+    (
+        0xfff0707f,
+        0x2b00027,
+        ("vsm_v", EXT::V, 32, &[VOP::Vs3, VOP::Base]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa6082077,
+        ("vsm4r_vs", EXT::Zvksed, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfe0ff07f,
+        0xa2082077,
+        ("vsm4r_vv", EXT::Zvksed, 32, &[VOP::Vd, VOP::Vs2]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48052057,
+        ("vbrev_v", EXT::Zvbb, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48042057,
+        ("vbrev8_v", EXT::Zvbb, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48062057,
+        ("vclz_v", EXT::Zvbb, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x40082057,
+        ("vcpop_m", EXT::V, 32, &[VOP::Rd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48072057,
+        ("vcpop_v", EXT::Zvbb, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4806a057,
+        ("vctz_v", EXT::Zvbb, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4c081057,
+        ("vfclass_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48019057,
+        ("vfcvt_f_x_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48011057,
+        ("vfcvt_f_xu_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48039057,
+        (
+            "vfcvt_rtz_x_f_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x48031057,
+        (
+            "vfcvt_rtz_xu_f_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x48009057,
+        ("vfcvt_x_f_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48001057,
+        ("vfcvt_xu_f_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4008a057,
+        ("vfirst_m", EXT::V, 32, &[VOP::Rd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x480a1057,
+        ("vfncvt_f_f_w", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48099057,
+        ("vfncvt_f_x_w", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48091057,
+        ("vfncvt_f_xu_w", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x480a9057,
+        (
+            "vfncvt_rod_f_f_w",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x480b9057,
+        (
+            "vfncvt_rtz_x_f_w",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x480b1057,
+        (
+            "vfncvt_rtz_xu_f_w",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x48089057,
+        ("vfncvt_x_f_w", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48081057,
+        ("vfncvt_xu_f_w", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x480e9057,
+        (
+            "vfncvtbf16_f_f_w",
+            EXT::Zvfbfmin,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x4c029057,
+        ("vfrec7_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4c021057,
+        ("vfrsqrt7_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4c001057,
+        ("vfsqrt_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48061057,
+        ("vfwcvt_f_f_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48059057,
+        ("vfwcvt_f_x_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48051057,
+        ("vfwcvt_f_xu_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48079057,
+        (
+            "vfwcvt_rtz_x_f_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x48071057,
+        (
+            "vfwcvt_rtz_xu_f_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x48049057,
+        ("vfwcvt_x_f_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48041057,
+        ("vfwcvt_xu_f_v", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48069057,
+        (
+            "vfwcvtbf16_f_f_v",
+            EXT::Zvfbfmin,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc0ff07f,
+        0x50082057,
+        ("viota_m", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x5007,
+        ("vle16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x1005007,
+        ("vle16ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x6007,
+        ("vle32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x1006007,
+        ("vle32ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x7007,
+        ("vle64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x1007007,
+        ("vle64ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x7,
+        ("vle8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x1000007,
+        ("vle8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x20005007,
+        ("vlseg2e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x21005007,
+        (
+            "vlseg2e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x20006007,
+        ("vlseg2e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x21006007,
+        (
+            "vlseg2e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x20007007,
+        ("vlseg2e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x21007007,
+        (
+            "vlseg2e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x20000007,
+        ("vlseg2e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x21000007,
+        ("vlseg2e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x40005007,
+        ("vlseg3e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x41005007,
+        (
+            "vlseg3e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x40006007,
+        ("vlseg3e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x41006007,
+        (
+            "vlseg3e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x40007007,
+        ("vlseg3e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x41007007,
+        (
+            "vlseg3e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x40000007,
+        ("vlseg3e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x41000007,
+        ("vlseg3e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x60005007,
+        ("vlseg4e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x61005007,
+        (
+            "vlseg4e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x60006007,
+        ("vlseg4e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x61006007,
+        (
+            "vlseg4e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x60007007,
+        ("vlseg4e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x61007007,
+        (
+            "vlseg4e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x60000007,
+        ("vlseg4e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x61000007,
+        ("vlseg4e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x80005007,
+        ("vlseg5e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x81005007,
+        (
+            "vlseg5e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x80006007,
+        ("vlseg5e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x81006007,
+        (
+            "vlseg5e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x80007007,
+        ("vlseg5e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x81007007,
+        (
+            "vlseg5e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0x80000007,
+        ("vlseg5e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x81000007,
+        ("vlseg5e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa0005007,
+        ("vlseg6e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa1005007,
+        (
+            "vlseg6e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xa0006007,
+        ("vlseg6e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa1006007,
+        (
+            "vlseg6e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xa0007007,
+        ("vlseg6e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa1007007,
+        (
+            "vlseg6e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xa0000007,
+        ("vlseg6e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa1000007,
+        ("vlseg6e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc0005007,
+        ("vlseg7e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc1005007,
+        (
+            "vlseg7e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xc0006007,
+        ("vlseg7e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc1006007,
+        (
+            "vlseg7e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xc0007007,
+        ("vlseg7e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc1007007,
+        (
+            "vlseg7e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xc0000007,
+        ("vlseg7e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc1000007,
+        ("vlseg7e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe0005007,
+        ("vlseg8e16_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe1005007,
+        (
+            "vlseg8e16ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xe0006007,
+        ("vlseg8e32_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe1006007,
+        (
+            "vlseg8e32ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xe0007007,
+        ("vlseg8e64_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe1007007,
+        (
+            "vlseg8e64ff_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Mask],
+        ),
+    ),
+    (
+        0xfdf0707f,
+        0xe0000007,
+        ("vlseg8e8_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe1000007,
+        ("vlseg8e8ff_v", EXT::V, 32, &[VOP::Vd, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x5000a057,
+        ("vmsbf_m", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x5001a057,
+        ("vmsif_m", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x50012057,
+        ("vmsof_m", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4804a057,
+        ("vrev8_v", EXT::Zvbb, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x5027,
+        ("vse16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x6027,
+        ("vse32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x7027,
+        ("vse64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x27,
+        ("vse8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4803a057,
+        ("vsext_vf2", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4802a057,
+        ("vsext_vf4", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x4801a057,
+        ("vsext_vf8", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x20005027,
+        ("vsseg2e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x20006027,
+        ("vsseg2e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x20007027,
+        ("vsseg2e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x20000027,
+        ("vsseg2e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x40005027,
+        ("vsseg3e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x40006027,
+        ("vsseg3e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x40007027,
+        ("vsseg3e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x40000027,
+        ("vsseg3e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x60005027,
+        ("vsseg4e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x60006027,
+        ("vsseg4e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x60007027,
+        ("vsseg4e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x60000027,
+        ("vsseg4e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x80005027,
+        ("vsseg5e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x80006027,
+        ("vsseg5e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x80007027,
+        ("vsseg5e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0x80000027,
+        ("vsseg5e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa0005027,
+        ("vsseg6e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa0006027,
+        ("vsseg6e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa0007027,
+        ("vsseg6e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xa0000027,
+        ("vsseg6e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc0005027,
+        ("vsseg7e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc0006027,
+        ("vsseg7e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc0007027,
+        ("vsseg7e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xc0000027,
+        ("vsseg7e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe0005027,
+        ("vsseg8e16_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe0006027,
+        ("vsseg8e32_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe0007027,
+        ("vsseg8e64_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfdf0707f,
+        0xe0000027,
+        ("vsseg8e8_v", EXT::V, 32, &[VOP::Vs3, VOP::Base, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48032057,
+        ("vzext_vf2", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48022057,
+        ("vzext_vf4", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfc0ff07f,
+        0x48012057,
+        ("vzext_vf8", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Mask]),
+    ),
+    (
+        0xfe00707f,
+        0x40003057,
+        (
+            "vadc_vim",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x40000057,
+        (
+            "vadc_vvm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x40004057,
+        (
+            "vadc_vxm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x8a002077,
+        (
+            "vaeskf1_vi",
+            EXT::Zvkned,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xaa002077,
+        (
+            "vaeskf2_vi",
+            EXT::Zvkned,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x5e002057,
+        ("vcompress_vm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x5c005057,
+        (
+            "vfmerge_vfm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xb2002077,
+        ("vghsh_vv", EXT::Zvkg, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x46003057,
+        ("vmadc_vi", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Simm5]),
+    ),
+    (
+        0xfe00707f,
+        0x44003057,
+        (
+            "vmadc_vim",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x46000057,
+        ("vmadc_vv", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x44000057,
+        (
+            "vmadc_vvm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x46004057,
+        ("vmadc_vx", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Rs1]),
+    ),
+    (
+        0xfe00707f,
+        0x44004057,
+        (
+            "vmadc_vxm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x66002057,
+        ("vmand_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x62002057,
+        ("vmandn_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x5c003057,
+        (
+            "vmerge_vim",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x5c000057,
+        (
+            "vmerge_vvm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x5c004057,
+        (
+            "vmerge_vxm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x76002057,
+        ("vmnand_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x7a002057,
+        ("vmnor_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x6a002057,
+        ("vmor_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x72002057,
+        ("vmorn_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x4e000057,
+        ("vmsbc_vv", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x4c000057,
+        (
+            "vmsbc_vvm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x4e004057,
+        ("vmsbc_vx", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Rs1]),
+    ),
+    (
+        0xfe00707f,
+        0x4c004057,
+        (
+            "vmsbc_vxm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x7e002057,
+        ("vmxnor_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x6e002057,
+        ("vmxor_mm", EXT::V, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x48000057,
+        (
+            "vsbc_vvm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x48004057,
+        (
+            "vsbc_vxm",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Carry],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0x80007057,
+        ("vsetvl", EXT::V, 32, &[VOP::Rd, VOP::Rs1, VOP::Rs2]),
+    ),
+    (
+        0xfe00707f,
+        0xba002077,
+        (
+            "vsha2ch_vv",
+            EXT::Zvknha,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xbe002077,
+        (
+            "vsha2cl_vv",
+            EXT::Zvknha,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xb6002077,
+        (
+            "vsha2ms_vv",
+            EXT::Zvknha,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1],
+        ),
+    ),
+    (
+        0xfe00707f,
+        0xae002077,
+        ("vsm3c_vi", EXT::Zvksh, 32, &[VOP::Vd, VOP::Vs2, VOP::Uimm5]),
+    ),
+    (
+        0xfe00707f,
+        0x82002077,
+        ("vsm3me_vv", EXT::Zvksh, 32, &[VOP::Vd, VOP::Vs2, VOP::Vs1]),
+    ),
+    (
+        0xfe00707f,
+        0x86002077,
+        (
+            "vsm4k_vi",
+            EXT::Zvksed,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24002057,
+        (
+            "vaadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24006057,
+        (
+            "vaadd_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x20002057,
+        (
+            "vaaddu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x20006057,
+        (
+            "vaaddu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x3057,
+        (
+            "vadd_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x57,
+        (
+            "vadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4057,
+        (
+            "vadd_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24003057,
+        (
+            "vand_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24000057,
+        (
+            "vand_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24004057,
+        (
+            "vand_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4000057,
+        (
+            "vandn_vv",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4004057,
+        (
+            "vandn_vx",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c002057,
+        (
+            "vasub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c006057,
+        (
+            "vasub_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28002057,
+        (
+            "vasubu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28006057,
+        (
+            "vasubu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x30002057,
+        (
+            "vclmul_vv",
+            EXT::Zvbc,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x30006057,
+        (
+            "vclmul_vx",
+            EXT::Zvbc,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x34002057,
+        (
+            "vclmulh_vv",
+            EXT::Zvbc,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x34006057,
+        (
+            "vclmulh_vx",
+            EXT::Zvbc,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84002057,
+        (
+            "vdiv_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84006057,
+        (
+            "vdiv_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80002057,
+        (
+            "vdivu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80006057,
+        (
+            "vdivu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x5057,
+        (
+            "vfadd_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x1057,
+        (
+            "vfadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80005057,
+        (
+            "vfdiv_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80001057,
+        (
+            "vfdiv_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb0005057,
+        (
+            "vfmacc_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb0001057,
+        (
+            "vfmacc_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa0005057,
+        (
+            "vfmadd_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa0001057,
+        (
+            "vfmadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x18005057,
+        (
+            "vfmax_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x18001057,
+        (
+            "vfmax_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x10005057,
+        (
+            "vfmin_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x10001057,
+        (
+            "vfmin_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb8005057,
+        (
+            "vfmsac_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb8001057,
+        (
+            "vfmsac_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8005057,
+        (
+            "vfmsub_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8001057,
+        (
+            "vfmsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x90005057,
+        (
+            "vfmul_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x90001057,
+        (
+            "vfmul_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4005057,
+        (
+            "vfnmacc_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4001057,
+        (
+            "vfnmacc_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4005057,
+        (
+            "vfnmadd_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4001057,
+        (
+            "vfnmadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc005057,
+        (
+            "vfnmsac_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc001057,
+        (
+            "vfnmsac_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac005057,
+        (
+            "vfnmsub_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac001057,
+        (
+            "vfnmsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84005057,
+        (
+            "vfrdiv_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x1c001057,
+        (
+            "vfredmax_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x14001057,
+        (
+            "vfredmin_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc001057,
+        (
+            "vfredosum_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4001057,
+        (
+            "vfredusum_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x9c005057,
+        (
+            "vfrsub_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x20005057,
+        (
+            "vfsgnj_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x20001057,
+        (
+            "vfsgnj_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24005057,
+        (
+            "vfsgnjn_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24001057,
+        (
+            "vfsgnjn_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28005057,
+        (
+            "vfsgnjx_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28001057,
+        (
+            "vfsgnjx_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x3c005057,
+        (
+            "vfslide1down_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x38005057,
+        (
+            "vfslide1up_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8005057,
+        (
+            "vfsub_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8001057,
+        (
+            "vfsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc0005057,
+        (
+            "vfwadd_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc0001057,
+        (
+            "vfwadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd0005057,
+        (
+            "vfwadd_wf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd0001057,
+        (
+            "vfwadd_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf0005057,
+        (
+            "vfwmacc_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf0001057,
+        (
+            "vfwmacc_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec005057,
+        (
+            "vfwmaccbf16_vf",
+            EXT::Zvfbfwma,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec001057,
+        (
+            "vfwmaccbf16_vv",
+            EXT::Zvfbfwma,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf8005057,
+        (
+            "vfwmsac_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf8001057,
+        (
+            "vfwmsac_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe0005057,
+        (
+            "vfwmul_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe0001057,
+        (
+            "vfwmul_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf4005057,
+        (
+            "vfwnmacc_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf4001057,
+        (
+            "vfwnmacc_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xfc005057,
+        (
+            "vfwnmsac_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Fs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xfc001057,
+        (
+            "vfwnmsac_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc001057,
+        (
+            "vfwredosum_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4001057,
+        (
+            "vfwredusum_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8005057,
+        (
+            "vfwsub_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8001057,
+        (
+            "vfwsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd8005057,
+        (
+            "vfwsub_wf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd8001057,
+        (
+            "vfwsub_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc005007,
+        (
+            "vloxei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc006007,
+        (
+            "vloxei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc007007,
+        (
+            "vloxei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc000007,
+        (
+            "vloxei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c005007,
+        (
+            "vloxseg2ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c006007,
+        (
+            "vloxseg2ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c007007,
+        (
+            "vloxseg2ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c000007,
+        (
+            "vloxseg2ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c005007,
+        (
+            "vloxseg3ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c006007,
+        (
+            "vloxseg3ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c007007,
+        (
+            "vloxseg3ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c000007,
+        (
+            "vloxseg3ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c005007,
+        (
+            "vloxseg4ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c006007,
+        (
+            "vloxseg4ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c007007,
+        (
+            "vloxseg4ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c000007,
+        (
+            "vloxseg4ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c005007,
+        (
+            "vloxseg5ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c006007,
+        (
+            "vloxseg5ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c007007,
+        (
+            "vloxseg5ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c000007,
+        (
+            "vloxseg5ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac005007,
+        (
+            "vloxseg6ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac006007,
+        (
+            "vloxseg6ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac007007,
+        (
+            "vloxseg6ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac000007,
+        (
+            "vloxseg6ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc005007,
+        (
+            "vloxseg7ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc006007,
+        (
+            "vloxseg7ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc007007,
+        (
+            "vloxseg7ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc000007,
+        (
+            "vloxseg7ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec005007,
+        (
+            "vloxseg8ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec006007,
+        (
+            "vloxseg8ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec007007,
+        (
+            "vloxseg8ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec000007,
+        (
+            "vloxseg8ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8005007,
+        (
+            "vlse16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8006007,
+        (
+            "vlse32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8007007,
+        (
+            "vlse64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8000007,
+        (
+            "vlse8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28005007,
+        (
+            "vlsseg2e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28006007,
+        (
+            "vlsseg2e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28007007,
+        (
+            "vlsseg2e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28000007,
+        (
+            "vlsseg2e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48005007,
+        (
+            "vlsseg3e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48006007,
+        (
+            "vlsseg3e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48007007,
+        (
+            "vlsseg3e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48000007,
+        (
+            "vlsseg3e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68005007,
+        (
+            "vlsseg4e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68006007,
+        (
+            "vlsseg4e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68007007,
+        (
+            "vlsseg4e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68000007,
+        (
+            "vlsseg4e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88005007,
+        (
+            "vlsseg5e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88006007,
+        (
+            "vlsseg5e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88007007,
+        (
+            "vlsseg5e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88000007,
+        (
+            "vlsseg5e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8005007,
+        (
+            "vlsseg6e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8006007,
+        (
+            "vlsseg6e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8007007,
+        (
+            "vlsseg6e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8000007,
+        (
+            "vlsseg6e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8005007,
+        (
+            "vlsseg7e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8006007,
+        (
+            "vlsseg7e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8007007,
+        (
+            "vlsseg7e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8000007,
+        (
+            "vlsseg7e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8005007,
+        (
+            "vlsseg8e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8006007,
+        (
+            "vlsseg8e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8007007,
+        (
+            "vlsseg8e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8000007,
+        (
+            "vlsseg8e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4005007,
+        (
+            "vluxei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4006007,
+        (
+            "vluxei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4007007,
+        (
+            "vluxei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4000007,
+        (
+            "vluxei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24005007,
+        (
+            "vluxseg2ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24006007,
+        (
+            "vluxseg2ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24007007,
+        (
+            "vluxseg2ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24000007,
+        (
+            "vluxseg2ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44005007,
+        (
+            "vluxseg3ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44006007,
+        (
+            "vluxseg3ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44007007,
+        (
+            "vluxseg3ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44000007,
+        (
+            "vluxseg3ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64005007,
+        (
+            "vluxseg4ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64006007,
+        (
+            "vluxseg4ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64007007,
+        (
+            "vluxseg4ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64000007,
+        (
+            "vluxseg4ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84005007,
+        (
+            "vluxseg5ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84006007,
+        (
+            "vluxseg5ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84007007,
+        (
+            "vluxseg5ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84000007,
+        (
+            "vluxseg5ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4005007,
+        (
+            "vluxseg6ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4006007,
+        (
+            "vluxseg6ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4007007,
+        (
+            "vluxseg6ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4000007,
+        (
+            "vluxseg6ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4005007,
+        (
+            "vluxseg7ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4006007,
+        (
+            "vluxseg7ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4007007,
+        (
+            "vluxseg7ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4000007,
+        (
+            "vluxseg7ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4005007,
+        (
+            "vluxseg8ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4006007,
+        (
+            "vluxseg8ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4007007,
+        (
+            "vluxseg8ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4000007,
+        (
+            "vluxseg8ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4002057,
+        (
+            "vmacc_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4006057,
+        (
+            "vmacc_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4002057,
+        (
+            "vmadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4006057,
+        (
+            "vmadd_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x1c000057,
+        (
+            "vmax_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x1c004057,
+        (
+            "vmax_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x18000057,
+        (
+            "vmaxu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x18004057,
+        (
+            "vmaxu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x60005057,
+        (
+            "vmfeq_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x60001057,
+        (
+            "vmfeq_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x7c005057,
+        (
+            "vmfge_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x74005057,
+        (
+            "vmfgt_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64005057,
+        (
+            "vmfle_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64001057,
+        (
+            "vmfle_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c005057,
+        (
+            "vmflt_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c001057,
+        (
+            "vmflt_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x70005057,
+        (
+            "vmfne_vf",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Fs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x70001057,
+        (
+            "vmfne_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x14000057,
+        (
+            "vmin_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x14004057,
+        (
+            "vmin_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x10000057,
+        (
+            "vminu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x10004057,
+        (
+            "vminu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x60003057,
+        (
+            "vmseq_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x60000057,
+        (
+            "vmseq_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x60004057,
+        (
+            "vmseq_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x7c003057,
+        (
+            "vmsgt_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x7c004057,
+        (
+            "vmsgt_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x78003057,
+        (
+            "vmsgtu_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x78004057,
+        (
+            "vmsgtu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x74003057,
+        (
+            "vmsle_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x74000057,
+        (
+            "vmsle_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x74004057,
+        (
+            "vmsle_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x70003057,
+        (
+            "vmsleu_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x70000057,
+        (
+            "vmsleu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x70004057,
+        (
+            "vmsleu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c000057,
+        (
+            "vmslt_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c004057,
+        (
+            "vmslt_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68000057,
+        (
+            "vmsltu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68004057,
+        (
+            "vmsltu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64003057,
+        (
+            "vmsne_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64000057,
+        (
+            "vmsne_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64004057,
+        (
+            "vmsne_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x94002057,
+        (
+            "vmul_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x94006057,
+        (
+            "vmul_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x9c002057,
+        (
+            "vmulh_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x9c006057,
+        (
+            "vmulh_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x98002057,
+        (
+            "vmulhsu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x98006057,
+        (
+            "vmulhsu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x90002057,
+        (
+            "vmulhu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x90006057,
+        (
+            "vmulhu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc003057,
+        (
+            "vnclip_wi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc000057,
+        (
+            "vnclip_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc004057,
+        (
+            "vnclip_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb8003057,
+        (
+            "vnclipu_wi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb8000057,
+        (
+            "vnclipu_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb8004057,
+        (
+            "vnclipu_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc002057,
+        (
+            "vnmsac_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xbc006057,
+        (
+            "vnmsac_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac002057,
+        (
+            "vnmsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac006057,
+        (
+            "vnmsub_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4003057,
+        (
+            "vnsra_wi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4000057,
+        (
+            "vnsra_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb4004057,
+        (
+            "vnsra_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb0003057,
+        (
+            "vnsrl_wi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb0000057,
+        (
+            "vnsrl_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xb0004057,
+        (
+            "vnsrl_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28003057,
+        (
+            "vor_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28000057,
+        (
+            "vor_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28004057,
+        (
+            "vor_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4002057,
+        (
+            "vredand_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x1c002057,
+        (
+            "vredmax_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x18002057,
+        (
+            "vredmaxu_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x14002057,
+        (
+            "vredmin_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x10002057,
+        (
+            "vredminu_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8002057,
+        (
+            "vredor_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2057,
+        (
+            "vredsum_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc002057,
+        (
+            "vredxor_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c002057,
+        (
+            "vrem_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c006057,
+        (
+            "vrem_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88002057,
+        (
+            "vremu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88006057,
+        (
+            "vremu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x30003057,
+        (
+            "vrgather_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x30000057,
+        (
+            "vrgather_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x30004057,
+        (
+            "vrgather_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x38000057,
+        (
+            "vrgatherei16_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x54000057,
+        (
+            "vrol_vv",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x54004057,
+        (
+            "vrol_vx",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x50000057,
+        (
+            "vror_vv",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x50004057,
+        (
+            "vror_vx",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc003057,
+        (
+            "vrsub_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc004057,
+        (
+            "vrsub_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84003057,
+        (
+            "vsadd_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84000057,
+        (
+            "vsadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84004057,
+        (
+            "vsadd_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80003057,
+        (
+            "vsaddu_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80000057,
+        (
+            "vsaddu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x80004057,
+        (
+            "vsaddu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x3c006057,
+        (
+            "vslide1down_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x38006057,
+        (
+            "vslide1up_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x3c003057,
+        (
+            "vslidedown_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x3c004057,
+        (
+            "vslidedown_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x38003057,
+        (
+            "vslideup_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x38004057,
+        (
+            "vslideup_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x94003057,
+        (
+            "vsll_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x94000057,
+        (
+            "vsll_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x94004057,
+        (
+            "vsll_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x9c000057,
+        (
+            "vsmul_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x9c004057,
+        (
+            "vsmul_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc005027,
+        (
+            "vsoxei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc006027,
+        (
+            "vsoxei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc007027,
+        (
+            "vsoxei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc000027,
+        (
+            "vsoxei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c005027,
+        (
+            "vsoxseg2ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c006027,
+        (
+            "vsoxseg2ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c007027,
+        (
+            "vsoxseg2ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c000027,
+        (
+            "vsoxseg2ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c005027,
+        (
+            "vsoxseg3ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c006027,
+        (
+            "vsoxseg3ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c007027,
+        (
+            "vsoxseg3ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4c000027,
+        (
+            "vsoxseg3ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c005027,
+        (
+            "vsoxseg4ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c006027,
+        (
+            "vsoxseg4ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c007027,
+        (
+            "vsoxseg4ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x6c000027,
+        (
+            "vsoxseg4ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c005027,
+        (
+            "vsoxseg5ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c006027,
+        (
+            "vsoxseg5ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c007027,
+        (
+            "vsoxseg5ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c000027,
+        (
+            "vsoxseg5ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac005027,
+        (
+            "vsoxseg6ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac006027,
+        (
+            "vsoxseg6ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac007027,
+        (
+            "vsoxseg6ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac000027,
+        (
+            "vsoxseg6ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc005027,
+        (
+            "vsoxseg7ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc006027,
+        (
+            "vsoxseg7ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc007027,
+        (
+            "vsoxseg7ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc000027,
+        (
+            "vsoxseg7ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec005027,
+        (
+            "vsoxseg8ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec006027,
+        (
+            "vsoxseg8ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec007027,
+        (
+            "vsoxseg8ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec000027,
+        (
+            "vsoxseg8ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4003057,
+        (
+            "vsra_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4000057,
+        (
+            "vsra_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4004057,
+        (
+            "vsra_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa0003057,
+        (
+            "vsrl_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa0000057,
+        (
+            "vsrl_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa0004057,
+        (
+            "vsrl_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8005027,
+        (
+            "vsse16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8006027,
+        (
+            "vsse32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8007027,
+        (
+            "vsse64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8000027,
+        (
+            "vsse8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac003057,
+        (
+            "vssra_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac000057,
+        (
+            "vssra_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xac004057,
+        (
+            "vssra_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8003057,
+        (
+            "vssrl_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8000057,
+        (
+            "vssrl_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8004057,
+        (
+            "vssrl_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28005027,
+        (
+            "vssseg2e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28006027,
+        (
+            "vssseg2e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28007027,
+        (
+            "vssseg2e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x28000027,
+        (
+            "vssseg2e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48005027,
+        (
+            "vssseg3e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48006027,
+        (
+            "vssseg3e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48007027,
+        (
+            "vssseg3e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x48000027,
+        (
+            "vssseg3e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68005027,
+        (
+            "vssseg4e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68006027,
+        (
+            "vssseg4e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68007027,
+        (
+            "vssseg4e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x68000027,
+        (
+            "vssseg4e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88005027,
+        (
+            "vssseg5e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88006027,
+        (
+            "vssseg5e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88007027,
+        (
+            "vssseg5e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88000027,
+        (
+            "vssseg5e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8005027,
+        (
+            "vssseg6e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8006027,
+        (
+            "vssseg6e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8007027,
+        (
+            "vssseg6e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa8000027,
+        (
+            "vssseg6e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8005027,
+        (
+            "vssseg7e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8006027,
+        (
+            "vssseg7e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8007027,
+        (
+            "vssseg7e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8000027,
+        (
+            "vssseg7e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8005027,
+        (
+            "vssseg8e16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8006027,
+        (
+            "vssseg8e32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8007027,
+        (
+            "vssseg8e64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8000027,
+        (
+            "vssseg8e8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Rs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c000057,
+        (
+            "vssub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8c004057,
+        (
+            "vssub_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88000057,
+        (
+            "vssubu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x88004057,
+        (
+            "vssubu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8000057,
+        (
+            "vsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x8004057,
+        (
+            "vsub_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4005027,
+        (
+            "vsuxei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4006027,
+        (
+            "vsuxei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4007027,
+        (
+            "vsuxei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x4000027,
+        (
+            "vsuxei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24005027,
+        (
+            "vsuxseg2ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24006027,
+        (
+            "vsuxseg2ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24007027,
+        (
+            "vsuxseg2ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x24000027,
+        (
+            "vsuxseg2ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44005027,
+        (
+            "vsuxseg3ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44006027,
+        (
+            "vsuxseg3ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44007027,
+        (
+            "vsuxseg3ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x44000027,
+        (
+            "vsuxseg3ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64005027,
+        (
+            "vsuxseg4ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64006027,
+        (
+            "vsuxseg4ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64007027,
+        (
+            "vsuxseg4ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x64000027,
+        (
+            "vsuxseg4ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84005027,
+        (
+            "vsuxseg5ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84006027,
+        (
+            "vsuxseg5ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84007027,
+        (
+            "vsuxseg5ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x84000027,
+        (
+            "vsuxseg5ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4005027,
+        (
+            "vsuxseg6ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4006027,
+        (
+            "vsuxseg6ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4007027,
+        (
+            "vsuxseg6ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xa4000027,
+        (
+            "vsuxseg6ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4005027,
+        (
+            "vsuxseg7ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4006027,
+        (
+            "vsuxseg7ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4007027,
+        (
+            "vsuxseg7ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4000027,
+        (
+            "vsuxseg7ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4005027,
+        (
+            "vsuxseg8ei16_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4006027,
+        (
+            "vsuxseg8ei32_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4007027,
+        (
+            "vsuxseg8ei64_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe4000027,
+        (
+            "vsuxseg8ei8_v",
+            EXT::V,
+            32,
+            &[VOP::Vs3, VOP::Base, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4002057,
+        (
+            "vwadd_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4006057,
+        (
+            "vwadd_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd4002057,
+        (
+            "vwadd_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd4006057,
+        (
+            "vwadd_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc0002057,
+        (
+            "vwaddu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc0006057,
+        (
+            "vwaddu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd0002057,
+        (
+            "vwaddu_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd0006057,
+        (
+            "vwaddu_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf4002057,
+        (
+            "vwmacc_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf4006057,
+        (
+            "vwmacc_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xfc002057,
+        (
+            "vwmaccsu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xfc006057,
+        (
+            "vwmaccsu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf0002057,
+        (
+            "vwmaccu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf0006057,
+        (
+            "vwmaccu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xf8006057,
+        (
+            "vwmaccus_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Rs1, VOP::Vs2, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec002057,
+        (
+            "vwmul_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xec006057,
+        (
+            "vwmul_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8002057,
+        (
+            "vwmulsu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe8006057,
+        (
+            "vwmulsu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe0002057,
+        (
+            "vwmulu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xe0006057,
+        (
+            "vwmulu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc4000057,
+        (
+            "vwredsum_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc0000057,
+        (
+            "vwredsumu_vs",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd4003057,
+        (
+            "vwsll_vi",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd4000057,
+        (
+            "vwsll_vv",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd4004057,
+        (
+            "vwsll_vx",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc002057,
+        (
+            "vwsub_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xcc006057,
+        (
+            "vwsub_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xdc002057,
+        (
+            "vwsub_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xdc006057,
+        (
+            "vwsub_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8002057,
+        (
+            "vwsubu_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xc8006057,
+        (
+            "vwsubu_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd8002057,
+        (
+            "vwsubu_wv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0xd8006057,
+        (
+            "vwsubu_wx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c003057,
+        (
+            "vxor_vi",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Simm5, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c000057,
+        (
+            "vxor_vv",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Vs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xfc00707f,
+        0x2c004057,
+        (
+            "vxor_vx",
+            EXT::V,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Rs1, VOP::Mask],
+        ),
+    ),
+    (
+        0xf800707f,
+        0x50003057,
+        (
+            "vror_vi",
+            EXT::Zvbb,
+            32,
+            &[VOP::Vd, VOP::Vs2, VOP::Uimm6, VOP::Mask],
+        ),
+    ),
+    (
+        0xc000707f,
+        0xc0007057,
+        ("vsetivli", EXT::V, 32, &[VOP::Rd, VOP::Uimm5, VOP::Vtype]),
+    ),
+    (
+        0x8000707f,
+        0x7057,
+        ("vsetvli", EXT::V, 32, &[VOP::Rd, VOP::Rs1, VOP::Vtype]),
+    ),
+];
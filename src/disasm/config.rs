@@ -1,9 +1,27 @@
-use crate::config::UNKNOWN_MNEMONIC;
+use crate::{config::UNKNOWN_MNEMONIC, model::Xlen};
 
 use super::Address;
 
+/// How targets of PC-relative instructions (branches, jumps and `auipc`) are printed
+/// in records, whose address is known.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TargetFormat {
+    /// Offset relative to the instruction, as encoded (e.g. `0x1c4`)
+    Offset,
+    /// Absolute address (e.g. `0x101c4`)
+    Absolute,
+    /// Offset followed by the absolute address (e.g. `0x1c4 <0x101c4>`)
+    Both,
+}
+
 #[derive(Debug, Clone, PartialEq)]
+#[allow(unpredictable_function_pointer_comparisons)]
 pub struct DisasmConfig {
+    /// Base ISA the code is decoded for
+    pub xlen: Xlen,
+    /// Decodes compressed (16-bit) instructions, which are rejected otherwise
+    pub compressed: bool,
+
     pub mnemonic_uppercase: bool,
     pub mnemonic_separator: String,
 
@@ -12,11 +30,20 @@ pub struct DisasmConfig {
 
     pub immediate_format: fn(i32) -> String,
 
+    /// Prints pseudo-instructions (e.g. `li`, `ret`, `csrr`) in place of their base forms,
+    /// as objdump does unless `-M no-aliases` is given
+    pub aliases: bool,
+
+    pub target_format: TargetFormat,
+
     pub show_addr: bool,
     pub start_addr: Address,
     pub addr_format: String,
     pub addr_separator: String,
 
+    /// Prints the relocations of each instruction on the lines below it, as `objdump -r` does
+    pub show_relocations: bool,
+
     pub unknown_mnemonic: String,
     pub hex_uppercase: bool,
 }
@@ -33,6 +60,9 @@ impl DisasmConfig {
 impl Default for DisasmConfig {
     fn default() -> Self {
         Self {
+            xlen: Xlen::Rv32,
+            compressed: true,
+
             mnemonic_uppercase: false,
             mnemonic_separator: String::from(" "),
 
@@ -41,11 +71,17 @@ impl Default for DisasmConfig {
 
             immediate_format: |num| format!("0x{:x}", num),
 
+            aliases: true,
+
+            target_format: TargetFormat::Absolute,
+
             show_addr: true,
             start_addr: 0,
             addr_format: String::from("{:x}"),
             addr_separator: String::from(":   "),
 
+            show_relocations: true,
+
             unknown_mnemonic: UNKNOWN_MNEMONIC.to_string(),
             hex_uppercase: false,
         }
@@ -0,0 +1,60 @@
+use std::fmt;
+
+use super::Address;
+
+/// Bytes which aren't decoded as instructions (e.g. a jump table or alignment padding
+/// in a code section), printed as a `.word`, `.half` or `.byte` directive.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataRecord {
+    address: Address,
+    bytes: Vec<u8>,
+}
+
+impl DataRecord {
+    /// Creates a record of 1, 2 or 4 bytes.
+    pub fn new(address: Address, bytes: Vec<u8>) -> Self {
+        debug_assert!(matches!(bytes.len(), 1 | 2 | 4));
+        Self { address, bytes }
+    }
+
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn size(&self) -> Address {
+        self.bytes.len() as Address
+    }
+
+    /// Returns the little-endian value of the bytes.
+    pub fn value(&self) -> u32 {
+        self.bytes
+            .iter()
+            .rev()
+            .fold(0, |value, byte| (value << 8) | u32::from(*byte))
+    }
+
+    /// Returns the assembler directive emitting the bytes.
+    pub fn directive(&self) -> &'static str {
+        match self.bytes.len() {
+            4 => ".word",
+            2 => ".half",
+            _ => ".byte",
+        }
+    }
+}
+
+impl fmt::Display for DataRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:x}: {} 0x{:x}",
+            self.address,
+            self.directive(),
+            self.value()
+        )
+    }
+}
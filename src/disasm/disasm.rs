@@ -1,15 +1,22 @@
+use std::collections::BTreeMap;
 use std::io::{self, Read};
 
-use super::{Address, DisasmConfig, DisasmError, InstructionFormatter, InstructionRecord};
+use super::{
+    Address, DisasmConfig, DisasmError, Flow, InstructionFormatter, InstructionRecord, Peephole,
+    Relocation, SymbolKind, SymbolTable, Traversal,
+};
 use crate::{
-    instr::Instruction,
-    model::{InstructionSize, TryFromOpcodeBinary},
+    instr::{Instruction, InstructionTrait},
+    model::{InstructionSize, TryFromOpcodeBinary, Xlen},
 };
 
 pub struct Disasm {
     reader: Box<dyn Read>,
     formatter: InstructionFormatter,
     addr: Address,
+    xlen: Xlen,
+    compressed: bool,
+    entries: Vec<Address>,
 }
 
 impl Disasm {
@@ -19,11 +26,124 @@ impl Disasm {
 
     pub fn with_config(reader: impl Read + 'static, config: DisasmConfig) -> Self {
         let addr = config.start_addr;
+        let xlen = config.xlen;
+        let compressed = config.compressed;
         Self {
             reader: Box::new(reader),
             formatter: InstructionFormatter::new(config),
             addr,
+            xlen,
+            compressed,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Labels the printed addresses and targets with the symbols, and precedes the
+    /// instructions of each symbol with a header (e.g. `00010144 <main>:`).
+    pub fn with_symbols(self, symbols: SymbolTable) -> Self {
+        Self {
+            formatter: self.formatter.with_symbols(symbols),
+            ..self
+        }
+    }
+
+    /// Prints the relocations below the instructions they apply to, like `objdump -r`
+    /// does, and keeps the relocated instructions from being fused by [`peephole`](Self::peephole).
+    pub fn with_relocations(self, relocations: impl IntoIterator<Item = Relocation>) -> Self {
+        Self {
+            formatter: self.formatter.with_relocations(relocations),
+            ..self
+        }
+    }
+
+    /// Adds addresses where code starts (e.g. the entry of an ELF file or the handlers
+    /// of a vector table), followed by [`traverse`](Self::traverse).
+    pub fn with_entry_points(mut self, entries: impl IntoIterator<Item = Address>) -> Self {
+        self.entries.extend(entries);
+        self
+    }
+
+    /// Disassembles the code reachable from the first byte, the entry points and the
+    /// symbols of code (see [`SymbolKind`](super::SymbolKind)), following fallthroughs
+    /// and the targets of branches, jumps and calls, instead of decoding all the bytes
+    /// in a linear sweep. Bytes that aren't reached (e.g. jump tables, literal pools or
+    /// padding) are reported as data.
+    ///
+    /// Targets of indirect jumps and calls (`jalr`) aren't known, so the code reached
+    /// only through them is reported as data, unless it's labelled by a symbol of code.
+    pub fn traverse(mut self) -> Result<Traversal, DisasmError> {
+        let mut bytes = Vec::new();
+        self.reader.read_to_end(&mut bytes)?;
+        let start = self.addr;
+        // addresses are handled as offsets from the start, which don't overflow
+        // when the bytes reach the end of the address space
+        let len = bytes.len() as Address;
+        let offset_of = |address: Address| Some(address.wrapping_sub(start)).filter(|o| *o < len);
+
+        let symbols = self
+            .formatter
+            .symbols()
+            .iter()
+            .filter(|s| s.kind == SymbolKind::Code)
+            .map(|s| s.address);
+        let mut pending: Vec<Address> = [start]
+            .into_iter()
+            .chain(self.entries.iter().copied())
+            .chain(symbols)
+            .collect();
+        // the start and the entry points are followed first
+        pending.reverse();
+
+        let mut code: BTreeMap<Address, InstructionRecord> = BTreeMap::new();
+        while let Some(address) = pending.pop() {
+            let Some(offset) = offset_of(address) else {
+                continue;
+            };
+            if code.contains_key(&offset) {
+                continue;
+            }
+            let Some(record) = self.decode_at(&bytes[offset as usize..], address) else {
+                continue;
+            };
+            // paths leading into the middle of decoded instructions are dropped
+            let size = record.size();
+            let overlaps = code
+                .range(..offset)
+                .next_back()
+                .is_some_and(|(previous, r)| previous + r.size() > offset)
+                || code.range(offset + 1..offset + size).next().is_some();
+            if overlaps {
+                continue;
+            }
+
+            let flow = Flow::of(&record);
+            if flow.falls_through() {
+                pending.push(address.wrapping_add(size));
+            }
+            pending.extend(flow.target());
+            code.insert(offset, record);
         }
+
+        Ok(Traversal::new(self.formatter, &bytes, start, code))
+    }
+
+    /// Decodes the instruction at the beginning of the bytes, if it's a known one.
+    fn decode_at(&self, bytes: &[u8], address: Address) -> Option<InstructionRecord> {
+        let opcode = *bytes.first()? & 0x7f;
+        let size = match InstructionSize::try_from_opcode_binary(opcode).ok()? {
+            InstructionSize::Size16 if self.compressed => 2,
+            InstructionSize::Size32 => 4,
+            _ => return None,
+        };
+        let bits = bytes
+            .get(..size)?
+            .iter()
+            .rev()
+            .fold(0, |bits, byte| (bits << 8) | u32::from(*byte));
+        let instruction = Instruction::decode(bits, self.xlen)
+            .ok()
+            .filter(|instr| instr.mnemonic().is_some())?;
+        Some(InstructionRecord::new(instruction, address))
     }
 
     /// Attempts to read the next instruction from the reader.
@@ -32,32 +152,57 @@ impl Disasm {
         self.reader.read_exact(&mut opcode_buf)?;
         let opcode = opcode_buf[0] & 0x7f;
 
-        let instr_size = match InstructionSize::try_from_opcode_binary(opcode) {
-            Ok(InstructionSize::Size32) => 32,
-            Ok(_) => {
+        let instr_size = InstructionSize::try_from_opcode_binary(opcode)?;
+        let instruction = match instr_size {
+            InstructionSize::Size16 if !self.compressed => {
+                return Err(DisasmError::from(
+                    "Compressed instructions are not enabled",
+                ))
+            }
+            InstructionSize::Size16 => {
+                let mut rest = [0u8; 1];
+                self.read_rest(&mut rest)?;
+                let bits = u16::from_le_bytes([opcode_buf[0], rest[0]]);
+                Instruction::decode(bits.into(), self.xlen)?
+            }
+            InstructionSize::Size32 => {
+                let mut rest = [0u8; 3];
+                self.read_rest(&mut rest)?;
+                let bits = u32::from_le_bytes([opcode_buf[0], rest[0], rest[1], rest[2]]);
+                Instruction::decode(bits, self.xlen)?
+            }
+            _ => {
                 return Err(DisasmError::from(
-                    "The disassembler doesn't currently support instructions of a size other than 32 bits"
+                    "The disassembler doesn't currently support instructions of a size other than 16 or 32 bits"
                 ))
             }
-            Err(e) => return Err(DisasmError::from(e)),
         };
 
-        let mut rest = [0u8; 3];
-        self.reader
-            .read_exact(&mut rest)
-            .map_err(|_| DisasmError::from("Unexpected end of file"))?;
-
-        let bytes = [opcode_buf[0], rest[0], rest[1], rest[2]];
-        let instruction = Instruction::try_from_le_bytes(bytes).map_err(DisasmError::from)?;
+        let size = (usize::from(&instr_size) / 8) as Address;
         let record = InstructionRecord::new(instruction, self.addr);
-        self.addr += instr_size;
+        self.addr = self.addr.wrapping_add(size);
 
         Ok(record)
     }
 
+    /// Reads the remaining bytes of an instruction, which are expected to be present.
+    fn read_rest(&mut self, buf: &mut [u8]) -> Result<(), DisasmError> {
+        self.reader
+            .read_exact(buf)
+            .map_err(|_| DisasmError::from("Unexpected end of file"))
+    }
+
+    /// Turns the disassembler into a stream of records with pairs of instructions
+    /// combined into pseudo-instructions (e.g. `auipc` and `jalr` into `call`).
+    pub fn peephole(self) -> Peephole<Self> {
+        let xlen = self.xlen;
+        let relocated: Vec<Address> = self.formatter.relocated_addresses().collect();
+        Peephole::new(self, xlen).with_relocated(relocated)
+    }
+
     pub fn print_next(&mut self) -> Result<(), DisasmError> {
         let record = self.next_instruction()?;
-        println!("{}", self.formatter.record(&record));
+        Self::print_record(&self.formatter, &record);
         Ok(())
     }
 
@@ -65,10 +210,17 @@ impl Disasm {
         let formatter = self.formatter.clone();
         self.try_for_each(|result| {
             let record = result?;
-            println!("{}", formatter.record(&record));
+            Self::print_record(&formatter, &record);
             Ok(())
         })
     }
+
+    fn print_record(formatter: &InstructionFormatter, record: &InstructionRecord) {
+        if let Some(header) = formatter.symbol_header(record.address()) {
+            println!("\n{}", header);
+        }
+        println!("{}", formatter.record(record));
+    }
 }
 
 impl Iterator for Disasm {
@@ -87,6 +239,7 @@ impl Iterator for Disasm {
 mod tests {
     use super::*;
     use crate::error::RISCVError;
+    use crate::instr::InstructionTrait;
     use std::io::Cursor;
 
     #[test]
@@ -108,6 +261,57 @@ mod tests {
         assert!(disasm.next().is_none());
     }
 
+    #[test]
+    fn test_disasm_compressed() {
+        // c.addi sp, -16; add a0, t0, t1; c.jr ra
+        let data = vec![0x41, 0x11, 0x33, 0x85, 0x62, 0x00, 0x82, 0x80];
+        let disasm = Disasm::new(Cursor::new(data));
+
+        let records: Vec<InstructionRecord> = disasm.map(|r| r.unwrap()).collect();
+        let addresses: Vec<Address> = records.iter().map(|r| r.address()).collect();
+        assert_eq!(vec![0, 2, 6], addresses);
+        assert!(records[0].instruction().is_compressed());
+        assert!(!records[1].instruction().is_compressed());
+        assert_eq!(0x8082, u32::from(records[2].instruction()));
+
+        let config = DisasmConfig {
+            compressed: false,
+            ..Default::default()
+        };
+        let mut disasm = Disasm::with_config(Cursor::new(vec![0x41, 0x11]), config);
+        assert!(matches!(disasm.next(), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_disasm_xlen() {
+        // ld a0, 8(sp); c.ld a0, 0(a1); addiw a0, a0, 1
+        let data = vec![0x03, 0x35, 0x81, 0x00, 0x88, 0x61, 0x1b, 0x05, 0x15, 0x00];
+
+        let config = DisasmConfig {
+            xlen: Xlen::Rv64,
+            ..Default::default()
+        };
+        let disasm = Disasm::with_config(Cursor::new(data.clone()), config);
+        let records: Vec<InstructionRecord> = disasm.map(|r| r.unwrap()).collect();
+        assert_eq!(3, records.len());
+        assert_eq!(Some("ld".into()), records[0].instruction().mnemonic());
+        assert_eq!(Some("c_ld".into()), records[1].instruction().mnemonic());
+        assert_eq!(Some("addiw".into()), records[2].instruction().mnemonic());
+
+        let mut disasm = Disasm::new(Cursor::new(data));
+        let result = disasm.next();
+        assert!(
+            matches!(
+                result,
+                Some(Err(DisasmError::RISCVError(
+                    RISCVError::UnsupportedInstruction(Xlen::Rv32)
+                )))
+            ),
+            "Expected an unsupported instruction error, got {:?}",
+            result
+        );
+    }
+
     #[test]
     fn test_disasm_unexpected_eof() {
         let data = vec![0x33, 0x00];
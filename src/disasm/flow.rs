@@ -0,0 +1,62 @@
+use super::{Address, Fusion, InstructionRecord};
+use crate::instr::InstructionTrait;
+
+/// Instructions returning from a trap handler, whose successors aren't known statically.
+const TRAP_RETURNS: [&str; 3] = ["mret", "sret", "dret"];
+
+/// How an instruction passes control on, as followed by the control-flow graph and
+/// the recursive traversal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Flow {
+    Next,
+    Branch(Address),
+    /// A jump with its target, if it's known statically
+    Jump(Option<Address>),
+    /// A call with its target, if it's known statically
+    Call(Option<Address>),
+    Return,
+    TrapReturn,
+}
+
+impl Flow {
+    pub fn of(record: &InstructionRecord) -> Self {
+        match record.fusion() {
+            Some(Fusion::Call { target }) => return Flow::Call(Some(target)),
+            Some(Fusion::Tail { target }) => return Flow::Jump(Some(target)),
+            Some(_) => return Flow::Next,
+            None => {}
+        }
+
+        let instr = record.instruction();
+        if instr.is_call() {
+            Flow::Call(record.target())
+        } else if instr.is_return() {
+            Flow::Return
+        } else if instr.is_branch() {
+            record.target().map_or(Flow::Next, Flow::Branch)
+        } else if instr.is_unconditional_jump() {
+            Flow::Jump(record.target())
+        } else if instr
+            .mnemonic()
+            .is_some_and(|m| TRAP_RETURNS.contains(&m.into()))
+        {
+            Flow::TrapReturn
+        } else {
+            Flow::Next
+        }
+    }
+
+    pub fn target(&self) -> Option<Address> {
+        match self {
+            Flow::Branch(target) => Some(*target),
+            Flow::Jump(target) | Flow::Call(target) => *target,
+            _ => None,
+        }
+    }
+
+    /// Checks whether execution may continue with the next instruction (for calls,
+    /// once the called function returns).
+    pub fn falls_through(&self) -> bool {
+        matches!(self, Flow::Next | Flow::Branch(_) | Flow::Call(_))
+    }
+}
@@ -1,18 +1,29 @@
-use std::fmt::Write;
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
 
 use crate::{
     data::find_system_mnemonic,
-    instr::{Instruction, InstructionTrait},
-    model::{Mnemonic, Register},
+    instr::{
+        fence_set_name, CInstruction, CompressedOperands, IInstruction, Instruction,
+        InstructionTrait, PseudoInstruction, VInstruction,
+    },
+    model::{
+        Csr, FRegister, Mnemonic, Operand, Register, RegisterClass, VRegister, VectorOperand, Xlen,
+    },
 };
 
-use super::{Address, DisasmConfig, InstructionRecord};
+use super::{
+    peephole::signed_hex, Address, DataRecord, DisasmConfig, DisasmItem, Fusion, InstructionRecord,
+    Relocation, SymbolTable, TargetFormat,
+};
 
 /// A formatter for instructions, providing various formatting options based on
 /// provided configuration.
 #[derive(Debug, Clone)]
 pub struct InstructionFormatter {
     config: DisasmConfig,
+    symbols: SymbolTable,
+    relocations: BTreeMap<Address, Vec<Relocation>>,
 }
 
 impl InstructionFormatter {
@@ -22,7 +33,49 @@ impl InstructionFormatter {
     ///
     /// * `config` - The configuration for the formatter.
     pub fn new(config: DisasmConfig) -> InstructionFormatter {
-        InstructionFormatter { config }
+        InstructionFormatter {
+            config,
+            symbols: SymbolTable::new(),
+            relocations: BTreeMap::new(),
+        }
+    }
+
+    /// Labels addresses and targets with the given symbols (e.g. `0x10188 <main+0x44>`).
+    pub fn with_symbols(self, symbols: SymbolTable) -> InstructionFormatter {
+        InstructionFormatter { symbols, ..self }
+    }
+
+    pub fn symbols(&self) -> &SymbolTable {
+        &self.symbols
+    }
+
+    /// Prints the relocations below the instructions they apply to, like `objdump -r`
+    /// does (unless `show_relocations` is disabled).
+    pub fn with_relocations(
+        mut self,
+        relocations: impl IntoIterator<Item = Relocation>,
+    ) -> InstructionFormatter {
+        for relocation in relocations {
+            self.relocations
+                .entry(relocation.address)
+                .or_default()
+                .push(relocation);
+        }
+        self
+    }
+
+    /// Returns the relocations applying to the bytes of the record.
+    pub fn relocations(&self, r: &InstructionRecord) -> impl Iterator<Item = &Relocation> {
+        let (address, size) = (r.address(), r.size());
+        self.relocations
+            .range(address..)
+            .take_while(move |(a, _)| a.wrapping_sub(address) < size)
+            .flat_map(|(_, relocations)| relocations)
+    }
+
+    /// Returns the addresses where relocations apply.
+    pub(crate) fn relocated_addresses(&self) -> impl Iterator<Item = Address> + '_ {
+        self.relocations.keys().copied()
     }
 
     /// Formats an optional mnemonic. If `None` (which means unrecognized mnemonic)
@@ -72,37 +125,96 @@ impl InstructionFormatter {
     ///
     /// A formatted instruction string.
     pub fn instruction(&self, instr: &Instruction) -> String {
+        self.format_instruction(instr, None)
+    }
+
+    /// Formats an instruction located at the given address, so the targets
+    /// of PC-relative instructions are printed as configured by `target_format`.
+    ///
+    /// # Arguments
+    ///
+    /// * `instr` - a reference to an instruction to format.
+    /// * `address` - the address of the instruction.
+    ///
+    /// # Returns
+    ///
+    /// A formatted instruction string.
+    pub fn instruction_at(&self, instr: &Instruction, address: Address) -> String {
+        self.format_instruction(instr, Some(address))
+    }
+
+    fn format_instruction(&self, instr: &Instruction, address: Option<Address>) -> String {
         use Instruction::*;
+        if let Some(pseudo) = instr
+            .pseudo(self.config.xlen)
+            .filter(|_| self.config.aliases)
+        {
+            return self.format_pseudo_instruction(&pseudo, address);
+        }
+        // the address is only needed by PC-relative instructions
+        let pc = address.filter(|_| instr.pc_offset().is_some());
+
         let mut out = format!(
             "{}{}",
-            self.optional_mnemonic(instr.mnemonic()),
+            self.instruction_mnemonic(instr),
             self.config.mnemonic_separator
         );
 
         let s = &self.config.register_separator;
         let result = match instr {
-            R(i) => write!(
+            R(_) | R4(_) => self.register_operands(&mut out, instr),
+
+            Amo(i) if i.is_load_reserved() => write!(
                 out,
-                "{},{}{},{}{}",
+                "{},{}({})",
                 self.register(&i.rd()),
                 s,
-                self.register(&i.rs1()),
+                self.register(&i.rs1())
+            ),
+
+            Amo(i) => write!(
+                out,
+                "{},{}{},{}({})",
+                self.register(&i.rd()),
+                s,
+                self.register(&i.rs2()),
                 s,
-                self.register(&i.rs2())
+                self.register(&i.rs1())
             ),
 
             I(i) => {
                 if find_system_mnemonic(instr.into()).is_some() {
                     Ok(())
-                } else {
+                } else if let Some((pred, succ)) = i.fence_sets() {
+                    write!(
+                        out,
+                        "{},{}{}",
+                        self.case(fence_set_name(pred)),
+                        s,
+                        self.case(fence_set_name(succ))
+                    )
+                } else if i.is_fence() {
+                    Ok(())
+                } else if let Some(csr) = i.csr() {
                     write!(
                         out,
                         "{},{}{},{}{}",
                         self.register(&i.rd()),
                         s,
-                        self.register(&i.rs1()),
+                        self.csr(&csr),
                         s,
-                        self.number(i.imm().into())
+                        self.csr_source(i)
+                    )
+                } else {
+                    let [rd, rs1, ..] = instr.register_classes();
+                    write!(
+                        out,
+                        "{},{}{},{}{}",
+                        self.typed_register(i.rd(), rd),
+                        s,
+                        self.typed_register(i.rs1(), rs1),
+                        s,
+                        self.number(i.shamt().map_or(i.imm().into(), i32::from))
                     )
                 }
             }
@@ -110,7 +222,7 @@ impl InstructionFormatter {
             S(i) => write!(
                 out,
                 "{},{}{}({})",
-                self.register(&i.rs2()),
+                self.typed_register(i.rs2(), instr.register_classes()[2]),
                 s,
                 self.number(i.imm().into()),
                 self.register(&i.rs1())
@@ -123,7 +235,7 @@ impl InstructionFormatter {
                 s,
                 self.register(&i.rs2()),
                 s,
-                self.number(i.imm().into())
+                self.pc_relative(i.imm().into(), pc)
             ),
 
             U(i) => write!(
@@ -131,7 +243,7 @@ impl InstructionFormatter {
                 "{},{}{}",
                 self.register(&i.rd()),
                 s,
-                self.number(i.imm().into())
+                self.pc_relative(i.imm().into(), pc)
             ),
 
             J(i) => write!(
@@ -139,8 +251,12 @@ impl InstructionFormatter {
                 "{},{}{}",
                 self.register(&i.rd()),
                 s,
-                self.number(i.imm().into())
+                self.pc_relative(i.imm().into(), pc)
             ),
+
+            V(i) => self.vector_operands(&mut out, i),
+
+            C(i) => self.compressed_operands(&mut out, i, pc),
         };
         if result.is_err() {
             todo!();
@@ -148,6 +264,155 @@ impl InstructionFormatter {
         out
     }
 
+    /// Formats the mnemonic of an instruction, including the memory ordering suffix
+    /// of atomic instructions (e.g. `amoadd.w.aqrl`).
+    fn instruction_mnemonic(&self, instr: &Instruction) -> String {
+        let mnemonic = self.optional_mnemonic(instr.mnemonic());
+        match instr {
+            Instruction::Amo(i) if self.config.mnemonic_uppercase => {
+                mnemonic + &i.ordering_suffix().to_uppercase()
+            }
+            Instruction::Amo(i) => mnemonic + i.ordering_suffix(),
+            _ => mnemonic,
+        }
+    }
+
+    /// Formats a pseudo-instruction.
+    pub fn pseudo_instruction(&self, p: &PseudoInstruction) -> String {
+        self.format_pseudo_instruction(p, None)
+    }
+
+    fn format_pseudo_instruction(&self, p: &PseudoInstruction, address: Option<Address>) -> String {
+        let operands: Vec<String> = p
+            .operands()
+            .iter()
+            .map(|op| match op {
+                Operand::Target(offset) => self.pc_relative(*offset, address),
+                op => self.operand(op),
+            })
+            .collect();
+        if operands.is_empty() {
+            return self.mnemonic(&p.mnemonic());
+        }
+        format!(
+            "{}{}{}",
+            self.mnemonic(&p.mnemonic()),
+            self.config.mnemonic_separator,
+            operands.join(&format!(",{}", self.config.register_separator))
+        )
+    }
+
+    /// Formats an operand.
+    pub fn operand(&self, op: &Operand) -> String {
+        match op {
+            Operand::IntReg(r) => self.register(r),
+            Operand::FloatReg(r) => self.fregister(r),
+            Operand::VecReg(r) => self.case(r.to_string()),
+            Operand::Csr(csr) => self.csr(csr),
+            Operand::Imm(imm) | Operand::Target(imm) => self.number(*imm),
+            Operand::MemOffset { base, offset } => {
+                format!("{}({})", self.number(*offset), self.register(base))
+            }
+        }
+    }
+
+    /// Formats the source operand of a Zicsr instruction: rs1, or the unsigned immediate.
+    fn csr_source(&self, i: &IInstruction) -> String {
+        i.uimm()
+            .map_or(self.register(&i.rs1()), |uimm| self.number(uimm.into()))
+    }
+
+    /// Writes register operands of R-type and R4-type instructions, followed by the
+    /// rounding mode of floating-point operations (unless it's dynamic, and raw if it's
+    /// reserved).
+    fn register_operands(&self, out: &mut String, instr: &Instruction) -> fmt::Result {
+        let registers = [instr.rd(), instr.rs1(), instr.rs2(), instr.rs3()];
+        let mut operands: Vec<String> = registers
+            .into_iter()
+            .zip(instr.register_classes())
+            .filter_map(|(reg, class)| Some(self.typed_register(reg?, class?)))
+            .collect();
+        if let Instruction::R(i) = instr {
+            operands.extend(i.fli_constant().map(String::from));
+        }
+        operands.extend(instr.rounding_mode_operand().map(|rm| self.case(rm)));
+
+        let separator = format!(",{}", self.config.register_separator);
+        write!(out, "{}", operands.join(&separator))
+    }
+
+    /// Writes operands of a compressed instruction, in the order used by its assembly form.
+    fn compressed_operands(
+        &self,
+        out: &mut String,
+        i: &CInstruction,
+        pc: Option<Address>,
+    ) -> fmt::Result {
+        use CompressedOperands::*;
+
+        let s = &self.config.register_separator;
+        // the data registers of c.flw, c.fsd, c.fldsp etc. are floating-point ones
+        let [rd_class, rs1_class, rs2_class, _] = i.expand().register_classes();
+        let reg =
+            |r: Option<Register>, class| r.map_or(String::new(), |r| self.typed_register(r, class));
+        let (rd, rs1, rs2) = (
+            reg(i.rd(), rd_class),
+            reg(i.rs1(), rs1_class),
+            reg(i.rs2(), rs2_class),
+        );
+        let imm = self.pc_relative(i.imm().unwrap_or(0), pc);
+
+        match i.operands_layout() {
+            Empty => Ok(()),
+            Rd => write!(out, "{}", rd),
+            Rs1 => write!(out, "{}", rs1),
+            RdRs2 => write!(out, "{},{}{}", rd, s, rs2),
+            RdImm => write!(out, "{},{}{}", rd, s, imm),
+            RdRs1Imm => write!(out, "{},{}{},{}{}", rd, s, rs1, s, imm),
+            Rs1Imm => write!(out, "{},{}{}", rs1, s, imm),
+            Imm => write!(out, "{}", imm),
+            Load => write!(out, "{},{}{}({})", rd, s, imm, rs1),
+            Store => write!(out, "{},{}{}({})", rs2, s, imm, rs1),
+        }
+    }
+
+    /// Writes operands of a vector instruction, in the order used by its assembly form.
+    fn vector_operands(&self, out: &mut String, i: &VInstruction) -> fmt::Result {
+        use VectorOperand::*;
+
+        let vreg = |r: Option<VRegister>| r.map(|r| self.case(r.to_string()));
+        let freg = |r: Option<u8>| {
+            r.and_then(|r| FRegister::try_from(r).ok())
+                .map(|r| self.fregister(&r))
+        };
+        let operands: Vec<String> = i
+            .operands()
+            .iter()
+            .filter_map(|op| match op {
+                Vd => vreg(i.vd()),
+                Vs1 => vreg(i.vs1()),
+                Vs2 => vreg(i.vs2()),
+                Vs3 => vreg(i.vs3()),
+                Fd => freg(i.fd()),
+                Fs1 => freg(i.fs1()),
+                Rd => i.rd().map(|r| self.register(&r)),
+                Rs1 => i.rs1().map(|r| self.register(&r)),
+                Rs2 => i.rs2().map(|r| self.register(&r)),
+                Base => i.rs1().map(|r| format!("({})", self.register(&r))),
+                Simm5 | Uimm5 | Uimm6 => i.imm().map(|imm| self.number(imm)),
+                Vtype => i.vtype().map(|vtype| vtype.to_string()),
+                Mask => (!i.vm()).then(|| self.case("v0.t".to_string())),
+                Carry => Some(self.case(VRegister::v0().to_string())),
+            })
+            .collect();
+
+        write!(
+            out,
+            "{}",
+            operands.join(&format!(",{}", self.config.register_separator))
+        )
+    }
+
     /// Formats a register.
     ///
     /// # Arguments
@@ -158,10 +423,35 @@ impl InstructionFormatter {
     ///
     /// A formatted register string.
     pub fn register(&self, r: &Register) -> String {
+        self.case(r.to_string())
+    }
+
+    /// Formats a CSR, by name if it's a standard one.
+    pub fn csr(&self, csr: &Csr) -> String {
+        self.case(csr.to_string())
+    }
+
+    /// Formats a floating-point register.
+    pub fn fregister(&self, r: &FRegister) -> String {
+        self.case(r.to_string())
+    }
+
+    /// Formats the register field as an operand of the given class.
+    fn typed_register(&self, r: Register, class: impl Into<Option<RegisterClass>>) -> String {
+        match class.into() {
+            Some(RegisterClass::Float) => {
+                self.fregister(&FRegister::from_rd_bits(r.into_rd_bits()))
+            }
+            _ => self.register(&r),
+        }
+    }
+
+    /// Applies the configured case of register names.
+    fn case(&self, name: String) -> String {
         if self.config.register_uppercase {
-            r.to_string().to_uppercase()
+            name.to_uppercase()
         } else {
-            r.to_string().to_lowercase()
+            name.to_lowercase()
         }
     }
 
@@ -178,6 +468,43 @@ impl InstructionFormatter {
         (self.config.immediate_format)(n)
     }
 
+    /// Formats the offset of a PC-relative instruction, which is at the given address
+    /// (if known), according to `target_format`.
+    fn pc_relative(&self, offset: i32, address: Option<Address>) -> String {
+        let Some(address) = address else {
+            return self.number(offset);
+        };
+        let mut target = address.wrapping_add_signed(offset.into());
+        if self.config.xlen == Xlen::Rv32 {
+            target &= 0xffff_ffff;
+        }
+        match (self.config.target_format, self.symbols.label(target)) {
+            (TargetFormat::Offset, _) => self.number(offset),
+            (TargetFormat::Absolute, _) => self.target(target),
+            (TargetFormat::Both, Some(label)) => format!("{} <{}>", self.number(offset), label),
+            (TargetFormat::Both, None) => format!("{} <0x{:x}>", self.number(offset), target),
+        }
+    }
+
+    /// Formats the header printed before the instructions of a symbol starting at
+    /// the address, like objdump does (e.g. `0000000000010144 <main>:`).
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The address of the next instruction.
+    ///
+    /// # Returns
+    ///
+    /// A formatted header, if a symbol starts at the address.
+    pub fn symbol_header(&self, a: Address) -> Option<String> {
+        let symbol = self.symbols.get(a)?;
+        let width = match self.config.xlen {
+            Xlen::Rv32 => 8,
+            Xlen::Rv64 => 16,
+        };
+        Some(format!("{:0width$x} <{}>:", a, symbol.name, width = width))
+    }
+
     /// Formats an address.
     ///
     /// # Arguments
@@ -191,6 +518,60 @@ impl InstructionFormatter {
         format!("0x{:08x}", a)
     }
 
+    /// Formats an absolute target address, followed by its label if a symbol is found
+    /// (e.g. `0x10188 <main+0x44>`), as the targets of jumps are printed.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The target address.
+    ///
+    /// # Returns
+    ///
+    /// A formatted target string.
+    pub fn target(&self, a: Address) -> String {
+        match self.symbols.label(a) {
+            Some(label) => format!("0x{:x} <{}>", a, label),
+            None => format!("0x{:x}", a),
+        }
+    }
+
+    /// Formats a pseudo-instruction made from two instructions, with the resolved
+    /// value or address.
+    ///
+    /// # Arguments
+    ///
+    /// * `next` - The second instruction of the pair.
+    /// * `fusion` - The pseudo-instruction formed by the pair.
+    ///
+    /// # Returns
+    ///
+    /// A formatted pseudo-instruction string.
+    pub fn fusion(&self, next: &Instruction, fusion: &Fusion) -> String {
+        let s = &self.config.register_separator;
+        let (mnemonic, operands) = match fusion {
+            Fusion::Li { rd, value } => (
+                Some("li".into()),
+                format!("{},{}{}", self.register(rd), s, signed_hex(*value)),
+            ),
+            Fusion::La { rd, address } => (
+                Some("la".into()),
+                format!("{},{}{}", self.register(rd), s, self.target(*address)),
+            ),
+            Fusion::Call { target } => (Some("call".into()), self.target(*target)),
+            Fusion::Tail { target } => (Some("tail".into()), self.target(*target)),
+            Fusion::Load { rd, address } => (
+                next.mnemonic(),
+                format!("{},{}{}", self.register(rd), s, self.target(*address)),
+            ),
+        };
+        format!(
+            "{}{}{}",
+            self.optional_mnemonic(mnemonic),
+            self.config.mnemonic_separator,
+            operands
+        )
+    }
+
     /// Formats an instruction record.
     ///
     /// # Arguments
@@ -201,8 +582,11 @@ impl InstructionFormatter {
     ///
     /// A formatted instruction record string.
     pub fn record(&self, r: &InstructionRecord) -> String {
-        let instr = self.instruction(&r.instruction());
-        if self.config.show_addr {
+        let instr = match (r.fused_with(), r.fusion()) {
+            (Some(next), Some(fusion)) => self.fusion(&next, &fusion),
+            _ => self.instruction_at(&r.instruction(), r.address()),
+        };
+        let mut out = if self.config.show_addr {
             format!(
                 "{}{}{}",
                 self.address(r.address()),
@@ -211,6 +595,265 @@ impl InstructionFormatter {
             )
         } else {
             instr
+        };
+        if self.config.show_relocations {
+            for relocation in self.relocations(r) {
+                out.push('\n');
+                out.push_str(&self.relocation(relocation));
+            }
+        }
+        out
+    }
+
+    /// Formats bytes which aren't decoded as instructions as a data directive
+    /// (e.g. `.word 0x10074`).
+    ///
+    /// # Arguments
+    ///
+    /// * `d` - The data record to format.
+    ///
+    /// # Returns
+    ///
+    /// A formatted data record string.
+    pub fn data(&self, d: &DataRecord) -> String {
+        let directive = if self.config.mnemonic_uppercase {
+            d.directive().to_uppercase()
+        } else {
+            d.directive().to_string()
+        };
+        let data = format!(
+            "{}{}0x{:x}",
+            directive,
+            self.config.mnemonic_separator,
+            d.value()
+        );
+        if self.config.show_addr {
+            format!(
+                "{}{}{}",
+                self.address(d.address()),
+                self.config.addr_separator,
+                data
+            )
+        } else {
+            data
+        }
+    }
+
+    /// Formats an item of a traversal, either an instruction or data.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item to format.
+    ///
+    /// # Returns
+    ///
+    /// A formatted item string.
+    pub fn item(&self, item: &DisasmItem) -> String {
+        match item {
+            DisasmItem::Instruction(r) => self.record(r),
+            DisasmItem::Data(d) => self.data(d),
         }
     }
+
+    /// Formats a relocation, indented to stand out from the instructions
+    /// (e.g. `\t\t\t8: R_RISCV_CALL_PLT\tprintf`).
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The relocation to format.
+    ///
+    /// # Returns
+    ///
+    /// A formatted relocation string.
+    pub fn relocation(&self, r: &Relocation) -> String {
+        format!("\t\t\t{}", r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::disasm::Symbol;
+
+    fn format(instr: u32, aliases: bool) -> String {
+        let config = DisasmConfig {
+            aliases,
+            ..Default::default()
+        };
+        let instr = Instruction::try_from(instr).unwrap();
+        InstructionFormatter::new(config).instruction(&instr)
+    }
+
+    #[test]
+    fn test_csr() {
+        assert_eq!("csrrs a0, mstatus, zero", format(0x30002573, false));
+        assert_eq!("csrr a0, mstatus", format(0x30002573, true));
+        assert_eq!("csrrw zero, satp, a1", format(0x18059073, false));
+        assert_eq!("csrw satp, a1", format(0x18059073, true));
+        assert_eq!("csrs mie, t0", format(0x3042a073, true));
+        assert_eq!("csrc mie, t0", format(0x3042b073, true));
+        assert_eq!("csrrwi zero, mtvec, 0x5", format(0x3052d073, false));
+        assert_eq!("csrwi mtvec, 0x5", format(0x3052d073, true));
+        assert_eq!("csrsi mstatus, 0x8", format(0x30046073, true));
+        assert_eq!("csrrci a0, fflags, 0x1", format(0x0010f573, true));
+        assert_eq!("csrrw a1, 0x7c0, a1", format(0x7c0595f3, true));
+    }
+
+    #[test]
+    fn test_targets() {
+        let blt = Instruction::try_from(0x1ca5c263).unwrap(); // blt a1, a0, 0x1c4
+        let cj = Instruction::try_from(0xa2d1).unwrap(); // c.j 0x1c4
+        let auipc = Instruction::try_from(0x00002517).unwrap(); // auipc a0, 0x2
+        let addi = Instruction::try_from(0x1c450513).unwrap(); // addi a0, a0, 0x1c4
+
+        let formatter = |target_format| {
+            InstructionFormatter::new(DisasmConfig {
+                target_format,
+                aliases: false,
+                ..Default::default()
+            })
+        };
+        let absolute = formatter(TargetFormat::Absolute);
+        assert_eq!(
+            "blt a1, a0, 0x101c4",
+            absolute.instruction_at(&blt, 0x10000)
+        );
+        assert_eq!("c.j 0x101c4", absolute.instruction_at(&cj, 0x10000));
+        assert_eq!(
+            "auipc a0, 0x12000",
+            absolute.instruction_at(&auipc, 0x10000)
+        );
+        assert_eq!(
+            "addi a0, a0, 0x1c4",
+            absolute.instruction_at(&addi, 0x10000)
+        );
+        assert_eq!("blt a1, a0, 0x1c4", absolute.instruction(&blt));
+        // wraps around the address space of RV32
+        assert_eq!("c.j 0x1c0", absolute.instruction_at(&cj, 0xfffffffc));
+
+        let both = formatter(TargetFormat::Both);
+        assert_eq!(
+            "blt a1, a0, 0x1c4 <0x101c4>",
+            both.instruction_at(&blt, 0x10000)
+        );
+        let offset = formatter(TargetFormat::Offset);
+        assert_eq!("blt a1, a0, 0x1c4", offset.instruction_at(&blt, 0x10000));
+
+        // pseudo-instructions, and records
+        let j = Instruction::try_from(0x1c40006f).unwrap(); // jal zero, 0x1c4
+        let config = DisasmConfig {
+            show_addr: false,
+            ..Default::default()
+        };
+        let record = InstructionRecord::new(j, 0x10000);
+        assert_eq!(Some(0x101c4), record.target());
+        assert_eq!(
+            "j 0x101c4",
+            InstructionFormatter::new(config).record(&record)
+        );
+    }
+
+    #[test]
+    fn test_symbols() {
+        let symbols: SymbolTable = [Symbol::new("main", 0x10144), Symbol::new("foo", 0x10188)]
+            .into_iter()
+            .collect();
+        let formatter = |target_format| {
+            InstructionFormatter::new(DisasmConfig {
+                target_format,
+                show_addr: false,
+                ..Default::default()
+            })
+            .with_symbols(symbols.clone())
+        };
+        let blt = Instruction::try_from(0x1ca5c263).unwrap(); // blt a1, a0, 0x1c4
+        let absolute = formatter(TargetFormat::Absolute);
+        assert_eq!(
+            "blt a1, a0, 0x10188 <foo>",
+            absolute.instruction_at(&blt, 0xffc4)
+        );
+        assert_eq!(
+            "blt a1, a0, 0x10208 <foo+0x80>",
+            absolute.instruction_at(&blt, 0x10044)
+        );
+        assert_eq!("blt a1, a0, 0x1c4", absolute.instruction_at(&blt, 0x0));
+        let both = formatter(TargetFormat::Both);
+        assert_eq!("blt a1, a0, 0x1c4 <foo>", both.instruction_at(&blt, 0xffc4));
+        let offset = formatter(TargetFormat::Offset);
+        assert_eq!("blt a1, a0, 0x1c4", offset.instruction_at(&blt, 0xffc4));
+
+        let auipc = InstructionRecord::new(Instruction::try_from(0x00000097).unwrap(), 0x10144);
+        let jalr = Instruction::try_from(0x044080e7).unwrap();
+        let call = auipc.with_fusion(jalr, Fusion::Call { target: 0x10188 });
+        assert_eq!("call 0x10188 <foo>", absolute.record(&call));
+
+        assert_eq!(
+            Some("00010144 <main>:".to_string()),
+            absolute.symbol_header(0x10144)
+        );
+        assert_eq!(None, absolute.symbol_header(0x10148));
+    }
+
+    #[test]
+    fn test_fusion() {
+        let config = DisasmConfig {
+            show_addr: false,
+            ..Default::default()
+        };
+        let formatter = InstructionFormatter::new(config);
+        let auipc = InstructionRecord::new(Instruction::try_from(0x00000097).unwrap(), 0x100);
+        let jalr = Instruction::try_from(0x024080e7).unwrap();
+        let call = auipc.with_fusion(jalr, Fusion::Call { target: 0x124 });
+        assert_eq!("call 0x124", formatter.record(&call));
+
+        let ld = Instruction::decode(0x00853503, crate::model::Xlen::Rv64).unwrap();
+        let load = auipc.with_fusion(
+            ld,
+            Fusion::Load {
+                rd: Register::a0(),
+                address: 0x1108,
+            },
+        );
+        assert_eq!("ld a0, 0x1108", formatter.record(&load));
+        assert_eq!("100: ld a0, 0x1108", load.to_string());
+
+        // lui a0, 0x80000; addi a0, a0, -1 in RV64
+        let lui = InstructionRecord::new(Instruction::try_from(0x80000537).unwrap(), 0x100);
+        let addi = Instruction::try_from(0xfff50513).unwrap();
+        let li = lui.with_fusion(
+            addi,
+            Fusion::Li {
+                rd: Register::a0(),
+                value: -0x80000001,
+            },
+        );
+        assert_eq!("li a0, -0x80000001", formatter.record(&li));
+        assert_eq!(li.to_string(), format!("100: {}", formatter.record(&li)));
+
+        // lui a0, 0xfffff; addi a0, a0, -16
+        let lui = InstructionRecord::new(Instruction::try_from(0xfffff537).unwrap(), 0x100);
+        let addi = Instruction::try_from(0xff050513).unwrap();
+        let li = lui.with_fusion(
+            addi,
+            Fusion::Li {
+                rd: Register::a0(),
+                value: -0x1010,
+            },
+        );
+        assert_eq!("li a0, -0x1010", formatter.record(&li));
+        assert_eq!("100: li a0, -0x1010", li.to_string());
+    }
+
+    #[test]
+    fn test_aliases() {
+        assert_eq!("ret", format(0x00008067, true));
+        assert_eq!("jalr zero, ra, 0x0", format(0x00008067, false));
+        assert_eq!("li a0, 0xfffffff0", format(0xff000513, true));
+        assert_eq!("addi a0, zero, 0xfffffff0", format(0xff000513, false));
+        assert_eq!("jr 0x8(a0)", format(0x00850067, true));
+        assert_eq!("fneg.d fa0, fa1", format(0x22b59553, true));
+        assert_eq!("mv a0, a1", format(0x852e, true));
+        assert_eq!("c.mv a0, a1", format(0x852e, false));
+        assert_eq!("zext.h a0, a0", format(0x08054533, true));
+    }
 }
@@ -1,12 +1,13 @@
 use std::fmt::{self};
 
-use super::Address;
-use crate::instr::Instruction;
+use super::{peephole::signed_hex, Address, Fusion};
+use crate::instr::{Instruction, InstructionTrait};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub struct InstructionRecord {
     instruction: Instruction,
     address: Address,
+    fused: Option<(Instruction, Fusion)>,
 }
 
 impl InstructionRecord {
@@ -14,6 +15,15 @@ impl InstructionRecord {
         Self {
             instruction,
             address,
+            fused: None,
+        }
+    }
+
+    /// Combines the record with the instruction following it, into a single pseudo-instruction.
+    pub fn with_fusion(self, next: Instruction, fusion: Fusion) -> Self {
+        Self {
+            fused: Some((next, fusion)),
+            ..self
         }
     }
 
@@ -24,10 +34,55 @@ impl InstructionRecord {
     pub fn address(&self) -> Address {
         self.address
     }
+
+    /// Returns the number of bytes the record spans, including the fused instruction.
+    pub fn size(&self) -> Address {
+        let bytes = |instr: &Instruction| (usize::from(&instr.size()) / 8) as Address;
+        bytes(&self.instruction) + self.fused.map_or(0, |(next, _)| bytes(&next))
+    }
+
+    /// Returns the absolute address targeted by a PC-relative instruction (a branch,
+    /// `jal` or `auipc`), i.e. the record's address plus the offset.
+    pub fn target(&self) -> Option<Address> {
+        let offset = self.instruction.pc_offset()?;
+        Some(self.address.wrapping_add_signed(offset.into()))
+    }
+
+    /// Returns the instruction fused with the record's instruction, see [`Fusion`].
+    pub fn fused_with(&self) -> Option<Instruction> {
+        self.fused.map(|(next, _)| next)
+    }
+
+    /// Returns the pseudo-instruction formed by the record's instruction and the one following it.
+    pub fn fusion(&self) -> Option<Fusion> {
+        self.fused.map(|(_, fusion)| fusion)
+    }
 }
 
 impl fmt::Display for InstructionRecord {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:x}: {}", self.address, self.instruction)
+        match self.fused {
+            Some((_, Fusion::Li { rd, value })) => {
+                write!(f, "{:x}: li {}, {}", self.address, rd, signed_hex(value))
+            }
+            Some((_, Fusion::La { rd, address })) => {
+                write!(f, "{:x}: la {}, 0x{:x}", self.address, rd, address)
+            }
+            Some((_, Fusion::Call { target })) => {
+                write!(f, "{:x}: call 0x{:x}", self.address, target)
+            }
+            Some((_, Fusion::Tail { target })) => {
+                write!(f, "{:x}: tail 0x{:x}", self.address, target)
+            }
+            Some((next, Fusion::Load { rd, address })) => write!(
+                f,
+                "{:x}: {} {}, 0x{:x}",
+                self.address,
+                next.mnemonic().map_or(String::new(), |m| m.to_string()),
+                rd,
+                address
+            ),
+            None => write!(f, "{:x}: {}", self.address, self.instruction),
+        }
     }
 }
@@ -1,13 +1,26 @@
 mod config;
+mod data_record;
+#[allow(clippy::module_inception)]
 mod disasm;
 mod disasm_error;
+mod flow;
 mod formatter;
 mod instruction_record;
+mod peephole;
+mod relocation;
+mod symbol_table;
+mod traversal;
 
 pub use config::*;
+pub use data_record::*;
 pub use disasm::*;
 pub use disasm_error::*;
+pub(crate) use flow::Flow;
 pub use formatter::*;
 pub use instruction_record::*;
+pub use peephole::*;
+pub use relocation::*;
+pub use symbol_table::*;
+pub use traversal::*;
 
 pub type Address = u64;
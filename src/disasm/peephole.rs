@@ -0,0 +1,265 @@
+use std::collections::BTreeSet;
+
+use super::{Address, DisasmError, InstructionRecord};
+use crate::{
+    instr::{Instruction, InstructionTrait},
+    model::{Register, Xlen},
+};
+
+/// A pseudo-instruction spanning two consecutive instructions, with its value
+/// or target resolved.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Fusion {
+    /// `lui` followed by `addi` (or `addiw`), loading a constant
+    Li { rd: Register, value: i64 },
+    /// `auipc` followed by `addi`, loading an address
+    La { rd: Register, address: Address },
+    /// `auipc` followed by `jalr` linking the return address in `ra`
+    Call { target: Address },
+    /// `auipc` followed by `jalr` without linking
+    Tail { target: Address },
+    /// `auipc` followed by a load from the address (e.g. `ld a0, 0x2010`)
+    Load { rd: Register, address: Address },
+}
+
+impl Fusion {
+    /// Recognizes a pair of instructions forming a pseudo-instruction, where the second
+    /// one uses (and, except for jumps, overwrites) the register set by the first.
+    /// Compressed instructions are matched by their 32-bit equivalents.
+    pub fn find(first: &InstructionRecord, second: &Instruction, xlen: Xlen) -> Option<Self> {
+        let (upper, lower) = (first.instruction().expand(), second.expand());
+        let rd = upper.rd()?;
+        if lower.rs1() != Some(rd) || rd == Register::zero() {
+            return None;
+        }
+
+        let hi = i64::from(upper.immediate()?);
+        let lo = i64::from(lower.immediate()?);
+        let first_mnemonic: &str = upper.mnemonic()?.into();
+        let second_mnemonic: &str = lower.mnemonic()?.into();
+        let same_rd = lower.rd() == Some(rd);
+        let address = wrap(xlen, first.address().wrapping_add_signed(hi + lo));
+
+        match (first_mnemonic, second_mnemonic) {
+            ("lui", "addi") if same_rd => Some(Fusion::Li {
+                rd,
+                value: if xlen == Xlen::Rv32 {
+                    i64::from((hi + lo) as i32)
+                } else {
+                    hi + lo
+                },
+            }),
+            ("lui", "addiw") if same_rd && xlen != Xlen::Rv32 => Some(Fusion::Li {
+                rd,
+                value: i64::from((hi + lo) as i32),
+            }),
+            ("auipc", "addi") if same_rd => Some(Fusion::La { rd, address }),
+            ("auipc", "jalr") if lower.rd() == Some(Register::ra()) && rd == Register::ra() => {
+                Some(Fusion::Call { target: address })
+            }
+            ("auipc", "jalr") if lower.rd() == Some(Register::zero()) => {
+                Some(Fusion::Tail { target: address })
+            }
+            ("auipc", "lb" | "lh" | "lw" | "ld" | "lbu" | "lhu" | "lwu") if same_rd => {
+                Some(Fusion::Load { rd, address })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Truncates an address to the width of the base ISA.
+fn wrap(xlen: Xlen, address: Address) -> Address {
+    if xlen == Xlen::Rv32 {
+        address & 0xffff_ffff
+    } else {
+        address
+    }
+}
+
+/// Formats the constant of a fused `li` as signed hexadecimal, e.g. `-0x1`.
+pub(crate) fn signed_hex(value: i64) -> String {
+    if value < 0 {
+        format!("-0x{:x}", value.unsigned_abs())
+    } else {
+        format!("0x{:x}", value)
+    }
+}
+
+/// A peephole pass over a stream of disassembled instructions, combining pairs
+/// of instructions into a single record, with the resolved [`Fusion`].
+///
+/// # Example
+///
+/// ```
+/// # use riscv_isa::{Disasm, Fusion, Register};
+/// // auipc ra, 0x0; jalr ra, 0x24(ra)
+/// let data = vec![0x97, 0x00, 0x00, 0x00, 0xe7, 0x80, 0x40, 0x02];
+/// let mut records = Disasm::new(std::io::Cursor::new(data)).peephole();
+///
+/// let record = records.next().unwrap().unwrap();
+/// assert_eq!(Some(Fusion::Call { target: 0x24 }), record.fusion());
+/// assert!(records.next().is_none());
+/// ```
+pub struct Peephole<I: Iterator<Item = Result<InstructionRecord, DisasmError>>> {
+    records: I,
+    xlen: Xlen,
+    pending: Option<Result<InstructionRecord, DisasmError>>,
+    relocated: BTreeSet<Address>,
+}
+
+impl<I: Iterator<Item = Result<InstructionRecord, DisasmError>>> Peephole<I> {
+    pub fn new(records: I, xlen: Xlen) -> Self {
+        Self {
+            records,
+            xlen,
+            pending: None,
+            relocated: BTreeSet::new(),
+        }
+    }
+
+    /// Doesn't fuse the instructions at the addresses, whose immediates are resolved
+    /// by the linker (e.g. the targets of relocations in object files).
+    pub fn with_relocated(mut self, addresses: impl IntoIterator<Item = Address>) -> Self {
+        self.relocated.extend(addresses);
+        self
+    }
+
+    fn is_relocated(&self, r: &InstructionRecord) -> bool {
+        self.relocated
+            .range(r.address()..)
+            .next()
+            .is_some_and(|a| a.wrapping_sub(r.address()) < r.size())
+    }
+}
+
+impl<I: Iterator<Item = Result<InstructionRecord, DisasmError>>> Iterator for Peephole<I> {
+    type Item = Result<InstructionRecord, DisasmError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.pending.take().or_else(|| self.records.next())? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        match self.records.next() {
+            Some(Ok(second)) => {
+                let instr = second.instruction();
+                let relocated = self.is_relocated(&first) || self.is_relocated(&second);
+                match Fusion::find(&first, &instr, self.xlen).filter(|_| !relocated) {
+                    Some(fusion) => return Some(Ok(first.with_fusion(instr, fusion))),
+                    None => self.pending = Some(Ok(second)),
+                }
+            }
+            // the error is reported after the record read successfully
+            other => self.pending = other,
+        }
+        Some(Ok(first))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Disasm, DisasmConfig};
+    use std::io::Cursor;
+
+    fn fuse(words: &[u32], xlen: Xlen) -> Vec<InstructionRecord> {
+        let data: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+        let config = DisasmConfig {
+            xlen,
+            start_addr: 0x1000,
+            ..Default::default()
+        };
+        Disasm::with_config(Cursor::new(data), config)
+            .peephole()
+            .map(|r| r.unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_constants() {
+        // lui a0, 0x12345; addi a0, a0, 0x678
+        let records = fuse(&[0x12345537, 0x67850513], Xlen::Rv32);
+        assert_eq!(1, records.len());
+        let li = Fusion::Li {
+            rd: Register::a0(),
+            value: 0x12345678,
+        };
+        assert_eq!(Some(li), records[0].fusion());
+        assert_eq!(Some(0x67850513), records[0].fused_with().map(u32::from));
+
+        // lui a0, 0x80000; addi a0, a0, -1 wraps in RV32, but not in RV64
+        let records = fuse(&[0x80000537, 0xfff50513], Xlen::Rv32);
+        assert!(matches!(
+            records[0].fusion(),
+            Some(Fusion::Li {
+                value: 0x7fffffff,
+                ..
+            })
+        ));
+        let records = fuse(&[0x80000537, 0xfff50513], Xlen::Rv64);
+        assert!(matches!(
+            records[0].fusion(),
+            Some(Fusion::Li {
+                value: -0x80000001,
+                ..
+            })
+        ));
+        assert_eq!("1000: li a0, -0x80000001", records[0].to_string());
+        // lui a0, 0x0; addi a0, a0, -1
+        let records = fuse(&[0x00000537, 0xfff50513], Xlen::Rv64);
+        assert_eq!("1000: li a0, -0x1", records[0].to_string());
+        // addiw wraps in RV64
+        let records = fuse(&[0x80000537, 0xfff5051b], Xlen::Rv64);
+        assert!(matches!(
+            records[0].fusion(),
+            Some(Fusion::Li {
+                value: 0x7fffffff,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_pc_relative() {
+        // auipc a0, 0x2; addi a0, a0, -16
+        let records = fuse(&[0x00002517, 0xff050513], Xlen::Rv32);
+        let la = Fusion::La {
+            rd: Register::a0(),
+            address: 0x2ff0,
+        };
+        assert_eq!(Some(la), records[0].fusion());
+
+        // auipc a0, 0x1; ld a0, 8(a0)
+        let records = fuse(&[0x00001517, 0x00853503], Xlen::Rv64);
+        let load = Fusion::Load {
+            rd: Register::a0(),
+            address: 0x2008,
+        };
+        assert_eq!(Some(load), records[0].fusion());
+
+        // auipc t1, 0x0; jalr zero, 0x10(t1)
+        let records = fuse(&[0x00000317, 0x01030067], Xlen::Rv32);
+        assert_eq!(Some(Fusion::Tail { target: 0x1010 }), records[0].fusion());
+    }
+
+    #[test]
+    fn test_unrelated() {
+        // auipc a0, 0x0; addi a1, a1, 1; addi a0, a0, 4
+        let records = fuse(&[0x00000517, 0x00158593, 0x00450513], Xlen::Rv32);
+        assert_eq!(3, records.len());
+        assert!(records.iter().all(|r| r.fusion().is_none()));
+        assert_eq!(0x1008, records[2].address());
+
+        // the error is reported after the preceding instruction
+        let data = vec![0x17, 0x05, 0x00, 0x00, 0x13, 0x05];
+        let mut records = Disasm::new(Cursor::new(data)).peephole();
+        assert!(matches!(records.next(), Some(Ok(_))));
+        assert!(matches!(records.next(), Some(Err(_))));
+
+        // lui a0, 0x1; addi a1, a0, 1 (different destination)
+        let records = fuse(&[0x00001537, 0x00150593], Xlen::Rv32);
+        assert_eq!(2, records.len());
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use super::Address;
+
+/// Symbol name of relocations that don't refer to any symbol, as printed by objdump.
+pub const ABS_SYMBOL: &str = "*ABS*";
+
+/// A relocation applied to an instruction by the linker, e.g. `R_RISCV_CALL_PLT`
+/// against `printf`. The immediates of relocated instructions in object files
+/// are placeholders (usually zero), so the relocation tells the actual operand.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Relocation {
+    /// Address of the relocated instruction (or one of its bytes)
+    pub address: Address,
+    /// Relocation type, e.g. `R_RISCV_HI20`
+    pub kind: String,
+    /// Name of the symbol the relocation refers to, [`ABS_SYMBOL`] if none
+    pub symbol: String,
+    pub addend: i64,
+}
+
+impl Relocation {
+    pub fn new(
+        address: Address,
+        kind: impl Into<String>,
+        symbol: impl Into<String>,
+        addend: i64,
+    ) -> Self {
+        Self {
+            address,
+            kind: kind.into(),
+            symbol: symbol.into(),
+            addend,
+        }
+    }
+
+    /// Returns the symbol with the addend, e.g. `buf+0x10` or `*ABS*-0x4`.
+    pub fn target(&self) -> String {
+        match self.addend {
+            0 => self.symbol.clone(),
+            a if a < 0 => format!("{}-0x{:x}", self.symbol, a.unsigned_abs()),
+            a => format!("{}+0x{:x}", self.symbol, a),
+        }
+    }
+}
+
+impl fmt::Display for Relocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:x}: {}\t{}", self.address, self.kind, self.target())
+    }
+}
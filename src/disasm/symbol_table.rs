@@ -0,0 +1,204 @@
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+use super::{Address, DisasmError};
+use crate::error::RISCVError;
+
+/// What a symbol labels, if it's known (e.g. from the type of an ELF symbol).
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub enum SymbolKind {
+    #[default]
+    Unknown,
+    /// A function or other code, used as an entry point by [`Disasm::traverse`](super::Disasm::traverse)
+    Code,
+    /// A variable or other data
+    Data,
+}
+
+/// A named address, such as a function or a global variable.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Symbol {
+    pub name: String,
+    pub address: Address,
+    /// Size in bytes, 0 if unknown
+    pub size: u64,
+    pub kind: SymbolKind,
+}
+
+impl Symbol {
+    pub fn new(name: impl Into<String>, address: Address) -> Self {
+        Self {
+            name: name.into(),
+            address,
+            size: 0,
+            kind: SymbolKind::Unknown,
+        }
+    }
+
+    pub fn with_size(self, size: u64) -> Self {
+        Self { size, ..self }
+    }
+
+    pub fn with_kind(self, kind: SymbolKind) -> Self {
+        Self { kind, ..self }
+    }
+}
+
+/// Symbols by address, used to label addresses and targets in the disassembly.
+///
+/// A table can be read from an ELF file (see [`ElfFile::symbols`](crate::ElfFile::symbols)),
+/// from a map file, or built programmatically.
+///
+/// # Example
+///
+/// ```
+/// # use riscv_isa::{Symbol, SymbolTable};
+/// let mut symbols = SymbolTable::new();
+/// symbols.insert(Symbol::new("main", 0x10144));
+///
+/// assert_eq!(Some("main".to_string()), symbols.label(0x10144));
+/// assert_eq!(Some("main+0x44".to_string()), symbols.label(0x10188));
+/// assert_eq!(None, symbols.label(0x10000));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SymbolTable {
+    symbols: BTreeMap<Address, Symbol>,
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads symbols from a map file, with a symbol per line given by its hexadecimal
+    /// address and name, optionally separated by a type as printed by `nm`
+    /// (e.g. `00010144 T main`, where `T` marks code). Empty lines and lines starting
+    /// with `#` are skipped.
+    pub fn from_map_file(reader: impl BufRead) -> Result<Self, DisasmError> {
+        let mut table = Self::new();
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (address, kind, name) = match fields.as_slice() {
+                [] => continue,
+                [first, ..] if first.starts_with('#') => continue,
+                [address, name] => (address, SymbolKind::Unknown, name),
+                [address, kind, name] => (address, Self::nm_kind(kind), name),
+                _ => return Err(Self::invalid_line(i)),
+            };
+            let address = address.trim_start_matches("0x");
+            let address =
+                Address::from_str_radix(address, 16).map_err(|_| Self::invalid_line(i))?;
+            table.insert(Symbol::new(*name, address).with_kind(kind));
+        }
+        Ok(table)
+    }
+
+    /// Returns the kind of a symbol of the type printed by `nm`.
+    fn nm_kind(kind: &str) -> SymbolKind {
+        match kind {
+            "T" | "t" => SymbolKind::Code,
+            "D" | "d" | "B" | "b" | "R" | "r" => SymbolKind::Data,
+            _ => SymbolKind::Unknown,
+        }
+    }
+
+    fn invalid_line(index: usize) -> DisasmError {
+        RISCVError::DisasmError(format!(
+            "Invalid symbol on line {} of the map file",
+            index + 1
+        ))
+        .into()
+    }
+
+    /// Adds a symbol, replacing the one previously defined at the same address.
+    pub fn insert(&mut self, symbol: Symbol) {
+        self.symbols.insert(symbol.address, symbol);
+    }
+
+    /// Returns the symbol defined at the address.
+    pub fn get(&self, address: Address) -> Option<&Symbol> {
+        self.symbols.get(&address)
+    }
+
+    /// Finds the closest symbol at or below the address, with the offset of the
+    /// address from the symbol.
+    pub fn find(&self, address: Address) -> Option<(&Symbol, u64)> {
+        self.symbols
+            .range(..=address)
+            .next_back()
+            .map(|(start, symbol)| (symbol, address - start))
+    }
+
+    /// Returns the address relative to the closest symbol, e.g. `main+0x44`.
+    pub fn label(&self, address: Address) -> Option<String> {
+        self.find(address).map(|(symbol, offset)| match offset {
+            0 => symbol.name.clone(),
+            _ => format!("{}+0x{:x}", symbol.name, offset),
+        })
+    }
+
+    /// Returns the symbols ordered by address.
+    pub fn iter(&self) -> impl Iterator<Item = &Symbol> {
+        self.symbols.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+}
+
+impl FromIterator<Symbol> for SymbolTable {
+    fn from_iter<T: IntoIterator<Item = Symbol>>(iter: T) -> Self {
+        let mut table = Self::new();
+        iter.into_iter().for_each(|symbol| table.insert(symbol));
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let symbols: SymbolTable = [
+            Symbol::new("_start", 0x10000).with_size(0x20),
+            Symbol::new("main", 0x10144),
+            Symbol::new("main_alias", 0x10144),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(2, symbols.len());
+        assert_eq!("main_alias", symbols.get(0x10144).unwrap().name);
+        assert_eq!(None, symbols.get(0x10148));
+        let (symbol, offset) = symbols.find(0x10010).unwrap();
+        assert_eq!(("_start", 0x10), (symbol.name.as_str(), offset));
+        assert_eq!(Some("main_alias+0x44".to_string()), symbols.label(0x10188));
+        assert_eq!(None, symbols.find(0xfffc));
+    }
+
+    #[test]
+    fn test_map_file() {
+        let map = "# generated by nm\n\n0000000000010144 T main\n0x10000 _start\n";
+        let symbols = SymbolTable::from_map_file(map.as_bytes()).unwrap();
+        let names: Vec<(&str, Address)> = symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.address))
+            .collect();
+        assert_eq!(vec![("_start", 0x10000), ("main", 0x10144)], names);
+        assert_eq!(SymbolKind::Unknown, symbols.get(0x10000).unwrap().kind);
+        assert_eq!(SymbolKind::Code, symbols.get(0x10144).unwrap().kind);
+
+        let result = SymbolTable::from_map_file("10000 _start\nmain\n".as_bytes());
+        assert!(matches!(
+            result,
+            Err(DisasmError::RISCVError(RISCVError::DisasmError(msg))) if msg.ends_with("line 2 of the map file")
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::vec;
+
+use super::{Address, DataRecord, InstructionFormatter, InstructionRecord};
+
+/// An item of a [`Traversal`]: an instruction reached from an entry point, or bytes
+/// which weren't reached.
+#[derive(Debug, PartialEq, Clone)]
+pub enum DisasmItem {
+    Instruction(InstructionRecord),
+    Data(DataRecord),
+}
+
+impl DisasmItem {
+    pub fn address(&self) -> Address {
+        match self {
+            DisasmItem::Instruction(r) => r.address(),
+            DisasmItem::Data(d) => d.address(),
+        }
+    }
+
+    pub fn size(&self) -> Address {
+        match self {
+            DisasmItem::Instruction(r) => r.size(),
+            DisasmItem::Data(d) => d.size(),
+        }
+    }
+}
+
+/// Result of a recursive traversal (see [`Disasm::traverse`](super::Disasm::traverse)):
+/// iterates over all the bytes in address order, as instructions where code was
+/// reached and as data directives elsewhere.
+#[derive(Debug)]
+pub struct Traversal {
+    items: vec::IntoIter<DisasmItem>,
+    formatter: InstructionFormatter,
+}
+
+impl Traversal {
+    /// Creates a traversal of the bytes placed at `start`, with the instructions
+    /// which were reached, by their offsets from `start`. The gaps between them are
+    /// split into naturally aligned words, halves and bytes.
+    pub(crate) fn new(
+        formatter: InstructionFormatter,
+        bytes: &[u8],
+        start: Address,
+        code: BTreeMap<Address, InstructionRecord>,
+    ) -> Self {
+        let end = bytes.len() as Address;
+        let mut items = Vec::new();
+        let mut code = code.into_iter().peekable();
+        let mut offset = 0;
+        while offset < end {
+            let next_code = code.peek().map_or(end, |(o, _)| *o);
+            if next_code == offset {
+                let (_, record) = code.next().unwrap();
+                offset += record.size();
+                items.push(DisasmItem::Instruction(record));
+                continue;
+            }
+            let address = start.wrapping_add(offset);
+            let size = data_size(address, next_code - offset);
+            let data = bytes[offset as usize..(offset + size) as usize].to_vec();
+            items.push(DisasmItem::Data(DataRecord::new(address, data)));
+            offset += size;
+        }
+        Self {
+            items: items.into_iter(),
+            formatter,
+        }
+    }
+
+    pub fn formatter(&self) -> &InstructionFormatter {
+        &self.formatter
+    }
+
+    /// Prints the remaining items, with a header before each symbol.
+    pub fn print_all(&mut self) {
+        let formatter = self.formatter.clone();
+        for item in self.by_ref() {
+            if let Some(header) = formatter.symbol_header(item.address()) {
+                println!("\n{}", header);
+            }
+            println!("{}", formatter.item(&item));
+        }
+    }
+}
+
+impl Iterator for Traversal {
+    type Item = DisasmItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next()
+    }
+}
+
+/// Returns the size of the largest naturally aligned data directive which fits.
+fn data_size(address: Address, remaining: Address) -> Address {
+    [4, 2]
+        .into_iter()
+        .find(|size| address.is_multiple_of(*size) && remaining >= *size)
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::Assembler;
+    use crate::disasm::{Disasm, DisasmConfig, Symbol, SymbolKind, SymbolTable};
+    use crate::model::Xlen;
+    use std::io::Cursor;
+
+    fn traverse(source: &str, entries: &[Address], symbols: bool) -> Vec<String> {
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x1000)
+            .assemble(source)
+            .unwrap();
+        let config = DisasmConfig {
+            addr_separator: ": ".into(),
+            ..DisasmConfig::with_address(program.text_address())
+        };
+        let mut disasm = Disasm::with_config(Cursor::new(program.text().to_vec()), config)
+            .with_entry_points(entries.iter().copied());
+        if symbols {
+            disasm = disasm.with_symbols(program.symbol_table());
+        }
+        let traversal = disasm.traverse().unwrap();
+        let formatter = traversal.formatter().clone();
+        traversal.map(|item| formatter.item(&item)).collect()
+    }
+
+    #[test]
+    fn test_jump_table() {
+        let source = "
+            j start
+        table:
+            .word 0x1234
+            .half 0x56
+            .byte 1, 2
+        start:
+            li a0, 1
+            beqz a0, start
+            ret
+        ";
+        assert_eq!(
+            traverse(source, &[0x1000], false),
+            vec![
+                "0x00001000: j 0x100c",
+                "0x00001004: .word 0x1234",
+                "0x00001008: .word 0x2010056",
+                "0x0000100c: li a0, 0x1",
+                "0x00001010: beqz a0, 0x100c",
+                "0x00001014: ret",
+            ]
+        );
+        // labels of unknown kind (like `table`) aren't entry points, but the start is
+        assert_eq!(
+            traverse(source, &[], true),
+            vec![
+                "0x00001000: j 0x100c <start>",
+                "0x00001004: .word 0x1234",
+                "0x00001008: .word 0x2010056",
+                "0x0000100c: li a0, 0x1",
+                "0x00001010: beqz a0, 0x100c <start>",
+                "0x00001014: ret",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unreached_code() {
+        let source = "
+        _start:
+            j end
+            addi a0, a0, 1
+            addi a0, a0, 2
+        end:
+            ecall
+        ";
+        let items = traverse(source, &[], false);
+        assert_eq!(items[1], "0x00001004: .word 0x150513");
+        assert_eq!(items[2], "0x00001008: .word 0x250513");
+        assert!(items[3].starts_with("0x0000100c: ecall"));
+
+        let items = traverse(source, &[0x1000, 0x1008], false);
+        assert_eq!(items[1], "0x00001004: .word 0x150513");
+        assert_eq!(items[2], "0x00001008: addi a0, a0, 0x2");
+    }
+
+    #[test]
+    fn test_code_symbols() {
+        // jr a0; addi a0, a0, 1; addi a0, a0, 2
+        let data = [
+            0x67, 0x00, 0x05, 0x00, 0x13, 0x05, 0x15, 0x00, 0x13, 0x05, 0x25, 0x00,
+        ];
+        let symbols: SymbolTable = [
+            Symbol::new("handler", 0x1008).with_kind(SymbolKind::Code),
+            Symbol::new("value", 0x1004).with_kind(SymbolKind::Data),
+        ]
+        .into_iter()
+        .collect();
+        let disasm = Disasm::with_config(Cursor::new(data), DisasmConfig::with_address(0x1000))
+            .with_symbols(symbols);
+        let items: Vec<DisasmItem> = disasm.traverse().unwrap().collect();
+        assert!(matches!(items[1], DisasmItem::Data(_)));
+        assert!(matches!(items[2], DisasmItem::Instruction(_)));
+        assert_eq!(items[2].address(), 0x1008);
+    }
+
+    #[test]
+    fn test_end_of_address_space() {
+        // j -0x4 (to the byte before the start); nop
+        let data = [0x6f, 0xf0, 0xdf, 0xff, 0x13, 0x00, 0x00, 0x00];
+        let start = Address::MAX - 7;
+        let disasm = Disasm::with_config(Cursor::new(data), DisasmConfig::with_address(start))
+            .with_entry_points([start + 4]);
+        let items: Vec<DisasmItem> = disasm.traverse().unwrap().collect();
+        assert_eq!(items.len(), 2);
+        assert!(items
+            .iter()
+            .all(|item| matches!(item, DisasmItem::Instruction(_))));
+        assert_eq!(items[1].address(), Address::MAX - 3);
+    }
+
+    #[test]
+    fn test_items() {
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x1000)
+            .assemble("ret\n.half 0")
+            .unwrap();
+        let disasm = Disasm::with_config(
+            Cursor::new(program.text().to_vec()),
+            DisasmConfig::with_address(0x1000),
+        );
+        let items: Vec<DisasmItem> = disasm.traverse().unwrap().collect();
+        assert_eq!(items.len(), 2);
+        assert!(matches!(items[0], DisasmItem::Instruction(_)));
+        assert_eq!(
+            items[1],
+            DisasmItem::Data(DataRecord::new(0x1004, vec![0, 0]))
+        );
+        assert_eq!((items[1].address(), items[1].size()), (0x1004, 2));
+    }
+
+    #[test]
+    fn test_data_size() {
+        assert_eq!(data_size(0x1000, 8), 4);
+        assert_eq!(data_size(0x1000, 3), 2);
+        assert_eq!(data_size(0x1002, 8), 2);
+        assert_eq!(data_size(0x1003, 8), 1);
+    }
+}
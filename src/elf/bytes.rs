@@ -0,0 +1,130 @@
+use super::{ElfClass, ElfError};
+
+/// Bounds-checked little-endian reads of ELF structures.
+pub(crate) struct ElfBytes<'a> {
+    data: &'a [u8],
+    class: ElfClass,
+}
+
+impl<'a> ElfBytes<'a> {
+    pub fn new(data: &'a [u8], class: ElfClass) -> Self {
+        Self { data, class }
+    }
+
+    pub fn slice(&self, offset: u64, len: u64) -> Result<&'a [u8], ElfError> {
+        let start = usize::try_from(offset).map_err(|_| ElfError::Truncated("offset"))?;
+        let len = usize::try_from(len).map_err(|_| ElfError::Truncated("size"))?;
+        start
+            .checked_add(len)
+            .and_then(|end| self.data.get(start..end))
+            .ok_or(ElfError::Truncated("data"))
+    }
+
+    fn array<const N: usize>(&self, offset: u64) -> Result<[u8; N], ElfError> {
+        let bytes = self.slice(offset, N as u64)?;
+        Ok(bytes.try_into().expect("slice has the requested length"))
+    }
+
+    pub fn u16(&self, offset: u64) -> Result<u16, ElfError> {
+        self.array(offset).map(u16::from_le_bytes)
+    }
+
+    pub fn u32(&self, offset: u64) -> Result<u32, ElfError> {
+        self.array(offset).map(u32::from_le_bytes)
+    }
+
+    pub fn u64(&self, offset: u64) -> Result<u64, ElfError> {
+        self.array(offset).map(u64::from_le_bytes)
+    }
+
+    /// Reads an address, offset or size, which are 4 bytes wide in ELF32 and 8 bytes in ELF64.
+    pub fn addr(&self, offset: u64) -> Result<u64, ElfError> {
+        match self.class {
+            ElfClass::Elf32 => self.u32(offset).map(u64::from),
+            ElfClass::Elf64 => self.u64(offset),
+        }
+    }
+
+    /// Reads a null-terminated string.
+    pub fn str(&self, offset: u64) -> Result<&'a str, ElfError> {
+        let start = usize::try_from(offset).map_err(|_| ElfError::Truncated("string"))?;
+        let rest = self
+            .data
+            .get(start..)
+            .ok_or(ElfError::Truncated("string"))?;
+        let len = rest
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or(ElfError::Truncated("string"))?;
+        std::str::from_utf8(&rest[..len])
+            .map_err(|_| ElfError::InvalidSection("non UTF-8 name".to_string()))
+    }
+}
+
+/// Adds offsets read from the file, failing instead of overflowing.
+pub(crate) fn add_offset(base: u64, offset: u64) -> Result<u64, ElfError> {
+    base.checked_add(offset)
+        .ok_or(ElfError::Truncated("offset"))
+}
+
+/// Returns the offset of the `index`-th entry of a table, failing instead of overflowing.
+pub(crate) fn entry_offset(table: u64, index: u64, entsize: u64) -> Result<u64, ElfError> {
+    index
+        .checked_mul(entsize)
+        .ok_or(ElfError::Truncated("offset"))
+        .and_then(|offset| add_offset(table, offset))
+}
+
+/// A little-endian writer of ELF structures, the counterpart of [`ElfBytes`].
+pub(crate) struct ElfBuffer {
+    data: Vec<u8>,
+    class: ElfClass,
+}
+
+impl ElfBuffer {
+    pub fn new(class: ElfClass) -> Self {
+        Self {
+            data: Vec::new(),
+            class,
+        }
+    }
+
+    pub fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.data.extend_from_slice(bytes);
+        self
+    }
+
+    pub fn u8(&mut self, value: u8) -> &mut Self {
+        self.bytes(&[value])
+    }
+
+    pub fn u16(&mut self, value: u16) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    pub fn u32(&mut self, value: u32) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    pub fn u64(&mut self, value: u64) -> &mut Self {
+        self.bytes(&value.to_le_bytes())
+    }
+
+    /// Writes an address, offset or size, which are 4 bytes wide in ELF32 and 8 bytes in ELF64.
+    pub fn addr(&mut self, value: u64) -> &mut Self {
+        match self.class {
+            ElfClass::Elf32 => self.u32(value as u32),
+            ElfClass::Elf64 => self.u64(value),
+        }
+    }
+
+    /// Pads the data with zeros up to the offset.
+    pub fn pad_to(&mut self, offset: u64) -> &mut Self {
+        self.data.resize(self.data.len().max(offset as usize), 0);
+        self
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.data
+    }
+}
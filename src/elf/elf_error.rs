@@ -0,0 +1,26 @@
+use std::io;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ElfError {
+    #[error("Not an ELF file")]
+    InvalidMagic,
+
+    #[error("Unsupported ELF class: {0}")]
+    UnsupportedClass(u8),
+
+    #[error("Only little-endian ELF files are supported")]
+    UnsupportedEncoding,
+
+    #[error("Not a RISC-V ELF file (e_machine: {0})")]
+    UnsupportedMachine(u16),
+
+    #[error("ELF data out of bounds: {0}")]
+    Truncated(&'static str),
+
+    #[error("Invalid section: {0}")]
+    InvalidSection(String),
+
+    #[error("IO error: {0}")]
+    IOError(#[from] io::Error),
+}
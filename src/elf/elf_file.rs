@@ -0,0 +1,648 @@
+use std::io::{Cursor, Read};
+
+use super::{
+    bytes::{add_offset, entry_offset, ElfBytes},
+    ElfError, ElfHeader, ElfRelocation, ElfSymbol, Section, ET_REL, SHT_RELA, SHT_SYMTAB,
+    STT_SECTION,
+};
+use crate::disasm::{Disasm, DisasmConfig, Relocation, Symbol, SymbolTable, ABS_SYMBOL};
+
+/// A RISC-V ELF file (executable or object file), with its header and sections.
+///
+/// # Example
+///
+/// ```no_run
+/// # use riscv_isa::{ElfFile, ElfError};
+/// # fn test() -> Result<(), ElfError> {
+/// let elf = ElfFile::read(std::fs::File::open("a.out")?)?;
+/// for section in elf.executable_sections() {
+///     println!("Disassembly of section {}:", section.name);
+///     let mut disasm = elf.disasm(section)?;
+///     disasm.print_all().ok();
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElfFile {
+    data: Vec<u8>,
+    header: ElfHeader,
+    sections: Vec<Section>,
+}
+
+impl ElfFile {
+    /// Parses an ELF file, validating that it's a little-endian RISC-V file.
+    pub fn parse(data: Vec<u8>) -> Result<Self, ElfError> {
+        let header = ElfHeader::parse(&data)?;
+        let bytes = ElfBytes::new(&data, header.class);
+
+        // section headers paired with offsets of their names in the string table
+        let headers = (0..u64::from(header.shnum))
+            .map(|i| {
+                let offset = entry_offset(header.shoff, i, u64::from(header.shentsize))?;
+                Ok((
+                    bytes.u32(offset)?,
+                    Section::parse(&bytes, header.class, offset)?,
+                ))
+            })
+            .collect::<Result<Vec<(u32, Section)>, ElfError>>()?;
+
+        let strtab = headers
+            .get(usize::from(header.shstrndx))
+            .map(|(_, section)| section.offset);
+        let sections = headers
+            .into_iter()
+            .map(|(name, mut section)| {
+                if let Some(strtab) = strtab {
+                    section.name = bytes.str(add_offset(strtab, u64::from(name))?)?.to_string();
+                }
+                Ok(section)
+            })
+            .collect::<Result<Vec<Section>, ElfError>>()?;
+
+        Ok(Self {
+            data,
+            header,
+            sections,
+        })
+    }
+
+    /// Reads and parses an ELF file.
+    pub fn read(mut reader: impl Read) -> Result<Self, ElfError> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        Self::parse(data)
+    }
+
+    pub fn header(&self) -> &ElfHeader {
+        &self.header
+    }
+
+    pub fn sections(&self) -> &[Section] {
+        &self.sections
+    }
+
+    pub fn section_by_name(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Returns the sections holding executable code, in the order of the section headers.
+    pub fn executable_sections(&self) -> impl Iterator<Item = &Section> {
+        self.sections
+            .iter()
+            .filter(|section| section.is_executable())
+    }
+
+    /// Returns the content of the section (empty for sections without data, like `.bss`).
+    pub fn section_data(&self, section: &Section) -> Result<&[u8], ElfError> {
+        if !section.has_data() {
+            return Ok(&[]);
+        }
+        ElfBytes::new(&self.data, self.header.class)
+            .slice(section.offset, section.size)
+            .map_err(|_| ElfError::InvalidSection(section.name.clone()))
+    }
+
+    /// Returns the entries of the symbol table (`.symtab`), empty if the file is stripped.
+    pub fn elf_symbols(&self) -> Result<Vec<ElfSymbol>, ElfError> {
+        let Some(symtab) = self.sections.iter().find(|s| s.kind == SHT_SYMTAB) else {
+            return Ok(Vec::new());
+        };
+        let invalid = || ElfError::InvalidSection(symtab.name.clone());
+        let strtab = self
+            .sections
+            .get(symtab.link as usize)
+            .ok_or_else(invalid)?;
+        if symtab.entsize == 0 {
+            return Err(invalid());
+        }
+
+        let bytes = ElfBytes::new(&self.data, self.header.class);
+        bytes.slice(symtab.offset, symtab.size)?;
+        (0..symtab.size / symtab.entsize)
+            .map(|i| {
+                let offset = entry_offset(symtab.offset, i, symtab.entsize)?;
+                let (name, mut symbol) = ElfSymbol::parse(&bytes, self.header.class, offset)?;
+                symbol.name = bytes
+                    .str(add_offset(strtab.offset, u64::from(name))?)?
+                    .to_string();
+                Ok(symbol)
+            })
+            .collect()
+    }
+
+    /// Returns the symbols labelling code and data, to be shown in the disassembly.
+    pub fn symbols(&self) -> Result<SymbolTable, ElfError> {
+        Ok(self
+            .elf_symbols()?
+            .into_iter()
+            .filter(ElfSymbol::is_label)
+            .map(|s| {
+                let kind = s.symbol_kind();
+                Symbol::new(s.name, s.value)
+                    .with_size(s.size)
+                    .with_kind(kind)
+            })
+            .collect())
+    }
+
+    /// Returns the symbols defined in the section. Unlike [`Self::symbols`], it keeps
+    /// apart symbols of sections sharing addresses, as in relocatable files.
+    pub fn section_symbols(&self, section: &Section) -> Result<SymbolTable, ElfError> {
+        let index = self.section_index(section);
+        Ok(self
+            .elf_symbols()?
+            .into_iter()
+            .filter(|s| s.is_label() && Some(usize::from(s.shndx)) == index)
+            .map(|s| {
+                let kind = s.symbol_kind();
+                Symbol::new(s.name, s.value)
+                    .with_size(s.size)
+                    .with_kind(kind)
+            })
+            .collect())
+    }
+
+    /// Returns the entries of the relocation sections (`.rela.*`) applying to the section.
+    pub fn elf_relocations(&self, section: &Section) -> Result<Vec<ElfRelocation>, ElfError> {
+        let index = self.section_index(section);
+        let bytes = ElfBytes::new(&self.data, self.header.class);
+        let mut relocations = Vec::new();
+        for rela in self
+            .sections
+            .iter()
+            .filter(|s| s.kind == SHT_RELA && Some(s.info as usize) == index)
+        {
+            if rela.entsize == 0 {
+                return Err(ElfError::InvalidSection(rela.name.clone()));
+            }
+            bytes.slice(rela.offset, rela.size)?;
+            for i in 0..rela.size / rela.entsize {
+                let offset = entry_offset(rela.offset, i, rela.entsize)?;
+                relocations.push(ElfRelocation::parse(&bytes, self.header.class, offset)?);
+            }
+        }
+        Ok(relocations)
+    }
+
+    /// Returns the relocations applying to the section, at the addresses of the
+    /// relocated instructions and with their symbols resolved. Relocations against
+    /// sections (e.g. `.rodata`) are named after the section.
+    pub fn section_relocations(&self, section: &Section) -> Result<Vec<Relocation>, ElfError> {
+        let symbols = self.elf_symbols()?;
+        let base = if self.header.file_type == ET_REL {
+            section.addr
+        } else {
+            0
+        };
+        self.elf_relocations(section)?
+            .into_iter()
+            .map(|r| {
+                let symbol = match symbols.get(r.symbol as usize) {
+                    None => return Err(ElfError::InvalidSection(section.name.clone())),
+                    Some(_) if r.symbol == 0 => ABS_SYMBOL.to_string(),
+                    Some(s) if s.kind() == STT_SECTION => self
+                        .sections
+                        .get(usize::from(s.shndx))
+                        .map_or_else(String::new, |section| section.name.clone()),
+                    Some(s) => s.name.clone(),
+                };
+                Ok(Relocation::new(
+                    base.wrapping_add(r.offset),
+                    r.kind_name(),
+                    symbol,
+                    r.addend,
+                ))
+            })
+            .collect()
+    }
+
+    fn section_index(&self, section: &Section) -> Option<usize> {
+        self.sections.iter().position(|s| s == section)
+    }
+
+    /// Returns the disassembler configuration matching the file: the base ISA is
+    /// selected by the ELF class, and compressed instructions are decoded only if
+    /// the file is flagged with `EF_RISCV_RVC`.
+    pub fn disasm_config(&self) -> DisasmConfig {
+        DisasmConfig {
+            xlen: self.header.class.xlen(),
+            compressed: self.header.rvc(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a disassembler of the section, starting at the section's address.
+    pub fn disasm(&self, section: &Section) -> Result<Disasm, ElfError> {
+        let config = DisasmConfig {
+            start_addr: section.addr,
+            ..self.disasm_config()
+        };
+        self.disasm_with_config(section, config)
+    }
+
+    /// Creates a disassembler of the section with a custom configuration. Addresses
+    /// are labelled with the symbols of the section, instructions are annotated
+    /// with their relocations, and the entry of the file is an entry point of
+    /// [`Disasm::traverse`].
+    pub fn disasm_with_config(
+        &self,
+        section: &Section,
+        config: DisasmConfig,
+    ) -> Result<Disasm, ElfError> {
+        let data = self.section_data(section)?.to_vec();
+        let symbols = self.section_symbols(section)?;
+        let relocations = self.section_relocations(section)?;
+        Ok(Disasm::with_config(Cursor::new(data), config)
+            .with_symbols(symbols)
+            .with_relocations(relocations)
+            .with_entry_points([self.header.entry]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        disasm::InstructionFormatter,
+        elf::{
+            ElfClass, FloatAbi, ET_EXEC, R_RISCV_CALL_PLT, R_RISCV_HI20, R_RISCV_LO12_I,
+            R_RISCV_RELAX, SHF_ALLOC, SHF_EXECINSTR, SHN_UNDEF, SHT_NOBITS, SHT_NULL, SHT_PROGBITS,
+            SHT_STRTAB, SHT_SYMTAB, STB_GLOBAL, STT_FILE, STT_FUNC, STT_NOTYPE,
+        },
+        instr::InstructionTrait,
+        model::Xlen,
+    };
+
+    /// A section appended to the test files: name, type, link, info, entry size and data.
+    type TestSection<'a> = (&'a str, u32, u32, u32, u32, Vec<u8>);
+
+    /// Builds an ELF32 file with `.text`, `.bss` and `.shstrtab` sections.
+    fn elf32(machine: u16, flags: u32, text: &[u8]) -> Vec<u8> {
+        elf32_with_sections(machine, flags, text, &[])
+    }
+
+    /// Builds an ELF32 file like [`elf32`], followed by `.symtab` and `.strtab`
+    /// sections with the symbols (name, value, info, section index), and a
+    /// `.rela.text` section with the relocations (offset, info, addend).
+    fn elf32_with_symbols(
+        machine: u16,
+        flags: u32,
+        text: &[u8],
+        symbols: &[(&str, u32, u8, u16)],
+        relocations: &[(u32, u32, i32)],
+    ) -> Vec<u8> {
+        let mut strtab = b"\0".to_vec();
+        let mut symtab = vec![0u8; 16];
+        for (name, value, info, shndx) in symbols {
+            let name_offset = strtab.len() as u32;
+            strtab.extend(name.as_bytes());
+            strtab.push(0);
+            for word in [name_offset, *value, 0] {
+                symtab.extend(word.to_le_bytes());
+            }
+            symtab.extend([*info, 0]);
+            symtab.extend(shndx.to_le_bytes());
+        }
+        let rela: Vec<u8> = relocations
+            .iter()
+            .flat_map(|(offset, info, addend)| [*offset, *info, *addend as u32])
+            .flat_map(u32::to_le_bytes)
+            .collect();
+
+        let mut sections: Vec<TestSection> = vec![
+            (".symtab", SHT_SYMTAB, 5, 1, 16, symtab),
+            (".strtab", SHT_STRTAB, 0, 0, 0, strtab),
+        ];
+        if !relocations.is_empty() {
+            sections.push((".rela.text", SHT_RELA, 4, 1, 12, rela));
+        }
+        elf32_with_sections(machine, flags, text, &sections)
+    }
+
+    /// Builds an ELF32 file with `.text`, `.bss` and `.shstrtab` sections, followed
+    /// by the given sections.
+    fn elf32_with_sections(
+        machine: u16,
+        flags: u32,
+        text: &[u8],
+        sections: &[TestSection],
+    ) -> Vec<u8> {
+        let mut shstrtab = b"\0.text\0.bss\0.shstrtab\0".to_vec();
+        let text_offset = 52;
+        let shstrtab_offset = text_offset + text.len();
+        let mut names = Vec::new();
+        for (name, ..) in sections {
+            names.push(shstrtab.len() as u32);
+            shstrtab.extend(name.as_bytes());
+            shstrtab.push(0);
+        }
+        let mut offsets = Vec::new();
+        let mut shoff = shstrtab_offset + shstrtab.len();
+        for (.., data) in sections {
+            offsets.push(shoff as u32);
+            shoff += data.len();
+        }
+
+        let mut data = b"\x7fELF\x01\x01\x01".to_vec();
+        data.resize(16, 0);
+        for half in [ET_EXEC, machine] {
+            data.extend(half.to_le_bytes());
+        }
+        for word in [1, 0x10000, 0, shoff as u32, flags] {
+            data.extend(word.to_le_bytes());
+        }
+        for half in [52u16, 0, 0, 40, 4 + sections.len() as u16, 3] {
+            data.extend(half.to_le_bytes());
+        }
+        data.extend(text);
+        data.extend(&shstrtab);
+        for (.., section_data) in sections {
+            data.extend(section_data);
+        }
+
+        let mut headers: Vec<[u32; 10]> = vec![
+            [0; 10],
+            [
+                1,
+                SHT_PROGBITS,
+                (SHF_ALLOC | SHF_EXECINSTR) as u32,
+                0x10000,
+                text_offset as u32,
+                text.len() as u32,
+                0,
+                0,
+                4,
+                0,
+            ],
+            [
+                7,
+                SHT_NOBITS,
+                SHF_ALLOC as u32,
+                0x20000,
+                shoff as u32,
+                0x100,
+                0,
+                0,
+                4,
+                0,
+            ],
+            [
+                12,
+                SHT_STRTAB,
+                0,
+                0,
+                shstrtab_offset as u32,
+                shstrtab.len() as u32,
+                0,
+                0,
+                1,
+                0,
+            ],
+        ];
+        for (i, (_, kind, link, info, entsize, section_data)) in sections.iter().enumerate() {
+            headers.push([
+                names[i],
+                *kind,
+                0,
+                0,
+                offsets[i],
+                section_data.len() as u32,
+                *link,
+                *info,
+                4,
+                *entsize,
+            ]);
+        }
+        for word in headers.iter().flatten() {
+            data.extend(word.to_le_bytes());
+        }
+        data
+    }
+
+    /// Builds an ELF64 header followed by section headers, given as name, type,
+    /// offset, size, link and entsize.
+    fn elf64(sections: &[(u32, u32, u64, u64, u32, u64)], shstrndx: u16) -> Vec<u8> {
+        let mut data = b"\x7fELF\x02\x01\x01".to_vec();
+        data.resize(16, 0);
+        for half in [2u16, 243] {
+            data.extend(half.to_le_bytes());
+        }
+        data.extend(1u32.to_le_bytes());
+        for dword in [0x10000u64, 0, 64] {
+            data.extend(dword.to_le_bytes());
+        }
+        data.extend(0u32.to_le_bytes());
+        for half in [64u16, 0, 0, 64, sections.len() as u16, shstrndx] {
+            data.extend(half.to_le_bytes());
+        }
+
+        for (name, kind, offset, size, link, entsize) in sections {
+            data.extend(name.to_le_bytes());
+            data.extend(kind.to_le_bytes());
+            for dword in [0u64, 0, *offset, *size] {
+                data.extend(dword.to_le_bytes());
+            }
+            data.extend(link.to_le_bytes());
+            data.extend(0u32.to_le_bytes());
+            for dword in [1u64, *entsize] {
+                data.extend(dword.to_le_bytes());
+            }
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse() -> Result<(), ElfError> {
+        // addi a0, a0, 1; c.nop
+        let elf = ElfFile::parse(elf32(243, 0x5, &[0x13, 0x05, 0x15, 0x00, 0x01, 0x00]))?;
+        assert_eq!(ElfClass::Elf32, elf.header().class);
+        assert_eq!(0x10000, elf.header().entry);
+        assert!(elf.header().rvc());
+        assert_eq!(FloatAbi::Double, elf.header().float_abi());
+
+        let names: Vec<&str> = elf.sections().iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(vec!["", ".text", ".bss", ".shstrtab"], names);
+
+        let bss = elf.section_by_name(".bss").unwrap();
+        assert!(!bss.is_executable());
+        assert!(elf.section_data(bss)?.is_empty());
+
+        let text: Vec<&Section> = elf.executable_sections().collect();
+        assert_eq!(1, text.len());
+        assert_eq!(6, elf.section_data(text[0])?.len());
+
+        let config = elf.disasm_config();
+        assert_eq!(Xlen::Rv32, config.xlen);
+        assert!(config.compressed);
+
+        let records: Vec<_> = elf.disasm(text[0])?.map(|r| r.unwrap()).collect();
+        assert_eq!(2, records.len());
+        assert_eq!(0x10000, records[0].address());
+        assert_eq!(0x10004, records[1].address());
+        assert_eq!(Some("c_nop".into()), records[1].instruction().mnemonic());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_disabled() -> Result<(), ElfError> {
+        let elf = ElfFile::parse(elf32(243, 0, &[0x01, 0x00]))?;
+        assert!(!elf.disasm_config().compressed);
+        let text = elf.section_by_name(".text").unwrap();
+        assert!(matches!(elf.disasm(text)?.next(), Some(Err(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbols() -> Result<(), ElfError> {
+        // jal ra, 8; nop; ret
+        let text = [
+            0xef, 0x00, 0x80, 0x00, 0x13, 0x00, 0x00, 0x00, 0x67, 0x80, 0x00, 0x00,
+        ];
+        let symbols = [
+            ("_start", 0x10000, (STB_GLOBAL << 4) | STT_FUNC, 1),
+            ("$x", 0x10000, STT_NOTYPE, 1),
+            ("foo", 0x10008, STT_FUNC, 1),
+            ("printf", 0, (STB_GLOBAL << 4) | STT_FUNC, SHN_UNDEF),
+            ("main.c", 0, STT_FILE, 0xfff1),
+        ];
+        let elf = ElfFile::parse(elf32_with_symbols(243, 0, &text, &symbols, &[]))?;
+
+        let elf_symbols = elf.elf_symbols()?;
+        assert_eq!(6, elf_symbols.len());
+        assert_eq!("_start", elf_symbols[1].name);
+        assert_eq!(STB_GLOBAL, elf_symbols[1].binding());
+        assert_eq!(STT_FUNC, elf_symbols[1].kind());
+        assert!(!elf_symbols[4].is_defined());
+
+        let symbols = elf.symbols()?;
+        let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(vec!["_start", "foo"], names);
+
+        let text = elf.section_by_name(".text").unwrap();
+        let config = DisasmConfig {
+            start_addr: text.addr,
+            show_addr: false,
+            ..elf.disasm_config()
+        };
+        let formatter =
+            InstructionFormatter::new(config.clone()).with_symbols(elf.section_symbols(text)?);
+        let records: Vec<_> = elf
+            .disasm_with_config(text, config)?
+            .map(|r| r.unwrap())
+            .collect();
+        assert_eq!("jal 0x10008 <foo>", formatter.record(&records[0]));
+        assert_eq!(
+            Some("00010008 <foo>:".to_string()),
+            formatter.symbol_header(records[2].address())
+        );
+        assert_eq!(None, formatter.symbol_header(records[1].address()));
+
+        assert!(ElfFile::parse(elf32(243, 0, &[0x01, 0x00]))?
+            .symbols()?
+            .is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_relocations() -> Result<(), ElfError> {
+        // auipc ra, 0x0; jalr ra, 0x0(ra); lui a0, 0x0; addi a0, a0, 0x0
+        let text: Vec<u8> = [0x00000097u32, 0x000080e7, 0x00000537, 0x00050513]
+            .iter()
+            .flat_map(|w| w.to_le_bytes())
+            .collect();
+        let symbols = [
+            ("printf", 0, (STB_GLOBAL << 4) | STT_FUNC, SHN_UNDEF),
+            ("", 0, STT_SECTION, 2),
+        ];
+        let info = |symbol: u32, kind: u32| (symbol << 8) | kind;
+        let relocations = [
+            (0, info(1, R_RISCV_CALL_PLT), 0),
+            (0, info(0, R_RISCV_RELAX), 0),
+            (8, info(2, R_RISCV_HI20), 0x10),
+            (12, info(2, R_RISCV_LO12_I), -4),
+            (12, 99, 0),
+        ];
+        let mut data = elf32_with_symbols(243, 0, &text, &symbols, &relocations);
+        data[16..18].copy_from_slice(&ET_REL.to_le_bytes());
+        let elf = ElfFile::parse(data)?;
+
+        let text = elf.section_by_name(".text").unwrap();
+        let elf_relocations = elf.elf_relocations(text)?;
+        assert_eq!(5, elf_relocations.len());
+        assert_eq!(R_RISCV_CALL_PLT, elf_relocations[0].kind);
+        assert_eq!(1, elf_relocations[0].symbol);
+        assert_eq!(-4, elf_relocations[3].addend);
+        assert_eq!("R_RISCV_99", elf_relocations[4].kind_name());
+
+        let relocations = elf.section_relocations(text)?;
+        let call = Relocation::new(0x10000, "R_RISCV_CALL_PLT", "printf", 0);
+        assert_eq!(call, relocations[0]);
+        assert_eq!(ABS_SYMBOL, relocations[1].symbol);
+        assert_eq!("10008: R_RISCV_HI20\t.bss+0x10", relocations[2].to_string());
+        assert_eq!(
+            "1000c: R_RISCV_LO12_I\t.bss-0x4",
+            relocations[3].to_string()
+        );
+
+        let mut disasm = elf.disasm(text)?.peephole();
+        let auipc = disasm.next().unwrap().unwrap();
+        assert!(auipc.fusion().is_none());
+        let formatter = InstructionFormatter::new(elf.disasm_config())
+            .with_relocations(elf.section_relocations(text)?);
+        assert_eq!(2, formatter.relocations(&auipc).count());
+        assert_eq!(
+            "0x00010000:   auipc ra, 0x10000\n\t\t\t10000: R_RISCV_CALL_PLT\tprintf\n\t\t\t10000: R_RISCV_RELAX\t*ABS*",
+            formatter.record(&auipc)
+        );
+        let next = disasm.next().unwrap().unwrap();
+        assert_eq!(0, formatter.relocations(&next).count());
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(matches!(
+            ElfFile::parse(b"\x7fELX".to_vec()),
+            Err(ElfError::InvalidMagic)
+        ));
+        assert!(matches!(
+            ElfFile::parse(elf32(62, 0, &[])),
+            Err(ElfError::UnsupportedMachine(62))
+        ));
+        let mut truncated = elf32(243, 0, &[]);
+        truncated.truncate(100);
+        assert!(matches!(
+            ElfFile::parse(truncated),
+            Err(ElfError::Truncated(_))
+        ));
+    }
+
+    #[test]
+    fn test_offset_overflow() {
+        // the names are read at sh_offset of .shstrtab plus their offsets (which is
+        // nonzero for the first section, so adding it overflows)
+        let sections = [
+            (1, SHT_NULL, 0, 0, 0, 0),
+            (0, SHT_STRTAB, u64::MAX, 0, 0, 0),
+        ];
+        assert!(matches!(
+            ElfFile::parse(elf64(&sections, 1)),
+            Err(ElfError::Truncated(_))
+        ));
+
+        // symbol names are read at sh_offset of .strtab plus their offsets
+        let sections = [
+            (0, SHT_NULL, 0, 0, 0, 0),
+            (0, SHT_SYMTAB, 256, 24, 2, 24),
+            (0, SHT_STRTAB, u64::MAX, 0, 0, 0),
+        ];
+        let mut data = elf64(&sections, 0);
+        data.extend(1u32.to_le_bytes());
+        data.resize(data.len() + 20, 0);
+        assert!(matches!(
+            ElfFile::parse(data).unwrap().elf_symbols(),
+            Err(ElfError::Truncated(_))
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+use super::{bytes::ElfBytes, ElfError};
+use crate::model::Xlen;
+
+pub const EM_RISCV: u16 = 243;
+
+pub const ET_REL: u16 = 1;
+pub const ET_EXEC: u16 = 2;
+pub const ET_DYN: u16 = 3;
+
+pub const EV_CURRENT: u8 = 1;
+
+pub const EF_RISCV_RVC: u32 = 0x1;
+pub const EF_RISCV_FLOAT_ABI: u32 = 0x6;
+pub const EF_RISCV_RVE: u32 = 0x8;
+pub const EF_RISCV_TSO: u32 = 0x10;
+
+/// Width of the ELF file's addresses and offsets (the `EI_CLASS` identification byte).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ElfClass {
+    Elf32,
+    Elf64,
+}
+
+impl ElfClass {
+    /// Returns the base ISA matching the class.
+    pub fn xlen(&self) -> Xlen {
+        match self {
+            ElfClass::Elf32 => Xlen::Rv32,
+            ElfClass::Elf64 => Xlen::Rv64,
+        }
+    }
+
+    /// Returns the size of addresses, offsets and sizes in bytes.
+    pub fn addr_size(&self) -> u64 {
+        match self {
+            ElfClass::Elf32 => 4,
+            ElfClass::Elf64 => 8,
+        }
+    }
+}
+
+impl TryFrom<u8> for ElfClass {
+    type Error = ElfError;
+
+    fn try_from(class: u8) -> Result<Self, Self::Error> {
+        match class {
+            1 => Ok(ElfClass::Elf32),
+            2 => Ok(ElfClass::Elf64),
+            _ => Err(ElfError::UnsupportedClass(class)),
+        }
+    }
+}
+
+/// Floating-point calling convention, encoded in `e_flags`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FloatAbi {
+    Soft,
+    Single,
+    Double,
+    Quad,
+}
+
+impl FloatAbi {
+    /// Returns the `e_flags` bits of the calling convention.
+    pub fn flags(&self) -> u32 {
+        let abi = match self {
+            FloatAbi::Soft => 0,
+            FloatAbi::Single => 1,
+            FloatAbi::Double => 2,
+            FloatAbi::Quad => 3,
+        };
+        abi << 1
+    }
+}
+
+impl From<u32> for FloatAbi {
+    fn from(e_flags: u32) -> Self {
+        match (e_flags & EF_RISCV_FLOAT_ABI) >> 1 {
+            0 => FloatAbi::Soft,
+            1 => FloatAbi::Single,
+            2 => FloatAbi::Double,
+            _ => FloatAbi::Quad,
+        }
+    }
+}
+
+/// The ELF file header, with the fields needed to locate sections and configure decoding.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ElfHeader {
+    pub class: ElfClass,
+    /// Object file type (`e_type`), e.g. [`ET_REL`] for relocatable and [`ET_EXEC`] for executable files
+    pub file_type: u16,
+    pub machine: u16,
+    pub entry: u64,
+    pub flags: u32,
+    pub shoff: u64,
+    pub shentsize: u16,
+    pub shnum: u16,
+    pub shstrndx: u16,
+}
+
+impl ElfHeader {
+    pub(crate) fn parse(data: &[u8]) -> Result<Self, ElfError> {
+        if data.len() < 16 || data[0..4] != *b"\x7fELF" {
+            return Err(ElfError::InvalidMagic);
+        }
+        let class = ElfClass::try_from(data[4])?;
+        if data[5] != 1 {
+            return Err(ElfError::UnsupportedEncoding);
+        }
+
+        let bytes = ElfBytes::new(data, class);
+        let machine = bytes.u16(18)?;
+        if machine != EM_RISCV {
+            return Err(ElfError::UnsupportedMachine(machine));
+        }
+
+        // offsets of the fields following e_entry depend on the address width
+        let (entry, shoff, flags, rest) = match class {
+            ElfClass::Elf32 => (24, 32, 36, 46),
+            ElfClass::Elf64 => (24, 40, 48, 58),
+        };
+        Ok(Self {
+            class,
+            file_type: bytes.u16(16)?,
+            machine,
+            entry: bytes.addr(entry)?,
+            flags: bytes.u32(flags)?,
+            shoff: bytes.addr(shoff)?,
+            shentsize: bytes.u16(rest)?,
+            shnum: bytes.u16(rest + 2)?,
+            shstrndx: bytes.u16(rest + 4)?,
+        })
+    }
+
+    /// Checks whether the code may contain compressed instructions (`EF_RISCV_RVC`).
+    pub fn rvc(&self) -> bool {
+        self.flags & EF_RISCV_RVC != 0
+    }
+
+    pub fn float_abi(&self) -> FloatAbi {
+        FloatAbi::from(self.flags)
+    }
+
+    /// Checks whether the code targets the RV32E/RV64E base ISA (16 registers).
+    pub fn rve(&self) -> bool {
+        self.flags & EF_RISCV_RVE != 0
+    }
+}
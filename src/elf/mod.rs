@@ -0,0 +1,16 @@
+mod bytes;
+mod elf_error;
+mod elf_file;
+mod header;
+mod relocation;
+mod section;
+mod symbol;
+mod writer;
+
+pub use elf_error::*;
+pub use elf_file::*;
+pub use header::*;
+pub use relocation::*;
+pub use section::*;
+pub use symbol::*;
+pub use writer::*;
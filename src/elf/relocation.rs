@@ -0,0 +1,138 @@
+use super::{
+    bytes::{add_offset, ElfBytes},
+    ElfClass, ElfError,
+};
+
+pub const R_RISCV_NONE: u32 = 0;
+pub const R_RISCV_32: u32 = 1;
+pub const R_RISCV_64: u32 = 2;
+pub const R_RISCV_BRANCH: u32 = 16;
+pub const R_RISCV_JAL: u32 = 17;
+pub const R_RISCV_CALL: u32 = 18;
+pub const R_RISCV_CALL_PLT: u32 = 19;
+pub const R_RISCV_GOT_HI20: u32 = 20;
+pub const R_RISCV_PCREL_HI20: u32 = 23;
+pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+pub const R_RISCV_HI20: u32 = 26;
+pub const R_RISCV_LO12_I: u32 = 27;
+pub const R_RISCV_LO12_S: u32 = 28;
+pub const R_RISCV_ALIGN: u32 = 43;
+pub const R_RISCV_RVC_BRANCH: u32 = 44;
+pub const R_RISCV_RVC_JUMP: u32 = 45;
+pub const R_RISCV_RELAX: u32 = 51;
+
+/// Names of the relocation types defined by the RISC-V ELF psABI, by their numbers.
+const RELOCATION_TYPES: [(u32, &str); 55] = [
+    (0, "R_RISCV_NONE"),
+    (1, "R_RISCV_32"),
+    (2, "R_RISCV_64"),
+    (3, "R_RISCV_RELATIVE"),
+    (4, "R_RISCV_COPY"),
+    (5, "R_RISCV_JUMP_SLOT"),
+    (6, "R_RISCV_TLS_DTPMOD32"),
+    (7, "R_RISCV_TLS_DTPMOD64"),
+    (8, "R_RISCV_TLS_DTPREL32"),
+    (9, "R_RISCV_TLS_DTPREL64"),
+    (10, "R_RISCV_TLS_TPREL32"),
+    (11, "R_RISCV_TLS_TPREL64"),
+    (12, "R_RISCV_TLSDESC"),
+    (16, "R_RISCV_BRANCH"),
+    (17, "R_RISCV_JAL"),
+    (18, "R_RISCV_CALL"),
+    (19, "R_RISCV_CALL_PLT"),
+    (20, "R_RISCV_GOT_HI20"),
+    (21, "R_RISCV_TLS_GOT_HI20"),
+    (22, "R_RISCV_TLS_GD_HI20"),
+    (23, "R_RISCV_PCREL_HI20"),
+    (24, "R_RISCV_PCREL_LO12_I"),
+    (25, "R_RISCV_PCREL_LO12_S"),
+    (26, "R_RISCV_HI20"),
+    (27, "R_RISCV_LO12_I"),
+    (28, "R_RISCV_LO12_S"),
+    (29, "R_RISCV_TPREL_HI20"),
+    (30, "R_RISCV_TPREL_LO12_I"),
+    (31, "R_RISCV_TPREL_LO12_S"),
+    (32, "R_RISCV_TPREL_ADD"),
+    (33, "R_RISCV_ADD8"),
+    (34, "R_RISCV_ADD16"),
+    (35, "R_RISCV_ADD32"),
+    (36, "R_RISCV_ADD64"),
+    (37, "R_RISCV_SUB8"),
+    (38, "R_RISCV_SUB16"),
+    (39, "R_RISCV_SUB32"),
+    (40, "R_RISCV_SUB64"),
+    (41, "R_RISCV_GOT32_PCREL"),
+    (43, "R_RISCV_ALIGN"),
+    (44, "R_RISCV_RVC_BRANCH"),
+    (45, "R_RISCV_RVC_JUMP"),
+    (51, "R_RISCV_RELAX"),
+    (52, "R_RISCV_SUB6"),
+    (53, "R_RISCV_SET6"),
+    (54, "R_RISCV_SET8"),
+    (55, "R_RISCV_SET16"),
+    (56, "R_RISCV_SET32"),
+    (57, "R_RISCV_32_PCREL"),
+    (58, "R_RISCV_IRELATIVE"),
+    (59, "R_RISCV_PLT32"),
+    (60, "R_RISCV_SET_ULEB128"),
+    (61, "R_RISCV_SUB_ULEB128"),
+    (62, "R_RISCV_TLSDESC_HI20"),
+    (63, "R_RISCV_TLSDESC_LOAD_LO12"),
+];
+
+/// An entry of a relocation section (`.rela.text`), applying to the section
+/// given by the `sh_info` field of the relocation section.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ElfRelocation {
+    /// Offset of the relocated bytes from the section start in relocatable files,
+    /// or their address in other files
+    pub offset: u64,
+    /// Relocation type, e.g. [`R_RISCV_CALL_PLT`]
+    pub kind: u32,
+    /// Index of the symbol in the linked symbol table, 0 if there's none
+    pub symbol: u32,
+    pub addend: i64,
+}
+
+impl ElfRelocation {
+    /// Parses the relocation entry (with an addend) at the given offset.
+    pub(crate) fn parse(bytes: &ElfBytes, class: ElfClass, offset: u64) -> Result<Self, ElfError> {
+        // (r_info, r_addend)
+        let fields: [u64; 2] = match class {
+            ElfClass::Elf32 => [4, 8],
+            ElfClass::Elf64 => [8, 16],
+        };
+        let [info, addend] = fields.map(|field| add_offset(offset, field));
+        let (info, addend) = (bytes.addr(info?)?, bytes.addr(addend?)?);
+        let (symbol, kind, addend) = match class {
+            ElfClass::Elf32 => (info >> 8, info & 0xff, i64::from(addend as u32 as i32)),
+            ElfClass::Elf64 => (info >> 32, info & 0xffff_ffff, addend as i64),
+        };
+        Ok(Self {
+            offset: bytes.addr(offset)?,
+            kind: kind as u32,
+            symbol: symbol as u32,
+            addend,
+        })
+    }
+
+    /// Returns the relocation type of the name, e.g. [`R_RISCV_HI20`] for `R_RISCV_HI20`.
+    pub fn kind_from_name(name: &str) -> Option<u32> {
+        RELOCATION_TYPES
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(kind, _)| *kind)
+    }
+
+    /// Returns the name of the relocation type, e.g. `R_RISCV_HI20`.
+    pub fn kind_name(&self) -> String {
+        RELOCATION_TYPES
+            .iter()
+            .find(|(kind, _)| *kind == self.kind)
+            .map_or_else(
+                || format!("R_RISCV_{}", self.kind),
+                |(_, name)| name.to_string(),
+            )
+    }
+}
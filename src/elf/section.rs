@@ -0,0 +1,70 @@
+use super::{
+    bytes::{add_offset, ElfBytes},
+    ElfClass, ElfError,
+};
+
+pub const SHT_NULL: u32 = 0;
+pub const SHT_PROGBITS: u32 = 1;
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_STRTAB: u32 = 3;
+pub const SHT_RELA: u32 = 4;
+pub const SHT_NOBITS: u32 = 8;
+
+pub const SHF_WRITE: u64 = 0x1;
+pub const SHF_ALLOC: u64 = 0x2;
+pub const SHF_EXECINSTR: u64 = 0x4;
+pub const SHF_INFO_LINK: u64 = 0x40;
+
+/// A section header, with the section's name resolved from the section name string table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Section {
+    pub name: String,
+    /// Section type (`sh_type`), e.g. [`SHT_PROGBITS`]
+    pub kind: u32,
+    pub flags: u64,
+    /// Virtual address of the section in memory (0 in relocatable files)
+    pub addr: u64,
+    pub offset: u64,
+    pub size: u64,
+    pub link: u32,
+    pub info: u32,
+    pub addralign: u64,
+    pub entsize: u64,
+}
+
+impl Section {
+    /// Parses the section header at the given offset. The name is left empty,
+    /// as it's resolved once the string table is known.
+    pub(crate) fn parse(bytes: &ElfBytes, class: ElfClass, offset: u64) -> Result<Self, ElfError> {
+        // (sh_flags, sh_addr, sh_offset, sh_size, sh_link, sh_info, sh_addralign, sh_entsize)
+        let fields: [u64; 8] = match class {
+            ElfClass::Elf32 => [8, 12, 16, 20, 24, 28, 32, 36],
+            ElfClass::Elf64 => [8, 16, 24, 32, 40, 44, 48, 56],
+        };
+        let [flags, addr, sh_offset, size, link, info, addralign, entsize] =
+            fields.map(|field| add_offset(offset, field));
+
+        Ok(Self {
+            name: String::new(),
+            kind: bytes.u32(add_offset(offset, 4)?)?,
+            flags: bytes.addr(flags?)?,
+            addr: bytes.addr(addr?)?,
+            offset: bytes.addr(sh_offset?)?,
+            size: bytes.addr(size?)?,
+            link: bytes.u32(link?)?,
+            info: bytes.u32(info?)?,
+            addralign: bytes.addr(addralign?)?,
+            entsize: bytes.addr(entsize?)?,
+        })
+    }
+
+    /// Checks whether the section holds executable code.
+    pub fn is_executable(&self) -> bool {
+        self.kind == SHT_PROGBITS && self.flags & SHF_EXECINSTR != 0
+    }
+
+    /// Checks whether the section occupies space in the file (unlike `.bss`).
+    pub fn has_data(&self) -> bool {
+        self.kind != SHT_NOBITS && self.kind != SHT_NULL
+    }
+}
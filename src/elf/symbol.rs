@@ -0,0 +1,90 @@
+use super::{
+    bytes::{add_offset, ElfBytes},
+    ElfClass, ElfError,
+};
+use crate::disasm::SymbolKind;
+
+pub const STT_NOTYPE: u8 = 0;
+pub const STT_OBJECT: u8 = 1;
+pub const STT_FUNC: u8 = 2;
+pub const STT_SECTION: u8 = 3;
+pub const STT_FILE: u8 = 4;
+
+pub const STB_LOCAL: u8 = 0;
+pub const STB_GLOBAL: u8 = 1;
+pub const STB_WEAK: u8 = 2;
+
+/// Section index of undefined symbols.
+pub const SHN_UNDEF: u16 = 0;
+/// Section index of symbols with absolute values, not affected by relocation.
+pub const SHN_ABS: u16 = 0xfff1;
+
+/// An entry of the symbol table (`.symtab`), with its name resolved from the linked string table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ElfSymbol {
+    pub name: String,
+    pub value: u64,
+    pub size: u64,
+    /// Binding (high nibble) and type (low nibble) of the symbol
+    pub info: u8,
+    /// Index of the section the symbol is defined in, [`SHN_UNDEF`] if it's undefined
+    pub shndx: u16,
+}
+
+impl ElfSymbol {
+    /// Parses the symbol table entry at the given offset, returning it with the offset
+    /// of its name in the string table. The name is left empty.
+    pub(crate) fn parse(
+        bytes: &ElfBytes,
+        class: ElfClass,
+        offset: u64,
+    ) -> Result<(u32, Self), ElfError> {
+        // (st_value, st_size, st_info, st_shndx)
+        let fields: [u64; 4] = match class {
+            ElfClass::Elf32 => [4, 8, 12, 14],
+            ElfClass::Elf64 => [8, 16, 4, 6],
+        };
+        let [value, size, info, shndx] = fields.map(|field| add_offset(offset, field));
+
+        let symbol = Self {
+            name: String::new(),
+            value: bytes.addr(value?)?,
+            size: bytes.addr(size?)?,
+            info: bytes.slice(info?, 1)?[0],
+            shndx: bytes.u16(shndx?)?,
+        };
+        Ok((bytes.u32(offset)?, symbol))
+    }
+
+    /// Returns the symbol type, e.g. [`STT_FUNC`].
+    pub fn kind(&self) -> u8 {
+        self.info & 0xf
+    }
+
+    /// Returns what the symbol labels: code for functions, data for objects.
+    pub fn symbol_kind(&self) -> SymbolKind {
+        match self.kind() {
+            STT_FUNC => SymbolKind::Code,
+            STT_OBJECT => SymbolKind::Data,
+            _ => SymbolKind::Unknown,
+        }
+    }
+
+    /// Returns the symbol binding, e.g. [`STB_GLOBAL`].
+    pub fn binding(&self) -> u8 {
+        self.info >> 4
+    }
+
+    pub fn is_defined(&self) -> bool {
+        self.shndx != SHN_UNDEF
+    }
+
+    /// Checks whether the symbol names a location in the code or data, excluding
+    /// section and file symbols, and the `$x`/`$d` mapping symbols.
+    pub fn is_label(&self) -> bool {
+        self.is_defined()
+            && matches!(self.kind(), STT_NOTYPE | STT_OBJECT | STT_FUNC)
+            && !self.name.is_empty()
+            && !self.name.starts_with('$')
+    }
+}
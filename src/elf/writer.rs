@@ -0,0 +1,564 @@
+use std::io::{self, Write};
+
+use super::{
+    bytes::ElfBuffer, ElfClass, ElfRelocation, FloatAbi, Section, EF_RISCV_RVC, EM_RISCV, ET_EXEC,
+    ET_REL, EV_CURRENT, R_RISCV_NONE, SHF_ALLOC, SHF_EXECINSTR, SHF_INFO_LINK, SHF_WRITE, SHN_ABS,
+    SHT_NULL, SHT_PROGBITS, SHT_RELA, SHT_STRTAB, SHT_SYMTAB, STB_GLOBAL, STB_LOCAL, STT_NOTYPE,
+    STT_SECTION,
+};
+use crate::{
+    asm::{Program, SectionKind},
+    disasm::Address,
+    model::Xlen,
+};
+
+pub const PT_LOAD: u32 = 1;
+
+pub const PF_X: u32 = 0x1;
+pub const PF_W: u32 = 0x2;
+pub const PF_R: u32 = 0x4;
+
+/// Alignment of the loadable segments of executable files
+const PAGE_SIZE: u64 = 0x1000;
+
+/// A symbol table entry to be written, with the index of its name in the string table.
+struct SymbolEntry {
+    name: u32,
+    value: u64,
+    info: u8,
+    shndx: u16,
+}
+
+/// A string table (`.strtab` or `.shstrtab`) under construction.
+struct StringTable(Vec<u8>);
+
+impl StringTable {
+    fn new() -> Self {
+        Self(vec![0])
+    }
+
+    /// Appends the string, returning its offset in the table.
+    fn add(&mut self, name: &str) -> u32 {
+        let offset = self.0.len() as u32;
+        self.0.extend_from_slice(name.as_bytes());
+        self.0.push(0);
+        offset
+    }
+}
+
+/// A writer of assembled [`Program`]s as RISC-V ELF files: executables (`ET_EXEC`),
+/// with a loadable segment per section, or relocatable objects (`ET_REL`) for a linker,
+/// with sections starting at 0 and labels placed with `.word` relocated (`R_RISCV_32`).
+///
+/// The file holds the `.text` and `.data` sections (the latter only if it isn't empty),
+/// and a symbol table with the labels and constants of the program, global if declared
+/// with `.globl`. The class of the file (ELF32 or ELF64) follows the program's base ISA.
+///
+/// # Example
+///
+/// ```
+/// # use riscv_isa::{Assembler, ElfFile, ElfWriter, FloatAbi, Xlen};
+/// let program = Assembler::new(Xlen::Rv64)
+///     .with_text_address(0x10000)
+///     .assemble(".globl _start\n_start: li a0, 0\nli a7, 93\necall")
+///     .unwrap();
+/// let elf = ElfWriter::executable()
+///     .with_float_abi(FloatAbi::Double)
+///     .write(&program);
+///
+/// let elf = ElfFile::parse(elf).unwrap();
+/// assert_eq!(0x10000, elf.header().entry);
+/// assert_eq!(FloatAbi::Double, elf.header().float_abi());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElfWriter {
+    file_type: u16,
+    flags: u32,
+}
+
+impl ElfWriter {
+    pub fn executable() -> Self {
+        Self {
+            file_type: ET_EXEC,
+            flags: 0,
+        }
+    }
+
+    pub fn relocatable() -> Self {
+        Self {
+            file_type: ET_REL,
+            flags: 0,
+        }
+    }
+
+    /// Flags the code as possibly containing compressed instructions (`EF_RISCV_RVC`).
+    pub fn with_rvc(self, rvc: bool) -> Self {
+        let flags = match rvc {
+            true => self.flags | EF_RISCV_RVC,
+            false => self.flags & !EF_RISCV_RVC,
+        };
+        Self { flags, ..self }
+    }
+
+    /// Sets the floating-point calling convention (soft-float by default).
+    pub fn with_float_abi(self, abi: FloatAbi) -> Self {
+        let flags = (self.flags & !FloatAbi::Quad.flags()) | abi.flags();
+        Self { flags, ..self }
+    }
+
+    /// Writes the program as an ELF file.
+    pub fn write_to(&self, program: &Program, mut writer: impl Write) -> io::Result<()> {
+        writer.write_all(&self.write(program))
+    }
+
+    /// Returns the program as an ELF file.
+    pub fn write(&self, program: &Program) -> Vec<u8> {
+        let class = match program.xlen() {
+            Xlen::Rv32 => ElfClass::Elf32,
+            Xlen::Rv64 => ElfClass::Elf64,
+        };
+        let relocatable = self.file_type == ET_REL;
+        // subtracted from the addresses of the program, as sections of relocatable
+        // files start at 0
+        let base = |kind| match relocatable {
+            true => program.section(kind).1,
+            false => 0,
+        };
+
+        let mut sections = vec![(section(SHT_NULL, 0, 0), Vec::new())];
+        let mut shstrtab = StringTable::new();
+        let mut names = vec![0];
+        let kinds: Vec<SectionKind> = [SectionKind::Text, SectionKind::Data]
+            .into_iter()
+            .filter(|kind| *kind == SectionKind::Text || !program.section(*kind).0.is_empty())
+            .collect();
+        for kind in &kinds {
+            let (bytes, address) = program.section(*kind);
+            let flags = match kind {
+                SectionKind::Text => SHF_ALLOC | SHF_EXECINSTR,
+                SectionKind::Data => SHF_ALLOC | SHF_WRITE,
+            };
+            let mut header = section(SHT_PROGBITS, flags, program.alignment(*kind));
+            header.addr = address - base(*kind);
+            names.push(shstrtab.add(kind.name()));
+            sections.push((header, bytes.to_vec()));
+        }
+        let section_index = |kind| 1 + kinds.iter().position(|k| *k == kind).unwrap_or(0) as u16;
+
+        // symbols of the sections, followed by the local and the global symbols
+        let mut strtab = StringTable::new();
+        let mut symbols: Vec<SymbolEntry> = vec![SymbolEntry {
+            name: 0,
+            value: 0,
+            info: 0,
+            shndx: 0,
+        }];
+        for kind in &kinds {
+            symbols.push(SymbolEntry {
+                name: 0,
+                value: sections[usize::from(section_index(*kind))].0.addr,
+                info: (STB_LOCAL << 4) | STT_SECTION,
+                shndx: section_index(*kind),
+            });
+        }
+        let mut defined = program.symbols().to_vec();
+        defined.sort_by_key(|s| s.global);
+        let first_global = symbols.len() + defined.iter().filter(|s| !s.global).count();
+        for symbol in &defined {
+            let (value, shndx) = match symbol.section {
+                Some(kind) => (symbol.value as u64 - base(kind), section_index(kind)),
+                None => (symbol.value as u64, SHN_ABS),
+            };
+            let binding = if symbol.global { STB_GLOBAL } else { STB_LOCAL };
+            symbols.push(SymbolEntry {
+                name: strtab.add(&symbol.name),
+                value,
+                info: (binding << 4) | STT_NOTYPE,
+                shndx,
+            });
+        }
+        let symbol_index = |name: &str| {
+            let offset = kinds.len() + 1;
+            defined
+                .iter()
+                .position(|s| s.name == name)
+                .map(|i| i + offset)
+        };
+
+        // relocations are resolved by the assembler, unless the file is to be linked
+        let relocated: Vec<SectionKind> = kinds
+            .iter()
+            .copied()
+            .filter(|kind| {
+                relocatable
+                    && program
+                        .relocations()
+                        .iter()
+                        .any(|r| program.section_at(r.address) == Some(*kind))
+            })
+            .collect();
+        let symtab_index = sections.len() + relocated.len();
+        for kind in relocated {
+            let index = usize::from(section_index(kind));
+            let mut rela = ElfBuffer::new(class);
+            for relocation in program.relocations() {
+                if program.section_at(relocation.address) != Some(kind) {
+                    continue;
+                }
+                let offset = relocation.address - base(kind);
+                let symbol = symbol_index(&relocation.symbol).unwrap_or(0) as u64;
+                let r_type =
+                    ElfRelocation::kind_from_name(&relocation.kind).unwrap_or(R_RISCV_NONE) as u64;
+                let info = match class {
+                    ElfClass::Elf32 => (symbol << 8) | r_type,
+                    ElfClass::Elf64 => (symbol << 32) | r_type,
+                };
+                rela.addr(offset).addr(info).addr(relocation.addend as u64);
+                // the relocated bytes are left for the linker to fill
+                sections[index].1[offset as usize..offset as usize + 4].fill(0);
+            }
+            let mut header = section(SHT_RELA, SHF_INFO_LINK, class.addr_size());
+            header.link = symtab_index as u32;
+            header.info = u32::from(section_index(kind));
+            header.entsize = match class {
+                ElfClass::Elf32 => 12,
+                ElfClass::Elf64 => 24,
+            };
+            names.push(shstrtab.add(&format!(".rela{}", kind.name())));
+            sections.push((header, rela.into_vec()));
+        }
+
+        let mut symtab = ElfBuffer::new(class);
+        for symbol in &symbols {
+            match class {
+                ElfClass::Elf32 => symtab
+                    .u32(symbol.name)
+                    .u32(symbol.value as u32)
+                    .u32(0)
+                    .u8(symbol.info)
+                    .u8(0)
+                    .u16(symbol.shndx),
+                ElfClass::Elf64 => symtab
+                    .u32(symbol.name)
+                    .u8(symbol.info)
+                    .u8(0)
+                    .u16(symbol.shndx)
+                    .u64(symbol.value)
+                    .u64(0),
+            };
+        }
+        let mut header = section(SHT_SYMTAB, 0, class.addr_size());
+        header.link = symtab_index as u32 + 1;
+        header.info = first_global as u32;
+        header.entsize = match class {
+            ElfClass::Elf32 => 16,
+            ElfClass::Elf64 => 24,
+        };
+        names.push(shstrtab.add(".symtab"));
+        sections.push((header, symtab.into_vec()));
+
+        names.push(shstrtab.add(".strtab"));
+        sections.push((section(SHT_STRTAB, 0, 1), strtab.0));
+        names.push(shstrtab.add(".shstrtab"));
+        sections.push((section(SHT_STRTAB, 0, 1), shstrtab.0));
+
+        self.layout(program, class, sections, &names)
+    }
+
+    /// Places the headers and the sections in the file, returning its content.
+    fn layout(
+        &self,
+        program: &Program,
+        class: ElfClass,
+        mut sections: Vec<(Section, Vec<u8>)>,
+        names: &[u32],
+    ) -> Vec<u8> {
+        // (e_ehsize, e_phentsize, e_shentsize)
+        let (ehsize, phentsize, shentsize): (u16, u16, u16) = match class {
+            ElfClass::Elf32 => (52, 32, 40),
+            ElfClass::Elf64 => (64, 56, 64),
+        };
+        let executable = self.file_type == ET_EXEC;
+        // sections loaded by each segment: sections sharing a page (like `.data` placed
+        // right after `.text`) are loaded together, as a page can't have two mappings
+        let mut segments: Vec<Vec<usize>> = Vec::new();
+        for (i, (header, bytes)) in sections.iter().enumerate() {
+            if !executable || header.flags & SHF_ALLOC == 0 || bytes.is_empty() {
+                continue;
+            }
+            let previous = segments.last().and_then(|segment| segment.last());
+            match previous.map(|p| sections[*p].0.addr + sections[*p].1.len() as u64) {
+                Some(end)
+                    if header.addr >= end && header.addr < end.next_multiple_of(PAGE_SIZE) =>
+                {
+                    segments.last_mut().unwrap().push(i)
+                }
+                _ => segments.push(vec![i]),
+            }
+        }
+
+        let mut offset = u64::from(ehsize) + segments.len() as u64 * u64::from(phentsize);
+        for (header, bytes) in sections.iter_mut().skip(1) {
+            offset = if executable && header.flags & SHF_ALLOC != 0 {
+                // segments are mapped by pages, so their offsets and addresses must agree
+                offset + (header.addr % PAGE_SIZE + PAGE_SIZE - offset % PAGE_SIZE) % PAGE_SIZE
+            } else {
+                offset.next_multiple_of(header.addralign.max(1))
+            };
+            header.offset = offset;
+            header.size = bytes.len() as u64;
+            offset += header.size;
+        }
+        let shoff = offset.next_multiple_of(class.addr_size());
+
+        let mut out = ElfBuffer::new(class);
+        let ei_class = match class {
+            ElfClass::Elf32 => 1,
+            ElfClass::Elf64 => 2,
+        };
+        out.bytes(b"\x7fELF")
+            .u8(ei_class)
+            .u8(1) // little-endian
+            .u8(EV_CURRENT)
+            .pad_to(16)
+            .u16(self.file_type)
+            .u16(EM_RISCV)
+            .u32(u32::from(EV_CURRENT))
+            .addr(if executable { program.entry() } else { 0 })
+            .addr(if segments.is_empty() {
+                0
+            } else {
+                u64::from(ehsize)
+            })
+            .addr(shoff)
+            .u32(self.flags)
+            .u16(ehsize)
+            .u16(if segments.is_empty() { 0 } else { phentsize })
+            .u16(segments.len() as u16)
+            .u16(shentsize)
+            .u16(sections.len() as u16)
+            .u16(sections.len() as u16 - 1);
+
+        for segment in &segments {
+            let headers: Vec<&Section> = segment.iter().map(|i| &sections[*i].0).collect();
+            let (first, last) = (headers[0], headers[headers.len() - 1]);
+            let size = last.addr + last.size - first.addr;
+            let flags = headers.iter().fold(PF_R, |flags, header| {
+                flags
+                    | match header.flags & SHF_EXECINSTR != 0 {
+                        true => PF_X,
+                        false => PF_W,
+                    }
+            });
+            if class == ElfClass::Elf64 {
+                out.u32(PT_LOAD).u32(flags);
+            } else {
+                out.u32(PT_LOAD);
+            }
+            out.addr(first.offset)
+                .addr(first.addr)
+                .addr(first.addr)
+                .addr(size)
+                .addr(size);
+            if class == ElfClass::Elf32 {
+                out.u32(flags);
+            }
+            out.addr(PAGE_SIZE);
+        }
+
+        for (header, bytes) in sections.iter().skip(1) {
+            out.pad_to(header.offset).bytes(bytes);
+        }
+        out.pad_to(shoff);
+        for ((header, _), name) in sections.iter().zip(names) {
+            out.u32(*name)
+                .u32(header.kind)
+                .addr(header.flags)
+                .addr(header.addr)
+                .addr(header.offset)
+                .addr(header.size)
+                .u32(header.link)
+                .u32(header.info)
+                .addr(header.addralign)
+                .addr(header.entsize);
+        }
+        out.into_vec()
+    }
+}
+
+/// Returns the header of a section, whose name and placement are set when it's written.
+fn section(kind: u32, flags: u64, addralign: Address) -> Section {
+    Section {
+        name: String::new(),
+        kind,
+        flags,
+        addr: 0,
+        offset: 0,
+        size: 0,
+        link: 0,
+        info: 0,
+        addralign: if kind == SHT_NULL { 0 } else { addralign },
+        entsize: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        asm::Assembler,
+        disasm::{InstructionFormatter, Relocation},
+        elf::{ElfError, ElfFile, ElfSymbol},
+    };
+
+    const SOURCE: &str = "
+        .globl _start
+    _start:
+        lui a0, 0x10
+        lw a0, 0(a0)
+        j _start
+        .data
+        .word 42
+    ptr: .word msg
+    msg: .byte 1, 2, 3
+    ";
+
+    fn u16_at(data: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    }
+
+    #[test]
+    fn test_executable() -> Result<(), ElfError> {
+        let program = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x10074)
+            .assemble(SOURCE)
+            .unwrap();
+        let data = ElfWriter::executable().write(&program);
+        // the sections share a page, so they're loaded by a single segment
+        assert_eq!(1, u16_at(&data, 44));
+        assert_eq!(
+            PF_R | PF_W | PF_X,
+            u32::from_le_bytes(data[76..80].try_into().unwrap())
+        );
+
+        let separate = Assembler::new(Xlen::Rv32)
+            .with_text_address(0x10074)
+            .with_data_address(0x11000)
+            .assemble(SOURCE)
+            .unwrap();
+        let separate = ElfWriter::executable().write(&separate);
+        // a segment per section, at offsets matching the addresses
+        assert_eq!(2, u16_at(&separate, 44));
+        for phdr in [52, 84] {
+            let word =
+                |i: usize| u32::from_le_bytes(separate[phdr + i..phdr + i + 4].try_into().unwrap());
+            assert_eq!(PT_LOAD, word(0));
+            assert_eq!(word(4) % 0x1000, word(8) % 0x1000);
+        }
+
+        let elf = ElfFile::parse(data)?;
+        assert_eq!(ElfClass::Elf32, elf.header().class);
+        assert_eq!(ET_EXEC, elf.header().file_type);
+        assert_eq!(0x10074, elf.header().entry);
+        assert_eq!(0, elf.header().flags);
+        assert_eq!(
+            vec!["", ".text", ".data", ".symtab", ".strtab", ".shstrtab"],
+            elf.sections()
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let text = elf.section_by_name(".text").unwrap();
+        let data = elf.section_by_name(".data").unwrap();
+        assert_eq!(program.text(), elf.section_data(text)?);
+        assert_eq!(program.data(), elf.section_data(data)?);
+        assert_eq!(program.data_address(), data.addr);
+
+        let symbols = elf.elf_symbols()?;
+        let symbol = |name: &str| symbols.iter().find(|s| s.name == name).unwrap();
+        assert_eq!((0x10074, STB_GLOBAL, 1), {
+            let s = symbol("_start");
+            (s.value, s.binding(), s.shndx)
+        });
+        assert_eq!((program.data_address() + 4, STB_LOCAL, 2), {
+            let s = symbol("ptr");
+            (s.value, s.binding(), s.shndx)
+        });
+        assert!(
+            symbols.iter().position(|s| s.name == "_start")
+                > symbols.iter().position(|s| s.name == "msg")
+        );
+
+        let formatter =
+            InstructionFormatter::new(elf.disasm_config()).with_symbols(elf.section_symbols(text)?);
+        let lines: Vec<String> = elf
+            .disasm(text)?
+            .map(|r| formatter.record(&r.unwrap()))
+            .collect();
+        assert_eq!("0x0001007c:   j 0x10074 <_start>", lines[2]);
+        assert!(elf.section_relocations(data)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_relocatable() -> Result<(), ElfError> {
+        let program = Assembler::new(Xlen::Rv64).assemble(SOURCE).unwrap();
+        let data = ElfWriter::relocatable().write(&program);
+        // no program headers
+        assert_eq!(0, u16_at(&data, 56));
+
+        let elf = ElfFile::parse(data)?;
+        assert_eq!(ElfClass::Elf64, elf.header().class);
+        assert_eq!(ET_REL, elf.header().file_type);
+        assert_eq!(0, elf.header().entry);
+        assert_eq!(
+            vec![
+                "",
+                ".text",
+                ".data",
+                ".rela.data",
+                ".symtab",
+                ".strtab",
+                ".shstrtab"
+            ],
+            elf.sections()
+                .iter()
+                .map(|s| s.name.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        let data = elf.section_by_name(".data").unwrap();
+        assert_eq!(0, data.addr);
+        assert_eq!(
+            vec![Relocation::new(4, "R_RISCV_32", "msg", 0)],
+            elf.section_relocations(data)?
+        );
+        assert_eq!(&[42, 0, 0, 0, 0, 0, 0, 0], &elf.section_data(data)?[..8]);
+
+        let msg: Vec<ElfSymbol> = elf
+            .elf_symbols()?
+            .into_iter()
+            .filter(|s| s.name == "msg")
+            .collect();
+        assert_eq!((8, 2), (msg[0].value, msg[0].shndx));
+        Ok(())
+    }
+
+    #[test]
+    fn test_flags() -> Result<(), ElfError> {
+        let program = Assembler::default().assemble("nop").unwrap();
+        let writer = ElfWriter::executable()
+            .with_rvc(true)
+            .with_float_abi(FloatAbi::Double)
+            .with_float_abi(FloatAbi::Single);
+        let elf = ElfFile::parse(writer.write(&program))?;
+        assert!(elf.header().rvc());
+        assert_eq!(FloatAbi::Single, elf.header().float_abi());
+
+        let writer = writer.with_rvc(false).with_float_abi(FloatAbi::Soft);
+        let elf = ElfFile::parse(writer.write(&program))?;
+        assert_eq!(0, elf.header().flags);
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::model::InstructionFormat;
+use crate::model::{InstructionFormat, Xlen};
 use thiserror::Error;
 
 #[derive(Debug, PartialEq, Error)]
@@ -9,6 +9,9 @@ pub enum RISCVError {
     #[error("Register must be a number between 0 and 31")]
     InvalidRegister,
 
+    #[error("CSR address must be a number between 0 and 4095")]
+    InvalidCsr,
+
     #[error("Unrecognized instruction format")]
     UnrecognizedInstructionFormat,
 
@@ -24,9 +27,30 @@ pub enum RISCVError {
     #[error("Immediate cannot have any data before bit {0}")]
     ImmediateBitsBeforeStart(u8),
 
+    #[error("Reserved rounding mode")]
+    InvalidRoundingMode,
+
+    #[error("Shift amount can only be set for shift-immediate instructions")]
+    UnexpectedShamt,
+
+    #[error("CSR can only be set for Zicsr instructions")]
+    UnexpectedCsr,
+
     #[error("Unrecognized instruction size")]
     UnrecognizedInstructionSize,
 
+    #[error("Illegal or reserved compressed instruction")]
+    IllegalCompressedInstruction,
+
+    #[error("Operands can't be encoded in the compressed instruction")]
+    InvalidCompressedOperands,
+
+    #[error("Instruction is not supported in {0}")]
+    UnsupportedInstruction(Xlen),
+
+    #[error("Unsupported XLEN: {0}")]
+    UnsupportedXlen(u32),
+
     #[error("Unidentified extension")]
     UnrecognizedExtension,
 
@@ -0,0 +1,239 @@
+use std::fmt;
+
+use super::InstructionTrait;
+use crate::config::UNKNOWN_MNEMONIC;
+use crate::data::get_mnemonic;
+use crate::error::RISCVError;
+use crate::model::{
+    AmoWidth, Funct3, Funct5, Funct7, InstructionFormat, Mnemonic, Opcode, Register,
+};
+
+/// Major opcode of atomic instructions (AMO)
+pub(crate) const AMO_OPCODE: u8 = 0b0101111;
+
+const LR_FUNCT5: u8 = 0b00010;
+
+/// Atomic instruction of the A extension (and Zabha/Zacas).
+///
+/// These are R-type instructions, whose funct7 is split into the operation (`funct5`)
+/// and the memory ordering bits: `aq` (acquire) and `rl` (release). The width of
+/// the memory operand is encoded in funct3. Load-reserved (`lr`) has no `rs2` operand.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct AmoInstruction {
+    opcode: Opcode,
+    rs1: Register,
+    rs2: Register,
+    rd: Register,
+    width: AmoWidth,
+    funct5: Funct5,
+    aq: bool,
+    rl: bool,
+}
+
+impl AmoInstruction {
+    pub fn new(
+        rs1: Register,
+        rs2: Register,
+        rd: Register,
+        width: AmoWidth,
+        funct5: Funct5,
+        aq: bool,
+        rl: bool,
+    ) -> Result<Self, RISCVError> {
+        Ok(Self {
+            opcode: Opcode::try_from(AMO_OPCODE)?,
+            rs1,
+            rs2,
+            rd,
+            width,
+            funct5,
+            aq,
+            rl,
+        })
+    }
+
+    pub fn rs1(&self) -> Register {
+        self.rs1
+    }
+
+    pub fn rs2(&self) -> Register {
+        self.rs2
+    }
+
+    pub fn rd(&self) -> Register {
+        self.rd
+    }
+
+    pub fn width(&self) -> AmoWidth {
+        self.width
+    }
+
+    pub fn funct3(&self) -> Funct3 {
+        self.width.into()
+    }
+
+    pub fn funct5(&self) -> Funct5 {
+        self.funct5
+    }
+
+    /// Returns the whole funct7 field, including the ordering bits
+    pub fn funct7(&self) -> Funct7 {
+        Funct7::from(
+            u32::from(self.funct5) | (u32::from(self.aq) << 26) | (u32::from(self.rl) << 25),
+        )
+    }
+
+    /// Returns `true` if no later memory operation can be observed before this one
+    pub fn aq(&self) -> bool {
+        self.aq
+    }
+
+    /// Returns `true` if this operation can't be observed before any earlier memory operation
+    pub fn rl(&self) -> bool {
+        self.rl
+    }
+
+    /// Checks whether the instruction is a load-reserved (`lr`), which has no `rs2` operand.
+    pub fn is_load_reserved(&self) -> bool {
+        u8::from(self.funct5) == LR_FUNCT5
+    }
+
+    /// Returns the suffix of the mnemonic for the memory ordering bits
+    /// (`.aq`, `.rl`, `.aqrl`, or an empty string).
+    pub fn ordering_suffix(&self) -> &'static str {
+        match (self.aq, self.rl) {
+            (false, false) => "",
+            (true, false) => ".aq",
+            (false, true) => ".rl",
+            (true, true) => ".aqrl",
+        }
+    }
+}
+
+impl InstructionTrait for AmoInstruction {
+    fn opcode(&self) -> &Opcode {
+        &self.opcode
+    }
+
+    fn format(&self) -> &InstructionFormat {
+        &InstructionFormat::R
+    }
+
+    /// Returns the mnemonic of the operation, without the ordering suffix.
+    fn mnemonic(&self) -> Option<Mnemonic> {
+        if self.is_load_reserved() && self.rs2 != Register::zero() {
+            return None;
+        }
+        let funct7 = Funct7::from(u32::from(self.funct5));
+        get_mnemonic(self.opcode, Some(self.funct3()), Some(funct7))
+    }
+
+    fn immediate_bits(&self) -> u32 {
+        0
+    }
+}
+
+impl TryFrom<u32> for AmoInstruction {
+    type Error = RISCVError;
+
+    fn try_from(instr: u32) -> Result<Self, Self::Error> {
+        let opcode = Opcode::try_from(instr)?;
+        if u8::from(opcode) != AMO_OPCODE {
+            return Err(RISCVError::InvalidOpcode);
+        }
+
+        Ok(Self {
+            opcode,
+            rs1: Register::from_rs1_bits(instr),
+            rs2: Register::from_rs2_bits(instr),
+            rd: Register::from_rd_bits(instr),
+            width: AmoWidth::try_from(Funct3::from(instr))?,
+            funct5: Funct5::from(instr),
+            aq: (instr >> 26) & 1 == 1,
+            rl: (instr >> 25) & 1 == 1,
+        })
+    }
+}
+
+impl From<AmoInstruction> for u32 {
+    fn from(instr: AmoInstruction) -> Self {
+        u32::from(&instr)
+    }
+}
+
+impl From<&AmoInstruction> for u32 {
+    fn from(instr: &AmoInstruction) -> u32 {
+        u32::from(instr.opcode)
+            | u32::from(instr.funct3())
+            | u32::from(instr.funct7())
+            | instr.rs1.into_rs1_bits()
+            | instr.rs2.into_rs2_bits()
+            | instr.rd.into_rd_bits()
+    }
+}
+
+/// Formats the instruction like binutils, e.g. `amoadd.w.aqrl a0, a1, (a2)`.
+impl fmt::Display for AmoInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{} {}, ",
+            self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()),
+            self.ordering_suffix(),
+            self.rd
+        )?;
+        if self.is_load_reserved() {
+            write!(f, "({})", self.rs1)
+        } else {
+            write!(f, "{}, ({})", self.rs2, self.rs1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_two_way_conversion() -> Result<(), RISCVError> {
+        let bits = 0x06c5a52f; // amoadd.w.aqrl a0, a2, (a1)
+        let instr = AmoInstruction::try_from(bits)?;
+        assert_eq!(AmoWidth::W, instr.width());
+        assert_eq!(0, u8::from(instr.funct5()));
+        assert!(instr.aq() && instr.rl());
+        assert_eq!(bits, u32::from(instr));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ordering() -> Result<(), RISCVError> {
+        // amoadd.w with every combination of the ordering bits maps to the same operation
+        for (bits, text) in [
+            (0x00c5a52f, "amoadd.w a0, a2, (a1)"),
+            (0x04c5a52f, "amoadd.w.aq a0, a2, (a1)"),
+            (0x02c5a52f, "amoadd.w.rl a0, a2, (a1)"),
+            (0x06c5a52f, "amoadd.w.aqrl a0, a2, (a1)"),
+        ] {
+            let instr = AmoInstruction::try_from(bits)?;
+            assert_eq!(Some("amoadd_w".into()), instr.mnemonic());
+            assert_eq!(text, instr.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_reserved_pair() -> Result<(), RISCVError> {
+        let lr = AmoInstruction::try_from(0x1405b52f)?; // lr.d.aq a0, (a1)
+        assert!(lr.is_load_reserved());
+        assert_eq!("lr.d.aq a0, (a1)", lr.to_string());
+
+        let sc = AmoInstruction::try_from(0x1ac5a52f)?; // sc.w.rl a0, a2, (a1)
+        assert_eq!(AmoWidth::W, sc.width());
+        assert_eq!("sc.w.rl a0, a2, (a1)", sc.to_string());
+
+        // lr with rs2 other than zero is reserved
+        let lr = AmoInstruction::try_from(0x10c5a52f)?;
+        assert_eq!(None, lr.mnemonic());
+        Ok(())
+    }
+}
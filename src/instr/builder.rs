@@ -1,8 +1,14 @@
 use crate::{
-    data::find_instr_from_mnemonic,
+    data::{find_float_instr_from_mnemonic, find_instr_from_mnemonic},
     error::RISCVError,
-    instr::{BInstruction, IInstruction, JInstruction, RInstruction, SInstruction, UInstruction},
-    model::{Funct3, Funct7, Immediate, InstructionFormat, Opcode, Register, Mnemonic},
+    instr::{
+        AmoInstruction, BInstruction, IInstruction, JInstruction, R4Instruction, RInstruction,
+        SInstruction, UInstruction, AMO_OPCODE,
+    },
+    model::{
+        AmoWidth, Csr, FRegister, Funct2, Funct3, Funct5, Funct7, Immediate, InstructionFormat,
+        Mnemonic, Opcode, Register, RoundingMode, REGISTER_MASK,
+    },
 };
 
 use super::{Instruction, InstructionTrait};
@@ -40,12 +46,15 @@ use super::{Instruction, InstructionTrait};
 #[derive(Default)]
 pub struct InstructionBuilder {
     opcode: Option<Opcode>,
+    funct2: Option<Funct2>,
     funct3: Option<Funct3>,
     funct7: Option<Funct7>,
     rs1: Option<Register>,
     rs2: Option<Register>,
+    rs3: Option<Register>,
     rd: Option<Register>,
     immediate: Option<i32>,
+    csr: Option<Csr>,
 }
 
 impl InstructionBuilder {
@@ -55,16 +64,52 @@ impl InstructionBuilder {
     }
 
     pub fn from_mnemonic(mnemonic: Mnemonic) -> Result<Self, RISCVError> {
-        let data =
+        if let Some((mask, bits, rm)) = find_float_instr_from_mnemonic(&mnemonic) {
+            return Self::from_float_encoding(mask, bits, rm);
+        }
+
+        let (opcode, funct3, funct7) =
             find_instr_from_mnemonic(mnemonic).ok_or(RISCVError::BuilderError("".to_string()))?;
+
+        // R4-type instructions keep their fmt field (funct2) in place of funct7
+        let (funct2, funct7) = if opcode.format() == InstructionFormat::R4 {
+            (Some(Funct2::try_from(u8::from(funct7))?), None)
+        } else {
+            (None, Some(funct7))
+        };
+
+        Ok(Self {
+            opcode: Some(opcode),
+            funct2,
+            funct3: Some(funct3),
+            funct7,
+            ..Default::default()
+        })
+    }
+
+    /// Presets the fixed fields of a floating-point instruction, including rs2 selecting
+    /// the operation of conversions (e.g. `fcvt.l.s`). The rounding mode defaults to dynamic.
+    fn from_float_encoding(mask: u32, bits: u32, rm: bool) -> Result<Self, RISCVError> {
+        let opcode = Opcode::try_from(bits)?;
+        let funct3 = if rm {
+            RoundingMode::Dyn.into()
+        } else {
+            Funct3::from(bits)
+        };
+        let (funct2, funct7) = if opcode.format() == InstructionFormat::R4 {
+            (Some(Funct2::from(bits)), None)
+        } else {
+            (None, Some(Funct7::from(bits)))
+        };
+        let rs2 = (mask & (REGISTER_MASK << 20) != 0).then(|| Register::from_rs2_bits(bits));
+
         Ok(Self {
-            opcode: Some(data.0),
-            funct3: Some(data.1),
-            funct7: Some(data.2),
-            rs1: None,
-            rs2: None,
-            rd: None,
-            immediate: None,
+            opcode: Some(opcode),
+            funct2,
+            funct3: Some(funct3),
+            funct7,
+            rs2,
+            ..Default::default()
         })
     }
 
@@ -82,6 +127,20 @@ impl InstructionBuilder {
         self
     }
 
+    /// Sets the funct2 field (`fmt`) for the R4-type instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `funct2` - The funct2 value to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_funct2(&mut self, funct2: Funct2) -> &mut InstructionBuilder {
+        self.funct2 = Some(funct2);
+        self
+    }
+
     /// Sets the funct3 field for the instruction.
     ///
     /// # Arguments
@@ -110,6 +169,24 @@ impl InstructionBuilder {
         self
     }
 
+    /// Sets the memory ordering bits of an atomic instruction, which are stored
+    /// in the two lowest bits of funct7.
+    ///
+    /// # Arguments
+    ///
+    /// * `aq` - The acquire bit.
+    /// * `rl` - The release bit.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_ordering(&mut self, aq: bool, rl: bool) -> &mut InstructionBuilder {
+        let funct7 = self.funct7.map_or(0, u32::from) & !(0b11 << 25);
+        let ordering = (u32::from(aq) << 26) | (u32::from(rl) << 25);
+        self.funct7 = Some(Funct7::from(funct7 | ordering));
+        self
+    }
+
     /// Sets the rs1 register for the instruction.
     ///
     /// # Arguments
@@ -138,6 +215,20 @@ impl InstructionBuilder {
         self
     }
 
+    /// Sets the rs3 register for the R4-type instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The rs3 register to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_rs3(&mut self, reg: Register) -> &mut InstructionBuilder {
+        self.rs3 = Some(reg);
+        self
+    }
+
     /// Sets the rd register for the instruction.
     ///
     /// # Arguments
@@ -152,6 +243,71 @@ impl InstructionBuilder {
         self
     }
 
+    /// Sets the floating-point rs1 register for the instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The rs1 register to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_frs1(&mut self, reg: FRegister) -> &mut InstructionBuilder {
+        self.set_rs1(Register::from_rs1_bits(reg.into_rs1_bits()))
+    }
+
+    /// Sets the floating-point rs2 register for the instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The rs2 register to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_frs2(&mut self, reg: FRegister) -> &mut InstructionBuilder {
+        self.set_rs2(Register::from_rs2_bits(reg.into_rs2_bits()))
+    }
+
+    /// Sets the floating-point rs3 register for the R4-type instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The rs3 register to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_frs3(&mut self, reg: FRegister) -> &mut InstructionBuilder {
+        self.set_rs3(Register::from_rs3_bits(reg.into_rs3_bits()))
+    }
+
+    /// Sets the floating-point rd register for the instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `reg` - The rd register to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_frd(&mut self, reg: FRegister) -> &mut InstructionBuilder {
+        self.set_rd(Register::from_rd_bits(reg.into_rd_bits()))
+    }
+
+    /// Sets the rounding mode of the floating-point operation, stored in funct3.
+    ///
+    /// # Arguments
+    ///
+    /// * `rm` - The rounding mode to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_rounding_mode(&mut self, rm: RoundingMode) -> &mut InstructionBuilder {
+        self.set_funct3(rm.into())
+    }
+
     /// Sets the immediate value for the instruction.
     ///
     /// # Arguments
@@ -166,6 +322,21 @@ impl InstructionBuilder {
         self
     }
 
+    /// Sets the CSR accessed by a Zicsr instruction. The 5-bit unsigned immediate
+    /// of `csrrwi`, `csrrsi` and `csrrci` is set with [`InstructionBuilder::set_immediate`].
+    ///
+    /// # Arguments
+    ///
+    /// * `csr` - The CSR to set.
+    ///
+    /// # Returns
+    ///
+    /// A mutable reference to the `InstructionBuilder`.
+    pub fn set_csr(&mut self, csr: Csr) -> &mut InstructionBuilder {
+        self.csr = Some(csr);
+        self
+    }
+
     fn opcode(&self) -> Result<Opcode, RISCVError> {
         self.opcode
             .ok_or(RISCVError::BuilderError("Opcode not provided".to_string()))
@@ -183,12 +354,24 @@ impl InstructionBuilder {
         ))
     }
 
+    fn rs3(&self) -> Result<Register, RISCVError> {
+        self.rs3.ok_or(RISCVError::BuilderError(
+            "rs3 value not provided".to_string(),
+        ))
+    }
+
     fn rd(&self) -> Result<Register, RISCVError> {
         self.rd.ok_or(RISCVError::BuilderError(
             "rd value not provided".to_string(),
         ))
     }
 
+    fn funct2(&self) -> Result<Funct2, RISCVError> {
+        self.funct2.ok_or(RISCVError::BuilderError(
+            "funct2 value not provided".to_string(),
+        ))
+    }
+
     fn funct3(&self) -> Result<Funct3, RISCVError> {
         self.funct3.ok_or(RISCVError::BuilderError(
             "funct3 value not provided".to_string(),
@@ -210,12 +393,15 @@ impl InstructionBuilder {
     /// Resets the builder to its default state.
     pub fn reset(&mut self) {
         self.opcode = None;
+        self.funct2 = None;
         self.funct3 = None;
         self.funct7 = None;
         self.rs1 = None;
         self.rs2 = None;
+        self.rs3 = None;
         self.rd = None;
         self.immediate = None;
+        self.csr = None;
     }
 
     /// Builds the instruction based on the provided fields.
@@ -236,6 +422,36 @@ impl InstructionBuilder {
                 self.funct3()?,
                 Immediate::<1, 12>::try_from(self.immediate()?)?,
             )?),
+            // the unsigned immediate of csrr*i is encoded in place of rs1
+            I if self.csr.is_some() => {
+                let funct3 = self.funct3()?;
+                let rs1 = match self.immediate {
+                    Some(uimm) if u8::from(funct3) & 0b100 != 0 => u8::try_from(uimm)
+                        .ok()
+                        .and_then(|uimm| Register::try_from(uimm).ok())
+                        .ok_or(RISCVError::ImmediateOutOfRange(0, 31))?,
+                    _ => self.rs1()?,
+                };
+                Instruction::I(IInstruction::new_csr(
+                    opcode,
+                    rs1,
+                    self.rd()?,
+                    funct3,
+                    self.csr.ok_or(RISCVError::UnexpectedCsr)?,
+                )?)
+            }
+            // with funct7 given, the immediate of shift-immediates is the shift amount
+            I if self.funct7.is_some() && IInstruction::has_shamt(opcode, self.funct3()?) => {
+                let shamt = self.immediate()?;
+                Instruction::I(IInstruction::new_shift(
+                    opcode,
+                    self.rs1()?,
+                    self.rd()?,
+                    self.funct3()?,
+                    self.funct7()?,
+                    u8::try_from(shamt).map_err(|_| RISCVError::ImmediateOutOfRange(0, 63))?,
+                )?)
+            }
             I => Instruction::I(IInstruction::new(
                 opcode,
                 self.rs1()?,
@@ -248,6 +464,19 @@ impl InstructionBuilder {
                 self.rd()?,
                 Immediate::<1, 20>::try_from(self.immediate()?)?,
             )?),
+            R if u8::from(opcode) == AMO_OPCODE => {
+                let funct7 = u32::from(self.funct7()?);
+                Instruction::Amo(AmoInstruction::new(
+                    self.rs1()?,
+                    // load-reserved has no rs2 operand
+                    self.rs2.unwrap_or(Register::zero()),
+                    self.rd()?,
+                    AmoWidth::try_from(self.funct3()?)?,
+                    Funct5::from(funct7),
+                    (funct7 >> 26) & 1 == 1,
+                    (funct7 >> 25) & 1 == 1,
+                )?)
+            }
             R => Instruction::R(RInstruction::new(
                 opcode,
                 self.rs1()?,
@@ -256,6 +485,15 @@ impl InstructionBuilder {
                 self.funct3()?,
                 self.funct7()?,
             )?),
+            R4 => Instruction::R4(R4Instruction::new(
+                opcode,
+                self.rs1()?,
+                self.rs2()?,
+                self.rs3()?,
+                self.rd()?,
+                self.funct2()?,
+                self.funct3()?,
+            )?),
             S => Instruction::S(SInstruction::new(
                 opcode,
                 self.rs1()?,
@@ -268,6 +506,7 @@ impl InstructionBuilder {
                 self.rd()?,
                 Immediate::<12, 31>::try_from(self.immediate()?)?,
             )?),
+            format => return Err(RISCVError::UnexpectedFormat(format)),
         };
 
         Ok(instr)
@@ -284,15 +523,22 @@ impl From<&Instruction> for InstructionBuilder {
     /// # Returns
     ///
     /// An `InstructionBuilder` initialized with the fields from the provided instruction.
+    /// Compressed instructions are expanded to their 32-bit equivalents first.
     fn from(instr: &Instruction) -> Self {
+        if let Instruction::C(c) = instr {
+            return InstructionBuilder::from(&c.expand());
+        }
         InstructionBuilder {
             opcode: Some(*instr.opcode()),
+            funct2: instr.funct2(),
             funct3: instr.funct3(),
             funct7: instr.funct7(),
             rs1: instr.rs1(),
             rs2: instr.rs2(),
+            rs3: instr.rs3(),
             rd: instr.rd(),
             immediate: instr.immediate(),
+            csr: instr.csr(),
         }
     }
 }
@@ -315,6 +561,131 @@ impl From<Instruction> for InstructionBuilder {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::model::Xlen;
+
+    #[test]
+    fn test_shift_from_mnemonic() -> Result<(), RISCVError> {
+        let instr = InstructionBuilder::from_mnemonic("srai".into())?
+            .set_rd(Register::a0())
+            .set_rs1(Register::a0())
+            .set_immediate(3)
+            .build()?;
+        assert_eq!(0x40355513, u32::from(instr));
+        assert_eq!(Some(3), instr.immediate());
+
+        let rori = Instruction::decode(0x62855513, Xlen::Rv64)?; // rori a0, a0, 40
+        assert_eq!(rori, InstructionBuilder::from(rori).build()?);
+
+        let too_far = InstructionBuilder::from_mnemonic("slli".into())?
+            .set_rd(Register::a0())
+            .set_rs1(Register::a0())
+            .set_immediate(64)
+            .build();
+        assert_eq!(Err(RISCVError::ImmediateOutOfRange(0, 63)), too_far);
+        Ok(())
+    }
+
+    #[test]
+    fn test_amo_from_mnemonic() -> Result<(), RISCVError> {
+        let instr = InstructionBuilder::from_mnemonic("amoadd_w".into())?
+            .set_rd(Register::a0())
+            .set_rs1(Register::a1())
+            .set_rs2(Register::a2())
+            .set_ordering(true, true)
+            .build()?;
+        assert_eq!(0x06c5a52f, u32::from(instr));
+        assert_eq!("amoadd.w.aqrl a0, a2, (a1)", instr.to_string());
+
+        let lr = InstructionBuilder::from_mnemonic("lr_d".into())?
+            .set_rd(Register::a0())
+            .set_rs1(Register::a1())
+            .set_ordering(true, false)
+            .build()?;
+        assert_eq!(0x1405b52f, u32::from(lr));
+        Ok(())
+    }
+
+    #[test]
+    fn test_r4_from_mnemonic() -> Result<(), RISCVError> {
+        let instr = InstructionBuilder::from_mnemonic("fmadd_s".into())?
+            .set_rd(Register::a0())
+            .set_rs1(Register::a1())
+            .set_rs2(Register::a2())
+            .set_rs3(Register::a3())
+            .set_funct3(Funct3::try_from(0b111u8)?)
+            .build()?;
+        assert_eq!(0x68c5f543, u32::from(instr));
+        Ok(())
+    }
+
+    #[test]
+    fn test_float_from_mnemonic() -> Result<(), RISCVError> {
+        let fadd = InstructionBuilder::from_mnemonic("fadd_s".into())?
+            .set_frd(FRegister::fa0())
+            .set_frs1(FRegister::fa1())
+            .set_frs2(FRegister::fa2())
+            .build()?;
+        assert_eq!(0x00c5f553, u32::from(fadd));
+        assert_eq!("fadd.s fa0, fa1, fa2", fadd.to_string());
+
+        // rs2 selects the conversion, and both register classes are used
+        let fcvt = InstructionBuilder::from_mnemonic("fcvt_wu_s".into())?
+            .set_rd(Register::a0())
+            .set_frs1(FRegister::fa1())
+            .set_rounding_mode(RoundingMode::Rtz)
+            .build()?;
+        assert_eq!(0xc0159553, u32::from(fcvt));
+        assert_eq!("fcvt.wu.s a0, fa1, rtz", fcvt.to_string());
+
+        let flw = InstructionBuilder::from_mnemonic("flw".into())?
+            .set_frd(FRegister::fa0())
+            .set_rs1(Register::sp())
+            .set_immediate(4)
+            .build()?;
+        assert_eq!(0x00412507, u32::from(flw));
+        assert_eq!(Some(FRegister::fa0()), flw.frd());
+        Ok(())
+    }
+
+    #[test]
+    fn test_csr_from_mnemonic() -> Result<(), RISCVError> {
+        let csrrs = InstructionBuilder::from_mnemonic("csrrs".into())?
+            .set_rd(Register::a0())
+            .set_rs1(Register::zero())
+            .set_csr(Csr::from_name("mstatus").unwrap())
+            .build()?;
+        assert_eq!(0x30002573, u32::from(csrrs));
+        assert_eq!(csrrs, InstructionBuilder::from(csrrs).build()?);
+
+        let csrrwi = InstructionBuilder::from_mnemonic("csrrwi".into())?
+            .set_rd(Register::zero())
+            .set_csr(Csr::from_name("mtvec").unwrap())
+            .set_immediate(5)
+            .build()?;
+        assert_eq!(0x3052d073, u32::from(csrrwi));
+        assert_eq!(Some(5), csrrwi.immediate());
+        assert_eq!(csrrwi, InstructionBuilder::from(csrrwi).build()?);
+
+        let too_big = InstructionBuilder::from_mnemonic("csrrsi".into())?
+            .set_rd(Register::zero())
+            .set_csr(Csr::from_name("mtvec").unwrap())
+            .set_immediate(32)
+            .build();
+        assert_eq!(Err(RISCVError::ImmediateOutOfRange(0, 31)), too_big);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compressed_output() -> Result<(), RISCVError> {
+        let instr = InstructionBuilder::from_mnemonic("addi".into())?
+            .set_rd(Register::sp())
+            .set_rs1(Register::sp())
+            .set_immediate(-16)
+            .build()?;
+        let compressed = instr.try_compress(Xlen::Rv32).map(u16::from);
+        assert_eq!(Some(0x1141), compressed);
+        Ok(())
+    }
 
     #[test]
     fn test_from_mnemonic() -> Result<(), RISCVError> {
@@ -0,0 +1,779 @@
+use std::fmt;
+
+use super::{float::register_name, Instruction, InstructionBuilder, InstructionTrait};
+use crate::{
+    config::UNKNOWN_MNEMONIC,
+    data::get_compressed_mnemonic,
+    error::RISCVError,
+    model::{
+        CompressedOp, Funct3, Funct7, InstructionFormat, Mnemonic, Opcode, Register, RegisterClass,
+        Xlen,
+    },
+    utils::bit::copy_bit,
+};
+
+/// Placement of immediate bits in a compressed instruction. Every segment starts
+/// at the given (highest) instruction bit and lists the immediate bits stored in
+/// the consecutive instruction bits, going down.
+type ImmLayout = &'static [(u8, &'static [u8])];
+
+const IMM6: ImmLayout = &[(12, &[5]), (6, &[4, 3, 2, 1, 0])];
+const ADDI16SP_IMM: ImmLayout = &[(12, &[9]), (6, &[4, 6, 8, 7, 5])];
+const LUI_IMM: ImmLayout = &[(12, &[17]), (6, &[16, 15, 14, 13, 12])];
+const ADDI4SPN_IMM: ImmLayout = &[(12, &[5, 4, 9, 8, 7, 6, 2, 3])];
+const WORD_OFFSET: ImmLayout = &[(12, &[5, 4, 3]), (6, &[2, 6])];
+const DOUBLE_OFFSET: ImmLayout = &[(12, &[5, 4, 3]), (6, &[7, 6])];
+const BYTE_OFFSET: ImmLayout = &[(6, &[0, 1])];
+const HALF_OFFSET: ImmLayout = &[(5, &[1])];
+const JUMP_OFFSET: ImmLayout = &[(12, &[11, 4, 9, 8, 10, 6, 7, 3, 2, 1, 5])];
+const BRANCH_OFFSET: ImmLayout = &[(12, &[8, 4, 3]), (6, &[7, 6, 2, 1, 5])];
+const LWSP_OFFSET: ImmLayout = &[(12, &[5]), (6, &[4, 3, 2, 7, 6])];
+const LDSP_OFFSET: ImmLayout = &[(12, &[5]), (6, &[4, 3, 8, 7, 6])];
+const SWSP_OFFSET: ImmLayout = &[(12, &[5, 4, 3, 2, 7, 6])];
+const SDSP_OFFSET: ImmLayout = &[(12, &[5, 4, 3, 8, 7, 6])];
+
+/// Location of a register operand in a compressed instruction.
+#[derive(Clone, Copy, PartialEq)]
+enum RegField {
+    /// The operand is not used by the instruction
+    Unused,
+    /// The register is implied by the operation
+    Fixed(u8),
+    /// 5-bit register number stored from the given bit
+    Full(u8),
+    /// 3-bit number of one of the x8-x15 registers stored from the given bit
+    Prime(u8),
+}
+
+impl RegField {
+    fn read(&self, bits: u16) -> Register {
+        let reg = match *self {
+            RegField::Unused => 0,
+            RegField::Fixed(reg) => reg,
+            RegField::Full(shift) => ((bits >> shift) & 0b11111) as u8,
+            RegField::Prime(shift) => 8 + ((bits >> shift) & 0b111) as u8,
+        };
+        match Register::try_from(reg) {
+            Ok(reg) => reg,
+            Err(_) => unreachable!("As the value is masked, it's always a valid register"),
+        }
+    }
+
+    fn write(&self, reg: Register) -> u16 {
+        let reg = u16::from(u8::from(reg));
+        match *self {
+            RegField::Unused | RegField::Fixed(_) => 0,
+            RegField::Full(shift) => reg << shift,
+            RegField::Prime(shift) => (reg.wrapping_sub(8) & 0b111) << shift,
+        }
+    }
+}
+
+/// Fixed bits and operand locations of a compressed operation.
+struct Encoding {
+    bits: u16,
+    rd: RegField,
+    rs1: RegField,
+    rs2: RegField,
+    imm: Option<(ImmLayout, bool)>,
+}
+
+const fn enc(
+    bits: u16,
+    rd: RegField,
+    rs1: RegField,
+    rs2: RegField,
+    imm: Option<(ImmLayout, bool)>,
+) -> Encoding {
+    Encoding {
+        bits,
+        rd,
+        rs1,
+        rs2,
+        imm,
+    }
+}
+
+fn encoding(op: CompressedOp) -> Encoding {
+    use CompressedOp::*;
+    use RegField::*;
+
+    const HI: RegField = Full(7);
+    const LO: RegField = Full(2);
+    const HI_P: RegField = Prime(7);
+    const LO_P: RegField = Prime(2);
+    const SP: RegField = Fixed(2);
+
+    let signed = |layout| Some((layout, true));
+    let unsigned = |layout| Some((layout, false));
+
+    match op {
+        Addi4spn => enc(0x0000, LO_P, SP, Unused, unsigned(ADDI4SPN_IMM)),
+        Fld => enc(0x2000, LO_P, HI_P, Unused, unsigned(DOUBLE_OFFSET)),
+        Lw => enc(0x4000, LO_P, HI_P, Unused, unsigned(WORD_OFFSET)),
+        Flw => enc(0x6000, LO_P, HI_P, Unused, unsigned(WORD_OFFSET)),
+        Lbu => enc(0x8000, LO_P, HI_P, Unused, unsigned(BYTE_OFFSET)),
+        Lhu => enc(0x8400, LO_P, HI_P, Unused, unsigned(HALF_OFFSET)),
+        Lh => enc(0x8440, LO_P, HI_P, Unused, unsigned(HALF_OFFSET)),
+        Sb => enc(0x8800, Unused, HI_P, LO_P, unsigned(BYTE_OFFSET)),
+        Sh => enc(0x8c00, Unused, HI_P, LO_P, unsigned(HALF_OFFSET)),
+        Fsd => enc(0xa000, Unused, HI_P, LO_P, unsigned(DOUBLE_OFFSET)),
+        Sw => enc(0xc000, Unused, HI_P, LO_P, unsigned(WORD_OFFSET)),
+        Fsw => enc(0xe000, Unused, HI_P, LO_P, unsigned(WORD_OFFSET)),
+
+        Nop => enc(0x0001, Fixed(0), Fixed(0), Unused, signed(IMM6)),
+        Addi => enc(0x0001, HI, HI, Unused, signed(IMM6)),
+        Jal => enc(0x2001, Fixed(1), Unused, Unused, signed(JUMP_OFFSET)),
+        Li => enc(0x4001, HI, Fixed(0), Unused, signed(IMM6)),
+        Addi16sp => enc(0x6101, SP, SP, Unused, signed(ADDI16SP_IMM)),
+        Lui => enc(0x6001, HI, Unused, Unused, signed(LUI_IMM)),
+        Srli => enc(0x8001, HI_P, HI_P, Unused, unsigned(IMM6)),
+        Srai => enc(0x8401, HI_P, HI_P, Unused, unsigned(IMM6)),
+        Andi => enc(0x8801, HI_P, HI_P, Unused, signed(IMM6)),
+        Sub => enc(0x8c01, HI_P, HI_P, LO_P, None),
+        Xor => enc(0x8c21, HI_P, HI_P, LO_P, None),
+        Or => enc(0x8c41, HI_P, HI_P, LO_P, None),
+        And => enc(0x8c61, HI_P, HI_P, LO_P, None),
+        Mul => enc(0x9c41, HI_P, HI_P, LO_P, None),
+        ZextB => enc(0x9c61, HI_P, HI_P, Unused, None),
+        SextB => enc(0x9c65, HI_P, HI_P, Unused, None),
+        ZextH => enc(0x9c69, HI_P, HI_P, Unused, None),
+        SextH => enc(0x9c6d, HI_P, HI_P, Unused, None),
+        Not => enc(0x9c75, HI_P, HI_P, Unused, None),
+        J => enc(0xa001, Fixed(0), Unused, Unused, signed(JUMP_OFFSET)),
+        Beqz => enc(0xc001, Unused, HI_P, Fixed(0), signed(BRANCH_OFFSET)),
+        Bnez => enc(0xe001, Unused, HI_P, Fixed(0), signed(BRANCH_OFFSET)),
+
+        Slli => enc(0x0002, HI, HI, Unused, unsigned(IMM6)),
+        Fldsp => enc(0x2002, HI, SP, Unused, unsigned(LDSP_OFFSET)),
+        Lwsp => enc(0x4002, HI, SP, Unused, unsigned(LWSP_OFFSET)),
+        Flwsp => enc(0x6002, HI, SP, Unused, unsigned(LWSP_OFFSET)),
+        Jr => enc(0x8002, Fixed(0), HI, Unused, None),
+        Mv => enc(0x8002, HI, Fixed(0), LO, None),
+        Ebreak => enc(0x9002, Unused, Unused, Unused, None),
+        Jalr => enc(0x9002, Fixed(1), HI, Unused, None),
+        Add => enc(0x9002, HI, HI, LO, None),
+        Fsdsp => enc(0xa002, Unused, SP, LO, unsigned(SDSP_OFFSET)),
+        Swsp => enc(0xc002, Unused, SP, LO, unsigned(SWSP_OFFSET)),
+        Fswsp => enc(0xe002, Unused, SP, LO, unsigned(SWSP_OFFSET)),
+
+        Ld => enc(0x6000, LO_P, HI_P, Unused, unsigned(DOUBLE_OFFSET)),
+        Sd => enc(0xe000, Unused, HI_P, LO_P, unsigned(DOUBLE_OFFSET)),
+        Addiw => enc(0x2001, HI, HI, Unused, signed(IMM6)),
+        Subw => enc(0x9c01, HI_P, HI_P, LO_P, None),
+        Addw => enc(0x9c21, HI_P, HI_P, LO_P, None),
+        ZextW => enc(0x9c71, HI_P, HI_P, Unused, None),
+        Ldsp => enc(0x6002, HI, SP, Unused, unsigned(LDSP_OFFSET)),
+        Sdsp => enc(0xe002, Unused, SP, LO, unsigned(SDSP_OFFSET)),
+    }
+}
+
+fn get_imm(bits: u16, layout: ImmLayout, signed: bool) -> i32 {
+    let bits = u32::from(bits);
+    let mut res = 0u32;
+    let mut top = 0u8;
+    for (start, imm_bits) in layout {
+        for (i, imm_bit) in imm_bits.iter().enumerate() {
+            copy_bit(&bits, start - i as u8, &mut res, *imm_bit);
+            top = top.max(*imm_bit);
+        }
+    }
+    if signed && (res >> top) & 1 == 1 {
+        res |= !0 << top;
+    }
+    res as i32
+}
+
+fn set_imm(imm: i32, layout: ImmLayout) -> u16 {
+    let imm = imm as u32;
+    let mut res = 0u32;
+    for (start, imm_bits) in layout {
+        for (i, imm_bit) in imm_bits.iter().enumerate() {
+            copy_bit(&imm, *imm_bit, &mut res, start - i as u8);
+        }
+    }
+    res as u16
+}
+
+/// Identifies the operation of a compressed instruction for the given base ISA,
+/// rejecting illegal and reserved encodings.
+fn decode_op(bits: u16, xlen: Xlen) -> Option<CompressedOp> {
+    use CompressedOp::*;
+
+    let rv64 = xlen >= Xlen::Rv64;
+    let funct3 = bits >> 13;
+    let bit12 = (bits >> 12) & 1;
+    let rd = (bits >> 7) & 0b11111;
+    let rs2 = (bits >> 2) & 0b11111;
+    let imm6 = (bit12 << 5) | rs2;
+    // shift amounts above 31 are reserved in RV32
+    let shamt_ok = rv64 || bit12 == 0;
+
+    let op = match (bits & 0b11, funct3) {
+        (0b00, 0b000) if (bits >> 5) & 0xff != 0 => Addi4spn,
+        (0b00, 0b001) => Fld,
+        (0b00, 0b010) => Lw,
+        (0b00, 0b011) if rv64 => Ld,
+        (0b00, 0b011) => Flw,
+        (0b00, 0b100) => match ((bits >> 10) & 0b111, (bits >> 6) & 1) {
+            (0b000, _) => Lbu,
+            (0b001, 0) => Lhu,
+            (0b001, 1) => Lh,
+            (0b010, _) => Sb,
+            (0b011, 0) => Sh,
+            _ => return None,
+        },
+        (0b00, 0b101) => Fsd,
+        (0b00, 0b110) => Sw,
+        (0b00, 0b111) if rv64 => Sd,
+        (0b00, 0b111) => Fsw,
+
+        (0b01, 0b000) if rd == 0 => Nop,
+        (0b01, 0b000) => Addi,
+        (0b01, 0b001) if rv64 && rd == 0 => return None,
+        (0b01, 0b001) if rv64 => Addiw,
+        (0b01, 0b001) => Jal,
+        (0b01, 0b010) => Li,
+        (0b01, 0b011) if imm6 == 0 => return None,
+        (0b01, 0b011) if rd == 2 => Addi16sp,
+        (0b01, 0b011) => Lui,
+        (0b01, 0b100) => match ((bits >> 10) & 0b11, bit12, (bits >> 5) & 0b11) {
+            (0b00, _, _) if shamt_ok => Srli,
+            (0b01, _, _) if shamt_ok => Srai,
+            (0b10, _, _) => Andi,
+            (0b11, 0, 0b00) => Sub,
+            (0b11, 0, 0b01) => Xor,
+            (0b11, 0, 0b10) => Or,
+            (0b11, 0, 0b11) => And,
+            (0b11, 1, 0b00) if rv64 => Subw,
+            (0b11, 1, 0b01) if rv64 => Addw,
+            (0b11, 1, 0b10) => Mul,
+            (0b11, 1, 0b11) => match (bits >> 2) & 0b111 {
+                0b000 => ZextB,
+                0b001 => SextB,
+                0b010 => ZextH,
+                0b011 => SextH,
+                0b100 if rv64 => ZextW,
+                0b101 => Not,
+                _ => return None,
+            },
+            _ => return None,
+        },
+        (0b01, 0b101) => J,
+        (0b01, 0b110) => Beqz,
+        (0b01, 0b111) => Bnez,
+
+        (0b10, 0b000) if shamt_ok => Slli,
+        (0b10, 0b001) => Fldsp,
+        (0b10, 0b010) if rd != 0 => Lwsp,
+        (0b10, 0b011) if rv64 && rd != 0 => Ldsp,
+        (0b10, 0b011) if !rv64 => Flwsp,
+        (0b10, 0b100) => match (bit12, rd, rs2) {
+            (0, 0, 0) => return None,
+            (0, _, 0) => Jr,
+            (0, _, _) => Mv,
+            (1, 0, 0) => Ebreak,
+            (1, _, 0) => Jalr,
+            _ => Add,
+        },
+        (0b10, 0b101) => Fsdsp,
+        (0b10, 0b110) => Swsp,
+        (0b10, 0b111) if rv64 => Sdsp,
+        (0b10, 0b111) => Fswsp,
+
+        _ => return None,
+    };
+
+    Some(op)
+}
+
+/// Order in which the operands of a compressed instruction are written in assembly.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum CompressedOperands {
+    Empty,
+    Rd,
+    Rs1,
+    RdRs2,
+    RdImm,
+    RdRs1Imm,
+    Rs1Imm,
+    Imm,
+    Load,
+    Store,
+}
+
+/// A 16-bit instruction of the C extension (RVC).
+///
+/// Register accessors return the operands of the equivalent 32-bit instruction,
+/// including the ones implied by the operation (e.g. `sp` for `c.lwsp`, or `ra` as the
+/// destination of `c.jal`). The immediate is returned as its actual value (an offset,
+/// a shift amount or a sign-extended constant), regardless of how its bits are scattered
+/// in the encoding.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct CInstruction {
+    op: CompressedOp,
+    opcode: Opcode,
+    rd: Register,
+    rs1: Register,
+    rs2: Register,
+    imm: i32,
+}
+
+impl CInstruction {
+    /// Creates a compressed instruction, verifying that the operands can be encoded
+    /// by the operation (register ranges, implied registers, immediate range and alignment).
+    /// Operands not used by the operation are ignored.
+    ///
+    /// The instruction is created for the narrowest base ISA supporting the operation
+    /// (so e.g. shift amounts must be below 32, unless the operation is RV64-only).
+    pub fn new(
+        op: CompressedOp,
+        rd: Register,
+        rs1: Register,
+        rs2: Register,
+        imm: i32,
+    ) -> Result<Self, RISCVError> {
+        let xlen = if op.supports(Xlen::Rv32) {
+            Xlen::Rv32
+        } else {
+            Xlen::Rv64
+        };
+        Self::with_xlen(op, rd, rs1, rs2, imm, xlen)
+    }
+
+    fn with_xlen(
+        op: CompressedOp,
+        rd: Register,
+        rs1: Register,
+        rs2: Register,
+        imm: i32,
+        xlen: Xlen,
+    ) -> Result<Self, RISCVError> {
+        let enc = encoding(op);
+        let zero = Register::zero();
+        let expected = Self {
+            op,
+            opcode: Opcode::try_from(op.base_opcode(xlen))?,
+            rd: if enc.rd == RegField::Unused { zero } else { rd },
+            rs1: if enc.rs1 == RegField::Unused {
+                zero
+            } else {
+                rs1
+            },
+            rs2: if enc.rs2 == RegField::Unused {
+                zero
+            } else {
+                rs2
+            },
+            imm: if enc.imm.is_none() { 0 } else { imm },
+        };
+
+        let bits = expected.encode();
+        match Self::decode(bits, xlen) {
+            Ok(instr) if instr == expected => Ok(instr),
+            _ => Err(RISCVError::InvalidCompressedOperands),
+        }
+    }
+
+    /// Decodes a compressed instruction for the given base ISA. The same encoding may
+    /// mean different operations (e.g. `c.flw` in RV32 and `c.ld` in RV64).
+    pub fn decode(bits: u16, xlen: Xlen) -> Result<Self, RISCVError> {
+        let op = decode_op(bits, xlen).ok_or(RISCVError::IllegalCompressedInstruction)?;
+        let enc = encoding(op);
+
+        Ok(Self {
+            op,
+            opcode: Opcode::try_from(op.base_opcode(xlen))?,
+            rd: enc.rd.read(bits),
+            rs1: enc.rs1.read(bits),
+            rs2: enc.rs2.read(bits),
+            imm: enc
+                .imm
+                .map_or(0, |(layout, signed)| get_imm(bits, layout, signed)),
+        })
+    }
+
+    pub fn op(&self) -> CompressedOp {
+        self.op
+    }
+
+    pub fn rd(&self) -> Option<Register> {
+        self.operand(encoding(self.op).rd, self.rd)
+    }
+
+    pub fn rs1(&self) -> Option<Register> {
+        self.operand(encoding(self.op).rs1, self.rs1)
+    }
+
+    pub fn rs2(&self) -> Option<Register> {
+        self.operand(encoding(self.op).rs2, self.rs2)
+    }
+
+    pub fn imm(&self) -> Option<i32> {
+        encoding(self.op).imm.map(|_| self.imm)
+    }
+
+    /// Checks whether the instruction is a HINT, i.e. an encoding that doesn't change
+    /// the architectural state (like `c.addi a0, 0`) and is reserved for future use.
+    pub fn is_hint(&self) -> bool {
+        use CompressedOp::*;
+        let rd = u8::from(self.rd);
+        match self.op {
+            Nop => self.imm != 0,
+            Addi | Srli | Srai => self.imm == 0,
+            Li | Lui | Mv | Add => rd == 0,
+            Slli => rd == 0 || self.imm == 0,
+            _ => false,
+        }
+    }
+
+    /// Returns the 32-bit instruction the compressed instruction is defined to be
+    /// equivalent to, e.g. `addi a0, a0, 4` for `c.addi a0, 4`.
+    pub fn expand(&self) -> Instruction {
+        match self.try_expand() {
+            Ok(instr) => instr,
+            Err(e) => unreachable!("A valid compressed instruction always expands: {}", e),
+        }
+    }
+
+    fn try_expand(&self) -> Result<Instruction, RISCVError> {
+        use CompressedOp::*;
+        let imm = match self.op {
+            ZextB => 0xff,
+            SextB => 4,
+            SextH => 5,
+            Not => -1,
+            Ebreak => 1,
+            _ => self.imm,
+        };
+
+        InstructionBuilder::new()
+            .set_opcode(self.opcode)
+            .set_funct3(Funct3::try_from(self.op.base_funct3())?)
+            .set_funct7(Funct7::try_from(self.op.base_funct7())?)
+            .set_rd(self.rd)
+            .set_rs1(self.rs1)
+            .set_rs2(self.rs2)
+            .set_immediate(imm)
+            .build()
+    }
+
+    /// Finds the compressed encoding of a 32-bit instruction for the given base ISA.
+    /// HINT encodings are never returned, and when more than one operation fits,
+    /// the first one in the encoding order is chosen.
+    pub(crate) fn compress(instr: &Instruction, xlen: Xlen) -> Option<Self> {
+        let zero = Register::zero();
+        let opcode = u8::from(*instr.opcode());
+        let rd = instr.rd().unwrap_or(zero);
+        let rs1 = instr.rs1().unwrap_or(zero);
+        let rs2 = instr.rs2().unwrap_or(zero);
+        let imm = instr.immediate().unwrap_or(0);
+
+        CompressedOp::ALL
+            .iter()
+            .filter(|op| op.supports(xlen) && op.base_opcode(xlen) == opcode)
+            .filter_map(|op| CInstruction::with_xlen(*op, rd, rs1, rs2, imm, xlen).ok())
+            .find(|c| !c.is_hint() && c.expand() == *instr)
+    }
+
+    fn operand(&self, field: RegField, reg: Register) -> Option<Register> {
+        (field != RegField::Unused).then_some(reg)
+    }
+
+    pub(crate) fn operands_layout(&self) -> CompressedOperands {
+        use CompressedOp::*;
+        use CompressedOperands::*;
+        match self.op {
+            Nop | Ebreak => Empty,
+            ZextB | SextB | ZextH | SextH | Not | ZextW => Rd,
+            Jr | Jalr => Rs1,
+            Sub | Xor | Or | And | Mul | Mv | Add | Subw | Addw => RdRs2,
+            Addi | Li | Addi16sp | Lui | Srli | Srai | Andi | Slli | Addiw => RdImm,
+            Addi4spn => RdRs1Imm,
+            Beqz | Bnez => Rs1Imm,
+            J | Jal => Imm,
+            Fld | Lw | Flw | Lbu | Lhu | Lh | Fldsp | Lwsp | Flwsp | Ld | Ldsp => Load,
+            Fsd | Sw | Fsw | Sb | Sh | Fsdsp | Swsp | Fswsp | Sd | Sdsp => Store,
+        }
+    }
+
+    fn encode(&self) -> u16 {
+        let enc = encoding(self.op);
+        enc.bits
+            | enc.rd.write(self.rd)
+            | enc.rs1.write(self.rs1)
+            | enc.rs2.write(self.rs2)
+            | enc.imm.map_or(0, |(layout, _)| set_imm(self.imm, layout))
+    }
+}
+
+impl InstructionTrait for CInstruction {
+    /// Returns the major opcode of the equivalent 32-bit instruction
+    fn opcode(&self) -> &Opcode {
+        &self.opcode
+    }
+
+    fn format(&self) -> &InstructionFormat {
+        self.op.format()
+    }
+
+    fn mnemonic(&self) -> Option<Mnemonic> {
+        get_compressed_mnemonic(self.op)
+    }
+
+    fn immediate_bits(&self) -> u32 {
+        encoding(self.op)
+            .imm
+            .map_or(0, |(layout, _)| set_imm(self.imm, layout).into())
+    }
+}
+
+/// Decodes an RV32 compressed instruction, see [`CInstruction::decode`] for other base ISAs.
+impl TryFrom<u16> for CInstruction {
+    type Error = RISCVError;
+
+    fn try_from(bits: u16) -> Result<Self, Self::Error> {
+        CInstruction::decode(bits, Xlen::Rv32)
+    }
+}
+
+impl TryFrom<u32> for CInstruction {
+    type Error = RISCVError;
+
+    fn try_from(instr: u32) -> Result<Self, Self::Error> {
+        let bits = u16::try_from(instr).map_err(|_| RISCVError::UnrecognizedInstructionSize)?;
+        CInstruction::try_from(bits)
+    }
+}
+
+impl From<CInstruction> for u16 {
+    fn from(instr: CInstruction) -> Self {
+        instr.encode()
+    }
+}
+
+impl From<&CInstruction> for u16 {
+    fn from(instr: &CInstruction) -> Self {
+        instr.encode()
+    }
+}
+
+impl From<CInstruction> for u32 {
+    fn from(instr: CInstruction) -> Self {
+        instr.encode().into()
+    }
+}
+
+impl From<&CInstruction> for u32 {
+    fn from(instr: &CInstruction) -> Self {
+        instr.encode().into()
+    }
+}
+
+impl fmt::Display for CInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use CompressedOperands::*;
+
+        write!(f, "{}", self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()))?;
+        let [rd_class, rs1_class, rs2_class, _] = self.expand().register_classes();
+        let name = |r: Register, class: Option<RegisterClass>| {
+            register_name(r, class.unwrap_or(RegisterClass::Integer))
+        };
+        let (rd, rs1, rs2, imm) = (
+            name(self.rd, rd_class),
+            name(self.rs1, rs1_class),
+            name(self.rs2, rs2_class),
+            self.imm,
+        );
+        match self.operands_layout() {
+            Empty => Ok(()),
+            Rd => write!(f, " {}", rd),
+            Rs1 => write!(f, " {}", rs1),
+            RdRs2 => write!(f, " {}, {}", rd, rs2),
+            RdImm => write!(f, " {}, 0x{:x}", rd, imm),
+            RdRs1Imm => write!(f, " {}, {}, 0x{:x}", rd, rs1, imm),
+            Rs1Imm => write!(f, " {}, 0x{:x}", rs1, imm),
+            Imm => write!(f, " 0x{:x}", imm),
+            Load => write!(f, " {}, 0x{:x}({})", rd, imm, rs1),
+            Store => write!(f, " {}, 0x{:x}({})", rs2, imm, rs1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_instr(bits: u16, op: CompressedOp) {
+        let instr = CInstruction::try_from(bits).unwrap();
+        assert_eq!(op, instr.op());
+        assert_eq!(bits, u16::from(instr));
+    }
+
+    #[test]
+    fn test_two_way_conversion() {
+        use CompressedOp::*;
+        assert_instr(0x0141, Addi); // c.addi sp, 16
+        assert_instr(0x4501, Li); // c.li a0, 0
+        assert_instr(0x0800, Addi4spn); // c.addi4spn s0, sp, 16
+        assert_instr(0x4188, Lw); // c.lw a0, 0(a1)
+        assert_instr(0xc188, Sw); // c.sw a0, 0(a1)
+        assert_instr(0x717d, Addi16sp); // c.addi16sp sp, -16
+        assert_instr(0x6505, Lui); // c.lui a0, 0x1
+        assert_instr(0x8082, Jr); // c.jr ra
+        assert_instr(0x852e, Mv); // c.mv a0, a1
+        assert_instr(0x9002, Ebreak); // c.ebreak
+        assert_instr(0xa001, J); // c.j 0
+        assert_instr(0xc111, Beqz); // c.beqz a0, 4
+        assert_instr(0x40b2, Lwsp); // c.lwsp ra, 12(sp)
+        assert_instr(0xc606, Swsp); // c.swsp ra, 12(sp)
+        assert_instr(0x0001, Nop); // c.nop
+    }
+
+    #[test]
+    fn test_operands() -> Result<(), RISCVError> {
+        let instr = CInstruction::try_from(0x40b2u16)?; // c.lwsp ra, 12(sp)
+        assert_eq!(Some(Register::ra()), instr.rd());
+        assert_eq!(Some(Register::sp()), instr.rs1());
+        assert_eq!(None, instr.rs2());
+        assert_eq!(Some(12), instr.imm());
+
+        let instr = CInstruction::try_from(0x717du16)?; // c.addi16sp sp, -16
+        assert_eq!(Some(-16), instr.imm());
+
+        let instr = CInstruction::try_from(0xfffdu16)?; // c.bnez a5, -2
+        assert_eq!(Some(-2), instr.imm());
+        Ok(())
+    }
+
+    #[test]
+    fn test_illegal_instructions() {
+        assert!(CInstruction::try_from(0x0000u16).is_err());
+        assert!(CInstruction::try_from(0x8002u16).is_err()); // c.jr zero
+        assert!(CInstruction::try_from(0x4002u16).is_err()); // c.lwsp zero
+        assert!(CInstruction::try_from(0x0033u16).is_err()); // not compressed
+    }
+
+    fn assert_expansion(bits: u16, expanded: u32) {
+        let instr = CInstruction::try_from(bits).unwrap();
+        assert_eq!(expanded, u32::from(instr.expand()));
+        let base = Instruction::try_from(expanded).unwrap();
+        assert_eq!(Some(instr), CInstruction::compress(&base, Xlen::Rv32));
+    }
+
+    #[test]
+    fn test_expand() {
+        assert_expansion(0x0511, 0x00450513); // c.addi a0, 4 => addi a0, a0, 4
+        assert_expansion(0x40b2, 0x00c12083); // c.lwsp ra, 12(sp) => lw ra, 12(sp)
+        assert_expansion(0x8082, 0x00008067); // c.jr ra => jalr zero, 0(ra)
+        assert_expansion(0x852e, 0x00b00533); // c.mv a0, a1 => add a0, zero, a1
+        assert_expansion(0x8d0d, 0x40b50533); // c.sub a0, a1 => sub a0, a0, a1
+        assert_expansion(0x8505, 0x40155513); // c.srai a0, 1 => srai a0, a0, 1
+        assert_expansion(0xc111, 0x00050263); // c.beqz a0, 4 => beq a0, zero, 4
+        assert_expansion(0x9002, 0x00100073); // c.ebreak => ebreak
+        assert_expansion(0x9d75, 0xfff54513); // c.not a0 => xori a0, a0, -1
+    }
+
+    #[test]
+    fn test_rv64() -> Result<(), RISCVError> {
+        use CompressedOp::*;
+        let rv64 = |bits| CInstruction::decode(bits, Xlen::Rv64).map(|instr| instr.op());
+
+        assert_eq!(
+            Ok(Flw),
+            CInstruction::try_from(0x6188u16).map(|instr| instr.op())
+        );
+        assert_eq!(Ok(Ld), rv64(0x6188)); // c.ld a0, 0(a1)
+        assert_eq!(
+            Ok(Jal),
+            CInstruction::try_from(0x2505u16).map(|instr| instr.op())
+        );
+        assert_eq!(Ok(Addiw), rv64(0x2505)); // c.addiw a0, 1
+        assert_eq!(Ok(Sdsp), rv64(0xe406)); // c.sdsp ra, 8(sp)
+        assert_eq!(Ok(Subw), rv64(0x9d0d)); // c.subw a0, a1
+        assert_eq!(Ok(ZextW), rv64(0x9d71)); // c.zext.w a0
+
+        // shift amounts above 31 are only valid in RV64
+        assert!(CInstruction::try_from(0x1502u16).is_err());
+        assert_eq!(Ok(Slli), rv64(0x1502)); // c.slli a0, 32
+        assert!(CInstruction::try_from(0x9d0du16).is_err());
+
+        // c.zext.h expands to the OP-32 encoding of zext.h in RV64, i.e. packw
+        let zext_h = CInstruction::decode(0x9d69, Xlen::Rv64)?;
+        assert_eq!(0x0805453b, u32::from(zext_h.expand()));
+        let base = Instruction::decode(0x0805453b, Xlen::Rv64)?;
+        assert_eq!("packw a0, a0, zero", base.to_string());
+        assert_eq!(Some(zext_h), CInstruction::compress(&base, Xlen::Rv64));
+        let zext_h = CInstruction::decode(0x9d69, Xlen::Rv32)?;
+        assert_eq!(0x08054533, u32::from(zext_h.expand()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_every_encoding() {
+        for xlen in [Xlen::Rv32, Xlen::Rv64] {
+            for bits in 0..=u16::MAX {
+                if let Ok(instr) = CInstruction::decode(bits, xlen) {
+                    let expanded = instr.expand();
+                    match CInstruction::compress(&expanded, xlen) {
+                        Some(c) => assert_eq!(expanded, c.expand()),
+                        None => assert!(instr.is_hint(), "{:04x} can't be compressed back", bits),
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_incompressible() {
+        let add = Instruction::try_from(0x00628533).unwrap(); // add a0, t0, t1
+        assert_eq!(None, CInstruction::compress(&add, Xlen::Rv32));
+        let addi = Instruction::try_from(0x00050513).unwrap(); // addi a0, a0, 0
+        assert_eq!(None, CInstruction::compress(&addi, Xlen::Rv32));
+        let jal = Instruction::try_from(0x008000ef).unwrap(); // jal ra, 8
+        assert!(CInstruction::compress(&jal, Xlen::Rv32).is_some());
+        assert_eq!(None, CInstruction::compress(&jal, Xlen::Rv64));
+    }
+
+    #[test]
+    fn test_new() {
+        use CompressedOp::*;
+        let (zero, sp, a0, a1) = (
+            Register::zero(),
+            Register::sp(),
+            Register::a0(),
+            Register::a1(),
+        );
+
+        let instr = CInstruction::new(Lw, a0, a1, zero, 4).unwrap();
+        assert_eq!(0x41c8, u16::from(instr));
+
+        assert!(CInstruction::new(Lw, a0, a1, zero, 2).is_err()); // misaligned
+        assert!(CInstruction::new(Lw, a0, a1, zero, 128).is_err()); // out of range
+        assert!(CInstruction::new(Lw, Register::ra(), a1, zero, 4).is_err()); // not x8-x15
+        assert!(CInstruction::new(Lwsp, a0, a1, zero, 4).is_err()); // rs1 must be sp
+        assert!(CInstruction::new(Lwsp, a0, sp, zero, 4).is_ok());
+        assert!(CInstruction::new(Addi, a0, a1, zero, 4).is_err()); // rd must be rs1
+    }
+
+    #[test]
+    fn test_float_registers() {
+        use crate::disasm::{DisasmConfig, InstructionFormatter};
+
+        let formatter = InstructionFormatter::new(DisasmConfig::default());
+        for (bits, expected) in [
+            (0x2188, "c.fld fa0, 0x0(a1)"),
+            (0xa188, "c.fsd fa0, 0x0(a1)"),
+            (0x6188, "c.flw fa0, 0x0(a1)"),
+            (0xe188, "c.fsw fa0, 0x0(a1)"),
+            (0x2502, "c.fldsp fa0, 0x0(sp)"),
+            (0xa02a, "c.fsdsp fa0, 0x0(sp)"),
+            (0x6502, "c.flwsp fa0, 0x0(sp)"),
+            (0xe02a, "c.fswsp fa0, 0x0(sp)"),
+        ] {
+            let instr = CInstruction::try_from(bits as u16).unwrap();
+            assert_eq!(expected, instr.to_string());
+            assert_eq!(expected, formatter.instruction(&Instruction::C(instr)));
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use super::{Instruction, InstructionTrait, AMO_OPCODE};
+use crate::data::find_system_mnemonic;
+use crate::model::{Mnemonic, Register};
+
+const LOAD: u8 = 0b0000011;
+const LOAD_FP: u8 = 0b0000111;
+const STORE: u8 = 0b0100011;
+const STORE_FP: u8 = 0b0100111;
+const BRANCH: u8 = 0b1100011;
+const JALR: u8 = 0b1100111;
+const JAL: u8 = 0b1101111;
+
+/// System instructions which aren't available in user mode.
+const PRIVILEGED: [&str; 4] = ["mret", "sret", "wfi", "dret"];
+
+/// Classification of instructions by what they do, e.g. to split code into basic blocks.
+/// Compressed instructions are classified as their 32-bit equivalents.
+impl Instruction {
+    /// Checks whether it's a conditional branch (`beq`, `bne`, ..., `c.beqz`, `c.bnez`).
+    pub fn is_branch(&self) -> bool {
+        self.major_opcode() == BRANCH
+    }
+
+    /// Checks whether it's `jal` or `jalr`, including calls, returns and their
+    /// compressed forms.
+    pub fn is_unconditional_jump(&self) -> bool {
+        matches!(self.major_opcode(), JAL | JALR)
+    }
+
+    /// Checks whether it's a call: a jump linking the return address to `ra` or `t0`,
+    /// as the return address stack hints of the ISA.
+    pub fn is_call(&self) -> bool {
+        let instr = self.expand();
+        instr.is_unconditional_jump() && instr.rd().is_some_and(is_link)
+    }
+
+    /// Checks whether it's a return: `jalr` to the address held in `ra` or `t0`.
+    /// A jump swapping the two link registers (a coroutine switch) is both a return
+    /// and a call.
+    pub fn is_return(&self) -> bool {
+        let instr = self.expand();
+        instr.major_opcode() == JALR
+            && instr.rs1().is_some_and(is_link)
+            && instr.rd() != instr.rs1()
+    }
+
+    /// Checks whether the instruction reads memory: loads (including floating-point
+    /// and vector ones), `lr` and AMOs.
+    pub fn is_load(&self) -> bool {
+        match self.expand() {
+            Instruction::Amo(i) => !i.mnemonic().is_some_and(is_store_conditional),
+            instr => matches!(instr.major_opcode(), LOAD | LOAD_FP),
+        }
+    }
+
+    /// Checks whether the instruction writes memory: stores (including floating-point
+    /// and vector ones), `sc` and AMOs.
+    pub fn is_store(&self) -> bool {
+        match self.expand() {
+            Instruction::Amo(i) => !i.is_load_reserved(),
+            instr => matches!(instr.major_opcode(), STORE | STORE_FP),
+        }
+    }
+
+    /// Returns the number of bytes a scalar load, store or atomic instruction accesses.
+    /// The accesses of vector instructions depend on `vtype` and `vl`, so they don't
+    /// have a fixed width.
+    pub fn memory_access_width(&self) -> Option<u8> {
+        let instr = self.expand();
+        let funct3 = u8::from(instr.funct3()?);
+        match (&instr, instr.major_opcode()) {
+            (Instruction::V(_), _) => None,
+            // lbu, lhu and lwu set the highest bit
+            (_, LOAD) => Some(1 << (funct3 & 0b11)),
+            // the width of floating-point accesses starts from halfwords (`flh`)
+            (_, LOAD_FP | STORE | STORE_FP | AMO_OPCODE) => Some(1 << funct3),
+            _ => None,
+        }
+    }
+
+    /// Checks whether it's an atomic memory operation: `lr`, `sc`, an AMO (including
+    /// `amocas`) or `ssamoswap`.
+    pub fn is_atomic(&self) -> bool {
+        matches!(self, Instruction::Amo(_))
+    }
+
+    /// Checks whether it's `fence` (including its `pause` hint), `fence.tso` or `fence.i`.
+    pub fn is_fence(&self) -> bool {
+        matches!(self, Instruction::I(i) if i.is_fence())
+    }
+
+    /// Checks whether the instruction can't be executed in user mode: `mret`, `sret`,
+    /// `wfi` and `dret`, or an access to a CSR above the user level.
+    pub fn is_privileged(&self) -> bool {
+        let instr = self.expand();
+        if let Some(csr) = instr.csr() {
+            return csr.privilege() > 0;
+        }
+        self.system_mnemonic()
+            .is_some_and(|name| PRIVILEGED.contains(&name))
+    }
+
+    /// Checks whether executing the instruction may raise an exception: memory accesses
+    /// (misaligned or faulting), `ecall` and `ebreak`, privileged instructions, CSR
+    /// accesses (to CSRs that are read-only or not implemented) and unknown encodings.
+    /// Misaligned jump targets, which only trap without the C extension, aren't considered.
+    pub fn may_trap(&self) -> bool {
+        self.mnemonic().is_none()
+            || self.is_load()
+            || self.is_store()
+            || self.csr().is_some()
+            || self.system_mnemonic().is_some()
+    }
+
+    /// Returns the major opcode of the instruction, or of its 32-bit equivalent.
+    fn major_opcode(&self) -> u8 {
+        u8::from(*self.expand().opcode())
+    }
+
+    /// Returns the name of an instruction without operands of the SYSTEM opcode
+    /// (e.g. `ecall` or `mret`).
+    fn system_mnemonic(&self) -> Option<&'static str> {
+        match self.expand() {
+            Instruction::I(i) => find_system_mnemonic(i.into()).map(<&str>::from),
+            _ => None,
+        }
+    }
+}
+
+/// Checks whether the register is one of the link registers (`ra` and `t0`).
+fn is_link(reg: Register) -> bool {
+    reg == Register::ra() || reg == Register::t0()
+}
+
+fn is_store_conditional(mnemonic: Mnemonic) -> bool {
+    <&str>::from(mnemonic).starts_with("sc_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{INSTRUCTIONS, SYSTEM_INSTRUCTIONS};
+    use crate::error::RISCVError;
+    use crate::model::Xlen;
+
+    /// Returns the number of bytes accessed by a load, store or atomic instruction,
+    /// according to the suffix of its name (e.g. `lhu` or `amoadd_w`).
+    fn width_of(name: &str) -> u8 {
+        let name = name.strip_suffix('u').unwrap_or(name);
+        match name.chars().last() {
+            Some('b') => 1,
+            Some('h') => 2,
+            Some('w') => 4,
+            Some('d') => 8,
+            Some('q') => 16,
+            _ => panic!("unknown width of {}", name),
+        }
+    }
+
+    #[test]
+    fn test_instruction_table() {
+        for (key, (name, _, _)) in INSTRUCTIONS.iter() {
+            let opcode = 0b11 | u32::from(key & 0b11111) << 2;
+            let funct3 = u32::from((key >> 5) & 0b111) << 12;
+            let funct7 = u32::from(key >> 8) << 25;
+            // rd = a0, rs1 = a1, and rs2 = a2 unless it's part of the operation
+            let rs2 = if name.starts_with("lr_") || name.starts_with("zext") {
+                0
+            } else {
+                12 << 20
+            };
+            let bits = opcode | funct3 | funct7 | rs2 | 11 << 15 | 10 << 7;
+            let instr = Instruction::decode(bits, Xlen::Rv64)
+                .unwrap_or_else(|e| panic!("{} ({:08x}): {:?}", name, bits, e));
+
+            let atomic = ["lr_", "sc_", "amo", "ssamoswap"]
+                .iter()
+                .any(|prefix| name.starts_with(prefix));
+            let load = ["lb", "lh", "lw", "ld", "lbu", "lhu", "lwu"].contains(name)
+                || (atomic && !name.starts_with("sc_"));
+            let store =
+                ["sb", "sh", "sw", "sd"].contains(name) || (atomic && !name.starts_with("lr_"));
+            let branch = ["beq", "bne", "blt", "bge", "bltu", "bgeu"].contains(name);
+            let jump = ["jal", "jalr"].contains(name);
+            let csr = name.starts_with("csrr");
+
+            assert_eq!(branch, instr.is_branch(), "{}", name);
+            assert_eq!(jump, instr.is_unconditional_jump(), "{}", name);
+            assert_eq!(load, instr.is_load(), "{}", name);
+            assert_eq!(store, instr.is_store(), "{}", name);
+            assert_eq!(atomic, instr.is_atomic(), "{}", name);
+            assert_eq!(name.starts_with("fence"), instr.is_fence(), "{}", name);
+            assert_eq!(load || store || csr, instr.may_trap(), "{}", name);
+            // the CSR at address 0 is a user-level one
+            assert!(!instr.is_privileged(), "{}", name);
+            assert!(!instr.is_call() && !instr.is_return(), "{}", name);
+            assert_eq!(
+                (load || store).then(|| width_of(name)),
+                instr.memory_access_width(),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_system_table() -> Result<(), RISCVError> {
+        for (bits, (name, _, _)) in SYSTEM_INSTRUCTIONS.iter() {
+            let instr = Instruction::try_from(*bits)?;
+            assert_eq!(PRIVILEGED.contains(name), instr.is_privileged(), "{}", name);
+            assert!(instr.may_trap(), "{}", name);
+            assert!(!instr.is_load() && !instr.is_store(), "{}", name);
+            assert!(
+                !instr.is_unconditional_jump() && !instr.is_fence(),
+                "{}",
+                name
+            );
+        }
+
+        let csrr_mstatus = Instruction::try_from(0x30002573)?;
+        assert!(csrr_mstatus.is_privileged());
+        let rdcycle = Instruction::try_from(0xc0002573)?;
+        assert!(!rdcycle.is_privileged());
+        assert!(rdcycle.may_trap());
+        Ok(())
+    }
+
+    #[test]
+    fn test_control_flow() -> Result<(), RISCVError> {
+        let decode = |bits: u32| Instruction::try_from(bits);
+        let c_decode = |bytes: [u8; 2]| Instruction::try_from_compressed_le_bytes(bytes);
+
+        assert!(decode(0x010000ef)?.is_call()); // jal ra, 0x10
+        assert!(decode(0x000582e7)?.is_call()); // jalr t0, a1
+        assert!(!decode(0x0100006f)?.is_call()); // j 0x10
+        assert!(decode(0x00008067)?.is_return()); // ret
+        assert!(decode(0x00028067)?.is_return()); // jr t0
+        assert!(!decode(0x00058067)?.is_return()); // jr a1
+
+        // jalr ra, t0 swaps the link registers
+        let swap = decode(0x000280e7)?;
+        assert!(swap.is_call() && swap.is_return());
+
+        let c_jal = c_decode([0x41, 0x20])?; // c.jal 0x10
+        assert!(c_jal.is_call() && c_jal.is_unconditional_jump());
+        let c_jr = c_decode([0x82, 0x80])?; // c.jr ra
+        assert!(c_jr.is_return());
+        let c_beqz = c_decode([0x01, 0xc1])?; // c.beqz a0, 0
+        assert!(c_beqz.is_branch());
+        let c_ebreak = c_decode([0x02, 0x90])?;
+        assert!(c_ebreak.may_trap() && !c_ebreak.is_privileged());
+        Ok(())
+    }
+
+    #[test]
+    fn test_memory_accesses() -> Result<(), RISCVError> {
+        let fld = Instruction::try_from(0x00853507)?; // fld fa0, 8(a0)
+        assert!(fld.is_load() && !fld.is_store());
+        assert_eq!(Some(8), fld.memory_access_width());
+
+        let c_swsp = Instruction::try_from_compressed_le_bytes([0x2a, 0xc6])?; // c.swsp a0, 12(sp)
+        assert!(c_swsp.is_store());
+        assert_eq!(Some(4), c_swsp.memory_access_width());
+
+        let vle = Instruction::try_from(0x02056087)?; // vle32.v v1, (a0)
+        assert!(vle.is_load() && vle.may_trap());
+        assert_eq!(None, vle.memory_access_width());
+
+        let add = Instruction::try_from(0x00b50533)?;
+        assert!(!add.may_trap());
+        assert_eq!(None, add.memory_access_width());
+
+        let fence = Instruction::try_from(0x0ff0000f)?;
+        assert!(fence.is_fence() && !fence.may_trap());
+        Ok(())
+    }
+}
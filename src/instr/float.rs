@@ -0,0 +1,100 @@
+use crate::data::find_float_instruction;
+use crate::model::{FRegister, Funct3, Opcode, Register, RegisterClass, RoundingMode};
+
+/// Constants loaded by `fli` (Zfa), selected by the rs1 field.
+const FLI_CONSTANTS: [&str; 32] = [
+    "-1.0",
+    "min",
+    "1.52587890625e-05",
+    "3.0517578125e-05",
+    "0.00390625",
+    "0.0078125",
+    "0.0625",
+    "0.125",
+    "0.25",
+    "0.3125",
+    "0.375",
+    "0.4375",
+    "0.5",
+    "0.625",
+    "0.75",
+    "0.875",
+    "1.0",
+    "1.25",
+    "1.5",
+    "1.75",
+    "2.0",
+    "2.5",
+    "3.0",
+    "4.0",
+    "8.0",
+    "16.0",
+    "128.0",
+    "256.0",
+    "32768.0",
+    "65536.0",
+    "inf",
+    "nan",
+];
+
+/// Returns the register classes of rd, rs1, rs2 and rs3 (`None` if the field isn't
+/// a register operand). Floating-point instructions mix both register files, while
+/// other instructions only use the integer registers.
+pub(crate) fn register_classes(opcode: Opcode, instr: u32) -> [Option<RegisterClass>; 4] {
+    if !opcode.is_float() {
+        return [Some(RegisterClass::Integer); 4];
+    }
+    find_float_instruction(instr).map_or([Some(RegisterClass::Float); 4], |(_, classes, _)| classes)
+}
+
+/// Checks whether it's a floating-point operation with a rounding mode field (which
+/// may hold a reserved rounding mode).
+pub(crate) fn has_rounding_mode(opcode: Opcode, instr: u32) -> bool {
+    opcode.is_float() && find_float_instruction(instr).is_some_and(|(_, _, rm)| rm)
+}
+
+/// Returns the rounding mode of floating-point operations which have it, `None` for
+/// other instructions or a reserved rounding mode.
+pub(crate) fn rounding_mode(opcode: Opcode, instr: u32) -> Option<RoundingMode> {
+    has_rounding_mode(opcode, instr)
+        .then(|| RoundingMode::try_from(Funct3::from(instr)).ok())
+        .flatten()
+}
+
+/// Returns the rounding mode operand of floating-point operations: the name of a
+/// static rounding mode, `None` for the dynamic one, or the raw field of a reserved
+/// one (e.g. `rm=5`), so the instruction isn't mistaken for a valid one.
+pub(crate) fn rounding_mode_operand(opcode: Opcode, instr: u32) -> Option<String> {
+    if !has_rounding_mode(opcode, instr) {
+        return None;
+    }
+    let funct3 = Funct3::from(instr);
+    match RoundingMode::try_from(funct3) {
+        Ok(RoundingMode::Dyn) => None,
+        Ok(rm) => Some(rm.to_string()),
+        Err(_) => Some(format!("rm={}", u8::from(funct3))),
+    }
+}
+
+/// Returns the constant loaded by `fli`, `None` for other instructions.
+pub(crate) fn fli_constant(opcode: Opcode, instr: u32) -> Option<&'static str> {
+    if !opcode.is_float() {
+        return None;
+    }
+    find_float_instruction(instr)
+        .filter(|(mnemonic, _, _)| mnemonic.to_string().starts_with("fli."))
+        .map(|_| FLI_CONSTANTS[usize::from(u8::from(Register::from_rs1_bits(instr)))])
+}
+
+/// Returns the floating-point register encoded in the same field as `reg`.
+pub(crate) fn as_fregister(reg: Register) -> FRegister {
+    FRegister::from_rd_bits(reg.into_rd_bits())
+}
+
+/// Formats the register as an operand of the given class.
+pub(crate) fn register_name(reg: Register, class: RegisterClass) -> String {
+    match class {
+        RegisterClass::Integer => reg.to_string(),
+        RegisterClass::Float => as_fregister(reg).to_string(),
+    }
+}
@@ -1,13 +1,53 @@
 use crate::{
     config::UNKNOWN_MNEMONIC,
-    data::{find_system_mnemonic, get_mnemonic},
+    data::{find_float_instruction, find_system_mnemonic, get_mnemonic},
     error::RISCVError,
-    model::{Funct3, Immediate, InstructionFormat, Mnemonic, Opcode, RawBitsConverter, Register},
+    model::{
+        Csr, Funct3, Funct7, Immediate, InstructionFormat, Mnemonic, Opcode, RawBitsConverter,
+        Register,
+    },
 };
 use std::fmt;
 
+use super::float::{register_classes, register_name};
 use super::InstructionTrait;
 
+const OP_IMM: u8 = 0b0010011;
+const OP_IMM_32: u8 = 0b0011011;
+const SYSTEM: u8 = 0b1110011;
+const MISC_MEM: u8 = 0b0001111;
+
+/// Bits of the predecessor and successor sets of `fence`, in the order they're written.
+const FENCE_SET: [(u8, char); 4] = [(0b1000, 'i'), (0b0100, 'o'), (0b0010, 'r'), (0b0001, 'w')];
+
+/// Formats a predecessor or successor set of `fence`, e.g. `iorw` or `rw` (`0` if empty).
+pub(crate) fn fence_set_name(set: u8) -> String {
+    if set == 0 {
+        return "0".into();
+    }
+    FENCE_SET
+        .iter()
+        .filter(|(bit, _)| set & bit != 0)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+/// Returns the width of the shift amount, if the instruction uses the shift-immediate
+/// layout (the immediate split into a function code and shamt). This covers the base
+/// shifts as well as the Zba/Zbb/Zbs operations sharing their encoding (e.g. `rori`, `bexti`).
+///
+/// OP-IMM operations keep funct6 in the upper bits, as RV64 shifts by up to 63 bits.
+/// OP-IMM-32 operations work on words, so they have funct7 and a 5-bit shamt,
+/// except for `slli.uw`, which shifts the zero-extended word by up to 63 bits.
+fn shamt_width(opcode: Opcode, funct3: Funct3, funct7: u8) -> Option<u8> {
+    match (u8::from(opcode), u8::from(funct3)) {
+        (OP_IMM, 0b001 | 0b101) => Some(6),
+        (OP_IMM_32, 0b001) if funct7 >> 1 == 0b000010 => Some(6),
+        (OP_IMM_32, 0b001 | 0b101) => Some(5),
+        _ => None,
+    }
+}
+
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub struct IInstruction {
     opcode: Opcode,
@@ -39,6 +79,59 @@ impl IInstruction {
         })
     }
 
+    /// Creates an instruction in the shift-immediate layout (e.g. `srai`, `rori`, `bclri`)
+    /// from the function code stored in the upper immediate bits and the shift amount.
+    /// For the operations with funct6, the lowest bit of `funct7` must be zero.
+    pub fn new_shift(
+        opcode: Opcode,
+        rs1: Register,
+        rd: Register,
+        funct3: Funct3,
+        funct7: Funct7,
+        shamt: u8,
+    ) -> Result<Self, RISCVError> {
+        let funct7 = u8::from(funct7);
+        let width = shamt_width(opcode, funct3, funct7).ok_or(RISCVError::UnexpectedShamt)?;
+
+        let max = (1u8 << width) - 1;
+        if shamt > max {
+            return Err(RISCVError::ImmediateOutOfRange(0, max.into()));
+        }
+        if (funct7 << 5) & max != 0 {
+            return Err(RISCVError::InvalidFunctValue(6));
+        }
+
+        let bits = (u32::from(funct7) << 5) | u32::from(shamt);
+        let imm = Immediate::<0, 11>::try_from_raw_bits(bits)?;
+        Self::new(opcode, rs1, rd, funct3, imm)
+    }
+
+    /// Creates a Zicsr instruction (e.g. `csrrs`, `csrrwi`). The `rs1` field holds
+    /// the source register, or the 5-bit unsigned immediate of the `csrr*i` forms.
+    pub fn new_csr(
+        opcode: Opcode,
+        rs1: Register,
+        rd: Register,
+        funct3: Funct3,
+        csr: Csr,
+    ) -> Result<Self, RISCVError> {
+        if !Self::is_csr_access(opcode, funct3) {
+            return Err(RISCVError::UnexpectedCsr);
+        }
+        let imm = Immediate::<0, 11>::try_from_raw_bits(csr.into())?;
+        Self::new(opcode, rs1, rd, funct3, imm)
+    }
+
+    /// Checks whether the opcode and funct3 select a Zicsr instruction.
+    pub(crate) fn is_csr_access(opcode: Opcode, funct3: Funct3) -> bool {
+        u8::from(opcode) == SYSTEM && u8::from(funct3) & 0b11 != 0
+    }
+
+    /// Checks whether the opcode and funct3 select an operation in the shift-immediate layout.
+    pub(crate) fn has_shamt(opcode: Opcode, funct3: Funct3) -> bool {
+        shamt_width(opcode, funct3, 0).is_some()
+    }
+
     pub fn rs1(&self) -> Register {
         self.rs1
     }
@@ -54,6 +147,52 @@ impl IInstruction {
     pub fn imm(&self) -> Immediate<0, 11> {
         self.imm
     }
+
+    /// Returns the CSR accessed by a Zicsr instruction.
+    pub fn csr(&self) -> Option<Csr> {
+        Self::is_csr_access(self.opcode, self.funct3)
+            .then(|| Csr::from_csr_bits(self.immediate_bits()))
+    }
+
+    /// Returns the 5-bit unsigned immediate of `csrrwi`, `csrrsi` and `csrrci`,
+    /// encoded in place of rs1.
+    pub fn uimm(&self) -> Option<u8> {
+        (Self::is_csr_access(self.opcode, self.funct3) && u8::from(self.funct3) & 0b100 != 0)
+            .then(|| u8::from(self.rs1))
+    }
+
+    /// Checks whether it's `fence`, `fence.tso` or `fence.i` (the MISC-MEM opcode).
+    pub fn is_fence(&self) -> bool {
+        u8::from(self.opcode) == MISC_MEM
+    }
+
+    /// Returns the predecessor and successor sets of `fence` as bits of `i`, `o`, `r`
+    /// and `w` (from the highest). `fence.tso` and `fence.i` don't have them.
+    pub fn fence_sets(&self) -> Option<(u8, u8)> {
+        let bits = self.imm.into_raw_bits();
+        (self.is_fence() && u8::from(self.funct3) == 0 && !self.is_fence_tso())
+            .then_some((((bits >> 4) & 0xf) as u8, (bits & 0xf) as u8))
+    }
+
+    /// `fence.tso` is `fence rw, rw` with the fence mode (the top four bits) set to `0b1000`.
+    fn is_fence_tso(&self) -> bool {
+        self.is_fence() && u8::from(self.funct3) == 0 && self.imm.into_raw_bits() & 0xfff == 0x833
+    }
+
+    /// Returns the shift amount of an instruction in the shift-immediate layout.
+    pub fn shamt(&self) -> Option<u8> {
+        let bits = self.imm.into_raw_bits();
+        let width = shamt_width(self.opcode, self.funct3, (bits >> 5) as u8)?;
+        Some((bits & ((1 << width) - 1)) as u8)
+    }
+
+    /// Returns the function code of an instruction in the shift-immediate layout.
+    /// Funct6 is returned in place of funct7, with the lowest bit set to zero.
+    pub fn funct7(&self) -> Option<Funct7> {
+        let bits = self.imm.into_raw_bits();
+        let width = shamt_width(self.opcode, self.funct3, (bits >> 5) as u8)?;
+        Some(Funct7::from((bits >> width << width) << 20))
+    }
 }
 
 impl InstructionTrait for IInstruction {
@@ -66,7 +205,13 @@ impl InstructionTrait for IInstruction {
     }
 
     fn mnemonic(&self) -> Option<Mnemonic> {
-        get_mnemonic(self.opcode, Some(self.funct3), None)
+        if self.opcode.is_float() {
+            return find_float_instruction(self.into()).map(|(mnemonic, _, _)| mnemonic);
+        }
+        if self.is_fence_tso() {
+            return Some("fence_tso".into());
+        }
+        get_mnemonic(self.opcode, Some(self.funct3), self.funct7())
             .or_else(|| find_system_mnemonic(self.into()))
     }
 
@@ -117,17 +262,153 @@ impl From<&IInstruction> for u32 {
 
 impl fmt::Display for IInstruction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mnemonic = self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into());
         if let Some(m) = find_system_mnemonic(self.into()) {
             write!(f, "{}", m)
+        } else if let Some((pred, succ)) = self.fence_sets() {
+            let (pred, succ) = (fence_set_name(pred), fence_set_name(succ));
+            write!(f, "{} {}, {}", mnemonic, pred, succ)
+        } else if self.is_fence() {
+            write!(f, "{}", mnemonic)
+        } else if let (Some(csr), Some(uimm)) = (self.csr(), self.uimm()) {
+            write!(f, "{} {}, {}, 0x{:x}", mnemonic, self.rd, csr, uimm)
+        } else if let Some(csr) = self.csr() {
+            write!(f, "{} {}, {}, {}", mnemonic, self.rd, csr, self.rs1)
+        } else if let Some(shamt) = self.shamt() {
+            write!(f, "{} {}, {}, 0x{:x}", mnemonic, self.rd, self.rs1, shamt)
         } else {
+            let [rd, rs1, ..] = register_classes(self.opcode, self.into());
             write!(
                 f,
                 "{} {}, {}, {}",
-                self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()),
-                self.rd,
-                self.rs1,
+                mnemonic,
+                rd.map_or(self.rd.to_string(), |class| register_name(self.rd, class)),
+                rs1.map_or(self.rs1.to_string(), |class| register_name(self.rs1, class)),
                 self.imm
             )
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn assert_shift(bits: u32, mnemonic: &'static str, funct7: u8, shamt: u8) {
+        let instr = IInstruction::try_from(bits).unwrap();
+        assert_eq!(Some(mnemonic.into()), instr.mnemonic());
+        assert_eq!(Some(funct7), instr.funct7().map(u8::from));
+        assert_eq!(Some(shamt), instr.shamt());
+        assert_eq!(bits, u32::from(instr));
+    }
+
+    #[test]
+    fn test_shift_immediates() {
+        assert_shift(0x00355513, "srli", 0b0000000, 3); // srli a0, a0, 3
+        assert_shift(0x40355513, "srai", 0b0100000, 3); // srai a0, a0, 3
+        assert_shift(0x02851513, "slli", 0b0000000, 40); // slli a0, a0, 40
+        assert_shift(0x62855513, "rori", 0b0110000, 40); // rori a0, a0, 40
+        assert_shift(0x4855d513, "bexti", 0b0100100, 5); // bexti a0, a1, 5
+        assert_shift(0x4bf59513, "bclri", 0b0100100, 63); // bclri a0, a1, 63
+        assert_shift(0x4035551b, "sraiw", 0b0100000, 3); // sraiw a0, a0, 3
+        assert_shift(0x0a85151b, "slli_uw", 0b0000100, 40); // slli.uw a0, a0, 40
+
+        let srai = IInstruction::try_from(0x40355513).unwrap();
+        assert_eq!("srai a0, a0, 0x3", srai.to_string());
+
+        let addi = IInstruction::try_from(0x00450513).unwrap(); // addi a0, a0, 4
+        assert_eq!(None, addi.shamt());
+        assert_eq!(None, addi.funct7());
+    }
+
+    #[test]
+    fn test_fence() -> Result<(), RISCVError> {
+        let fence = IInstruction::try_from(0x0ff0000f)?;
+        assert!(fence.is_fence());
+        assert_eq!(Some((0b1111, 0b1111)), fence.fence_sets());
+        assert_eq!("fence iorw, iorw", fence.to_string());
+
+        let fence_rw_w = IInstruction::try_from(0x0310000f)?;
+        assert_eq!("fence rw, w", fence_rw_w.to_string());
+
+        let fence_tso = IInstruction::try_from(0x8330000f)?;
+        assert_eq!(Some("fence_tso".into()), fence_tso.mnemonic());
+        assert_eq!(None, fence_tso.fence_sets());
+        assert_eq!("fence.tso", fence_tso.to_string());
+
+        let fence_i = IInstruction::try_from(0x0000100f)?;
+        assert!(fence_i.is_fence());
+        assert_eq!(None, fence_i.fence_sets());
+        assert_eq!("fence.i", fence_i.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_shift() -> Result<(), RISCVError> {
+        let op_imm = Opcode::try_from(0b0010011u8)?;
+        let op_imm_32 = Opcode::try_from(0b0011011u8)?;
+        let (a0, a1) = (Register::a0(), Register::a1());
+        let sr = Funct3::try_from(0b101u8)?;
+        let srai = Funct7::try_from(0b0100000u8)?;
+
+        let instr = IInstruction::new_shift(op_imm, a0, a0, sr, srai, 3)?;
+        assert_eq!(0x40355513, u32::from(instr));
+
+        assert_eq!(
+            Err(RISCVError::ImmediateOutOfRange(0, 63)),
+            IInstruction::new_shift(op_imm, a0, a0, sr, srai, 64)
+        );
+        assert_eq!(
+            Err(RISCVError::ImmediateOutOfRange(0, 31)),
+            IInstruction::new_shift(op_imm_32, a0, a0, sr, srai, 32)
+        );
+        assert_eq!(
+            Err(RISCVError::InvalidFunctValue(6)),
+            IInstruction::new_shift(op_imm, a0, a0, sr, Funct7::try_from(1u8)?, 3)
+        );
+        assert_eq!(
+            Err(RISCVError::UnexpectedShamt),
+            IInstruction::new_shift(op_imm, a0, a1, Funct3::try_from(0u8)?, srai, 3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_csr() -> Result<(), RISCVError> {
+        let csrrs = IInstruction::try_from(0x30002573)?;
+        assert_eq!(Some(0x300), csrrs.csr().map(u16::from));
+        assert_eq!(None, csrrs.uimm());
+        assert_eq!("csrrs a0, mstatus, zero", csrrs.to_string());
+
+        let csrrwi = IInstruction::try_from(0x3052d073)?;
+        assert_eq!(Some(5), csrrwi.uimm());
+        assert_eq!("csrrwi zero, mtvec, 0x5", csrrwi.to_string());
+
+        let unnamed = IInstruction::try_from(0x7c0595f3)?;
+        assert_eq!("csrrw a1, 0x7c0, a1", unnamed.to_string());
+
+        let addi = IInstruction::try_from(0x00450513)?;
+        assert_eq!(None, addi.csr());
+
+        let system = Opcode::try_from(0b1110011u8)?;
+        let instr = IInstruction::new_csr(
+            system,
+            Register::zero(),
+            Register::a0(),
+            Funct3::try_from(0b010u8)?,
+            Csr::from_name("mstatus").unwrap(),
+        )?;
+        assert_eq!(0x30002573, u32::from(instr));
+        assert_eq!(
+            Err(RISCVError::UnexpectedCsr),
+            IInstruction::new_csr(
+                system,
+                Register::zero(),
+                Register::a0(),
+                Funct3::try_from(0b000u8)?,
+                Csr::from_name("mstatus").unwrap(),
+            )
+        );
+        Ok(())
+    }
+}
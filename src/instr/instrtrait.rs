@@ -1,6 +1,6 @@
 use crate::model::{InstructionFormat, Mnemonic, Opcode};
 
-pub trait InstructionTrait: Into<u32>  + TryFrom<u32> {
+pub trait InstructionTrait: Into<u32> + TryFrom<u32> {
     fn opcode(&self) -> &Opcode;
 
     fn format(&self) -> &InstructionFormat;
@@ -1,31 +1,122 @@
 use std::fmt;
 
+use super::float::{
+    as_fregister, has_rounding_mode, register_classes, rounding_mode, rounding_mode_operand,
+};
 use super::*;
 use crate::error::RISCVError;
-use crate::model::{Funct3, Funct7, InstructionFormat, Mnemonic, Opcode, Register};
+use crate::model::{
+    Csr, FRegister, Funct2, Funct3, Funct7, InstructionFormat, InstructionSize, Mnemonic, Opcode,
+    Register, RegisterClass, RoundingMode, Xlen,
+};
+
+const AUIPC_OPCODE: u8 = 0b0010111;
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub enum Instruction {
     R(RInstruction),
+    R4(R4Instruction),
+    Amo(AmoInstruction),
     I(IInstruction),
     S(SInstruction),
     B(BInstruction),
     U(UInstruction),
     J(JInstruction),
+    V(VInstruction),
+    C(CInstruction),
 }
 
 impl Instruction {
+    /// Decodes an instruction for the given base ISA. Encodings that aren't defined
+    /// for it (e.g. `ld` or `addiw` in RV32) are rejected.
+    pub fn decode(instr: u32, xlen: Xlen) -> Result<Self, RISCVError> {
+        use InstructionFormat::*;
+
+        if instr & 0b11 != 0b11 {
+            let bits = u16::try_from(instr).map_err(|_| RISCVError::UnrecognizedInstructionSize)?;
+            return Ok(Instruction::C(CInstruction::decode(bits, xlen)?));
+        }
+
+        if xlen < Xlen::Rv64 && requires_rv64(instr) {
+            return Err(RISCVError::UnsupportedInstruction(xlen));
+        }
+
+        if is_vector(instr) {
+            return Ok(Instruction::V(VInstruction::try_from(instr)?));
+        }
+
+        let instruction = match InstructionFormat::try_from(instr)? {
+            R if instr & 0x7f == AMO_OPCODE.into() => {
+                Instruction::Amo(AmoInstruction::try_from(instr)?)
+            }
+            R => Instruction::R(RInstruction::try_from(instr)?),
+            R4 => Instruction::R4(R4Instruction::try_from(instr)?),
+            I => Instruction::I(IInstruction::try_from(instr)?),
+            S => Instruction::S(SInstruction::try_from(instr)?),
+            B => Instruction::B(BInstruction::try_from(instr)?),
+            U => Instruction::U(UInstruction::try_from(instr)?),
+            J => Instruction::J(JInstruction::try_from(instr)?),
+            format => return Err(RISCVError::UnexpectedFormat(format)),
+        };
+
+        Ok(instruction)
+    }
+
     pub fn try_from_le_bytes(bytes: [u8; 4]) -> Result<Self, RISCVError> {
         Instruction::try_from(u32::from_le_bytes(bytes))
     }
 
+    pub fn try_from_compressed_le_bytes(bytes: [u8; 2]) -> Result<Self, RISCVError> {
+        CInstruction::try_from(u16::from_le_bytes(bytes)).map(Instruction::C)
+    }
+
+    /// Returns the size of the instruction's encoding.
+    pub fn size(&self) -> InstructionSize {
+        match self {
+            Instruction::C(_) => InstructionSize::Size16,
+            _ => InstructionSize::Size32,
+        }
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        matches!(self, Instruction::C(_))
+    }
+
+    /// Returns the 32-bit equivalent of a compressed instruction.
+    /// Other instructions are returned unchanged.
+    pub fn expand(&self) -> Instruction {
+        match self {
+            Instruction::C(instr) => instr.expand(),
+            instr => *instr,
+        }
+    }
+
+    /// Returns the compressed (16-bit) form of the instruction in the given base ISA,
+    /// if it has one.
+    pub fn try_compress(&self, xlen: Xlen) -> Option<CInstruction> {
+        match self {
+            Instruction::C(instr) => Some(*instr),
+            instr => CInstruction::compress(instr, xlen),
+        }
+    }
+
     pub fn funct3(&self) -> Option<Funct3> {
         use Instruction::*;
         match self {
             R(instr) => Some(instr.funct3()),
+            R4(instr) => Some(instr.funct3()),
+            Amo(instr) => Some(instr.funct3()),
             I(instr) => Some(instr.funct3()),
             S(instr) => Some(instr.funct3()),
             B(instr) => Some(instr.funct3()),
+            V(instr) => Some(instr.funct3()),
+            _ => None,
+        }
+    }
+
+    pub fn funct2(&self) -> Option<Funct2> {
+        match self {
+            Instruction::R4(instr) => Some(instr.funct2()),
             _ => None,
         }
     }
@@ -34,6 +125,8 @@ impl Instruction {
         use Instruction::*;
         match self {
             R(instr) => Some(instr.funct7()),
+            Amo(instr) => Some(instr.funct7()),
+            I(instr) => instr.funct7(),
             _ => None,
         }
     }
@@ -42,9 +135,13 @@ impl Instruction {
         use Instruction::*;
         match self {
             R(instr) => Some(instr.rs1()),
+            R4(instr) => Some(instr.rs1()),
+            Amo(instr) => Some(instr.rs1()),
             I(instr) => Some(instr.rs1()),
             S(instr) => Some(instr.rs1()),
             B(instr) => Some(instr.rs1()),
+            V(instr) => instr.rs1(),
+            C(instr) => instr.rs1(),
             _ => None,
         }
     }
@@ -53,8 +150,19 @@ impl Instruction {
         use Instruction::*;
         match self {
             R(instr) => Some(instr.rs2()),
+            R4(instr) => Some(instr.rs2()),
+            Amo(instr) => (!instr.is_load_reserved()).then_some(instr.rs2()),
             S(instr) => Some(instr.rs2()),
             B(instr) => Some(instr.rs2()),
+            V(instr) => instr.rs2(),
+            C(instr) => instr.rs2(),
+            _ => None,
+        }
+    }
+
+    pub fn rs3(&self) -> Option<Register> {
+        match self {
+            Instruction::R4(instr) => Some(instr.rs3()),
             _ => None,
         }
     }
@@ -63,42 +171,162 @@ impl Instruction {
         use Instruction::*;
         match self {
             R(instr) => Some(instr.rd()),
+            R4(instr) => Some(instr.rd()),
+            Amo(instr) => Some(instr.rd()),
             I(instr) => Some(instr.rd()),
             U(instr) => Some(instr.rd()),
             J(instr) => Some(instr.rd()),
+            V(instr) => instr.rd(),
+            C(instr) => instr.rd(),
+            _ => None,
+        }
+    }
+
+    /// Returns the floating-point register selected by rd, if it's a floating-point operand.
+    pub fn frd(&self) -> Option<FRegister> {
+        self.float_operand(0, |instr| instr.rd())
+    }
+
+    /// Returns the floating-point register selected by rs1, if it's a floating-point operand.
+    pub fn frs1(&self) -> Option<FRegister> {
+        self.float_operand(1, |instr| instr.rs1())
+    }
+
+    /// Returns the floating-point register selected by rs2, if it's a floating-point operand.
+    pub fn frs2(&self) -> Option<FRegister> {
+        self.float_operand(2, |instr| instr.rs2())
+    }
+
+    /// Returns the floating-point register selected by rs3, if it's a floating-point operand.
+    pub fn frs3(&self) -> Option<FRegister> {
+        self.float_operand(3, |instr| instr.rs3())
+    }
+
+    /// Returns the classes of the rd, rs1, rs2 and rs3 fields (`None` if the field
+    /// isn't a register operand). Only floating-point instructions use `Float`.
+    /// Compressed instructions are described by their 32-bit equivalents.
+    pub fn register_classes(&self) -> [Option<RegisterClass>; 4] {
+        let instr = self.expand();
+        register_classes(*instr.opcode(), instr.into())
+    }
+
+    fn float_operand(
+        &self,
+        field: usize,
+        reg: impl Fn(&Instruction) -> Option<Register>,
+    ) -> Option<FRegister> {
+        let instr = self.expand();
+        (instr.register_classes()[field] == Some(RegisterClass::Float))
+            .then(|| reg(&instr).map(as_fregister))
+            .flatten()
+    }
+
+    /// Returns the rounding mode of floating-point operations which have it.
+    pub fn rounding_mode(&self) -> Option<RoundingMode> {
+        match self {
+            Instruction::R4(instr) => instr.rounding_mode(),
+            Instruction::R(instr) => rounding_mode(*instr.opcode(), instr.into()),
+            _ => None,
+        }
+    }
+
+    /// Checks whether it's a floating-point operation with a rounding mode field. The
+    /// field may hold a reserved rounding mode, which [`Instruction::rounding_mode`]
+    /// doesn't return, and which makes the instruction illegal to execute.
+    pub fn has_rounding_mode(&self) -> bool {
+        has_rounding_mode(*self.opcode(), self.into())
+    }
+
+    /// Returns the rounding mode operand printed after the registers: `None` for the
+    /// dynamic rounding mode, or the raw field (e.g. `rm=5`) for a reserved one.
+    pub(crate) fn rounding_mode_operand(&self) -> Option<String> {
+        rounding_mode_operand(*self.opcode(), self.into())
+    }
+
+    /// Returns the offset of a PC-relative instruction: a branch, `jal` or `auipc`
+    /// (including their compressed forms).
+    pub fn pc_offset(&self) -> Option<i32> {
+        match self.expand() {
+            Instruction::B(instr) => Some(instr.imm().into()),
+            Instruction::J(instr) => Some(instr.imm().into()),
+            Instruction::U(instr) if u8::from(*instr.opcode()) == AUIPC_OPCODE => {
+                Some(instr.imm().into())
+            }
             _ => None,
         }
     }
 
+    /// Returns the canonical pseudo-instruction (e.g. `li`, `ret`, `beqz`) standing for
+    /// the instruction in the base ISA, if it has one.
+    pub fn pseudo(&self, xlen: Xlen) -> Option<PseudoInstruction> {
+        PseudoInstruction::resolve(self, xlen)
+    }
+
+    /// Returns the CSR accessed by a Zicsr instruction.
+    pub fn csr(&self) -> Option<Csr> {
+        match self {
+            Instruction::I(instr) => instr.csr(),
+            _ => None,
+        }
+    }
+
+    /// Returns the immediate operand. For shift-immediates it's the shift amount,
+    /// for Zicsr instructions the 5-bit unsigned immediate of `csrr*i` (the CSR
+    /// address is returned by [`Instruction::csr`]).
     pub fn immediate(&self) -> Option<i32> {
         use Instruction::*;
         match self {
-            I(instr) => Some(instr.imm().into()),
+            I(instr) if instr.csr().is_some() => instr.uimm().map(i32::from),
+            I(instr) => Some(instr.shamt().map_or(instr.imm().into(), i32::from)),
             S(instr) => Some(instr.imm().into()),
             B(instr) => Some(instr.imm().into()),
             U(instr) => Some(instr.imm().into()),
             J(instr) => Some(instr.imm().into()),
+            V(instr) => instr.imm(),
+            C(instr) => instr.imm(),
             _ => None,
         }
     }
 }
 
+/// Checks whether a 32-bit encoding is only defined for RV64 (and wider) base ISAs.
+fn requires_rv64(instr: u32) -> bool {
+    let opcode = instr & 0x7f;
+    let funct3 = (instr >> 12) & 0b111;
+    let rs2 = (instr >> 20) & 0b11111;
+    let funct7 = instr >> 25;
+
+    match opcode {
+        // OP-IMM-32 and OP-32
+        0b0011011 | 0b0111011 => true,
+        // ld, lwu
+        0b0000011 => funct3 == 0b011 || funct3 == 0b110,
+        // sd
+        0b0100011 => funct3 == 0b011,
+        // shift amounts above 31
+        0b0010011 => (funct3 == 0b001 || funct3 == 0b101) && funct7 & 1 == 1,
+        // doubleword AMOs (except amocas.d, operating on a register pair in RV32), amocas.q
+        0b0101111 => (funct3 == 0b011 && funct7 >> 2 != 0b00101) || funct3 == 0b100,
+        0b1010011 => match funct7 {
+            // conversions between floats and 64-bit integers
+            0b1100000..=0b1100011 | 0b1101000..=0b1101011 => rs2 & 0b10 != 0,
+            // fmv.x.d, fmv.d.x
+            0b1110001 | 0b1111001 => funct3 == 0b000 && rs2 == 0,
+            // fmvh.x.q, fmvp.q.x
+            0b1110011 => funct3 == 0b000 && rs2 == 1,
+            0b1011011 => funct3 == 0b000,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Decodes an RV32 instruction, see [`Instruction::decode`] for other base ISAs.
 impl TryFrom<u32> for Instruction {
     type Error = RISCVError;
 
     fn try_from(instr: u32) -> Result<Self, Self::Error> {
-        use InstructionFormat::*;
-
-        let instruction = match InstructionFormat::try_from(instr)? {
-            R => Instruction::R(RInstruction::try_from(instr)?),
-            I => Instruction::I(IInstruction::try_from(instr)?),
-            S => Instruction::S(SInstruction::try_from(instr)?),
-            B => Instruction::B(BInstruction::try_from(instr)?),
-            U => Instruction::U(UInstruction::try_from(instr)?),
-            J => Instruction::J(JInstruction::try_from(instr)?),
-        };
-
-        Ok(instruction)
+        Instruction::decode(instr, Xlen::Rv32)
     }
 }
 
@@ -113,11 +341,15 @@ impl From<&Instruction> for u32 {
         use Instruction::*;
         match instr {
             R(instr) => u32::from(instr),
+            R4(instr) => u32::from(instr),
+            Amo(instr) => u32::from(instr),
             I(instr) => u32::from(instr),
             S(instr) => u32::from(instr),
             B(instr) => u32::from(instr),
             U(instr) => u32::from(instr),
             J(instr) => u32::from(instr),
+            V(instr) => u32::from(instr),
+            C(instr) => u32::from(instr),
         }
     }
 }
@@ -127,11 +359,15 @@ impl fmt::Display for Instruction {
         use Instruction::*;
         match self {
             R(instr) => write!(f, "{}", instr),
+            R4(instr) => write!(f, "{}", instr),
+            Amo(instr) => write!(f, "{}", instr),
             I(instr) => write!(f, "{}", instr),
             S(instr) => write!(f, "{}", instr),
             B(instr) => write!(f, "{}", instr),
             U(instr) => write!(f, "{}", instr),
             J(instr) => write!(f, "{}", instr),
+            V(instr) => write!(f, "{}", instr),
+            C(instr) => write!(f, "{}", instr),
         }
     }
 }
@@ -143,11 +379,15 @@ macro_rules! delegate_instruction_methods {
                 fn $fn_name(&self) -> $ret {
                     match self {
                         $enum_name::R(inner) => inner.$fn_name(),
+                        $enum_name::R4(inner) => inner.$fn_name(),
+                        $enum_name::Amo(inner) => inner.$fn_name(),
                         $enum_name::I(inner) => inner.$fn_name(),
                         $enum_name::S(inner) => inner.$fn_name(),
                         $enum_name::B(inner) => inner.$fn_name(),
                         $enum_name::U(inner) => inner.$fn_name(),
                         $enum_name::J(inner) => inner.$fn_name(),
+                        $enum_name::V(inner) => inner.$fn_name(),
+                        $enum_name::C(inner) => inner.$fn_name(),
                     }
                 }
             )*
@@ -173,8 +413,123 @@ macro_rules! create_from_instruction {
 }
 
 create_from_instruction!(R, RInstruction);
+create_from_instruction!(R4, R4Instruction);
+create_from_instruction!(Amo, AmoInstruction);
 create_from_instruction!(I, IInstruction);
 create_from_instruction!(S, SInstruction);
 create_from_instruction!(B, BInstruction);
 create_from_instruction!(U, UInstruction);
 create_from_instruction!(J, JInstruction);
+create_from_instruction!(V, VInstruction);
+create_from_instruction!(C, CInstruction);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decode_rv64() -> Result<(), RISCVError> {
+        let rv64_only = [
+            0x02851513, // slli a0, a0, 40
+            0x4035551b, // sraiw a0, a0, 3
+            0x00b5053b, // addw a0, a0, a1
+            0x00813503, // ld a0, 8(sp)
+            0x00a13423, // sd a0, 8(sp)
+            0xc0257553, // fcvt.l.s a0, fa0
+            0x00c5b52f, // amoadd.d a0, a2, (a1)
+        ];
+        for bits in rv64_only {
+            assert_eq!(
+                Err(RISCVError::UnsupportedInstruction(Xlen::Rv32)),
+                Instruction::try_from(bits)
+            );
+            assert!(Instruction::decode(bits, Xlen::Rv64).is_ok());
+        }
+
+        let slli = Instruction::decode(0x02851513, Xlen::Rv64)?;
+        assert_eq!(Some("slli".into()), slli.mnemonic());
+        let srai = Instruction::try_from(0x40355513)?;
+        assert_eq!(Some("srai".into()), srai.mnemonic());
+        let srli = Instruction::try_from(0x00355513)?;
+        assert_eq!(Some("srli".into()), srli.mnemonic());
+        let sraiw = Instruction::decode(0x4035551b, Xlen::Rv64)?;
+        assert_eq!(Some("sraiw".into()), sraiw.mnemonic());
+
+        // fcvt.w.s a0, fa0 and amocas.d a0, a2, (a1) are available in both
+        assert!(Instruction::try_from(0xc0057553).is_ok());
+        assert!(Instruction::try_from(0x28c5b52f).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_float_registers() -> Result<(), RISCVError> {
+        let fadd = Instruction::try_from(0x00c5f553)?; // fadd.s fa0, fa1, fa2
+        assert_eq!(Some(FRegister::fa0()), fadd.frd());
+        assert_eq!(Some(FRegister::fa2()), fadd.frs2());
+        assert_eq!(Some(RoundingMode::Dyn), fadd.rounding_mode());
+        assert_eq!("fadd.s fa0, fa1, fa2", fadd.to_string());
+
+        let fcvt = Instruction::decode(0xc025f553, Xlen::Rv64)?; // fcvt.l.s a0, fa1
+        assert_eq!(None, fcvt.frd());
+        assert_eq!(Some(FRegister::fa1()), fcvt.frs1());
+        assert_eq!(
+            [
+                Some(RegisterClass::Integer),
+                Some(RegisterClass::Float),
+                None,
+                None
+            ],
+            fcvt.register_classes()
+        );
+        assert_eq!("fcvt.l.s a0, fa1", fcvt.to_string());
+
+        let fsd = Instruction::try_from(0x00a53427)?; // fsd fa0, 8(a0)
+        assert_eq!(Some(FRegister::fa0()), fsd.frs2());
+        assert_eq!(None, fsd.frs1());
+        assert_eq!("fsd fa0, 0x8(a0)", fsd.to_string());
+
+        // c.fldsp fa0, 8(sp)
+        let c_fldsp = Instruction::try_from_compressed_le_bytes([0x22, 0x25])?;
+        assert_eq!(Some(FRegister::fa0()), c_fldsp.frd());
+
+        let add = Instruction::try_from(0x00b50533)?; // add a0, a0, a1
+        assert_eq!(None, add.frd());
+        assert_eq!(None, add.rounding_mode());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_vector() -> Result<(), RISCVError> {
+        let vadd = Instruction::try_from(0x002180d7)?;
+        assert!(matches!(vadd, Instruction::V(_)));
+        assert_eq!(InstructionFormat::V, *vadd.format());
+        assert_eq!("vadd.vv v1, v2, v3, v0.t", vadd.to_string());
+        assert_eq!(None, vadd.rd());
+
+        let vle = Instruction::try_from(0x02056087)?; // vle32.v v1, (a0)
+        assert!(matches!(vle, Instruction::V(_)));
+        assert_eq!(Some(Register::a0()), vle.rs1());
+
+        // flw fa0, 4(a0) shares the opcode
+        let flw = Instruction::try_from(0x00452507)?;
+        assert!(matches!(flw, Instruction::I(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reserved_rounding_mode() {
+        use crate::disasm::{DisasmConfig, InstructionFormatter};
+
+        let formatter = InstructionFormatter::new(DisasmConfig::default());
+        for (bits, expected) in [
+            (0xc015d553, "fcvt.wu.s a0, fa1, rm=5"),
+            (0x00c5e553, "fadd.s fa0, fa1, fa2, rm=6"),
+            (0x00c5f553, "fadd.s fa0, fa1, fa2"),
+            (0x00c58553, "fadd.s fa0, fa1, fa2, rne"),
+        ] {
+            let instr = Instruction::decode(bits, Xlen::Rv64).unwrap();
+            assert_eq!(expected, formatter.instruction(&instr));
+            assert_eq!(expected, instr.to_string());
+        }
+    }
+}
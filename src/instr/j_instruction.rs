@@ -51,10 +51,10 @@ impl InstructionTrait for JInstruction {
     fn immediate_bits(&self) -> u32 {
         let imm = self.imm.into_raw_bits();
         let mut res = 0u32;
-        copy_bits(&imm, 1, &mut res, 21, 10);
-        copy_bit(&imm, 11, &mut res, 20);
-        copy_bits(&imm, 12, &mut res, 12, 8);
-        copy_bit(&imm, 20, &mut res, 31);
+        copy_bits(&imm, 0, &mut res, 21, 10);
+        copy_bit(&imm, 10, &mut res, 20);
+        copy_bits(&imm, 11, &mut res, 12, 8);
+        copy_bit(&imm, 19, &mut res, 31);
         res
     }
 }
@@ -62,10 +62,10 @@ impl InstructionTrait for JInstruction {
 fn get_raw_imm(instr: &u32) -> u32 {
     let mut res = 0u32;
 
-    copy_bits(instr, 21, &mut res, 1, 10);
-    copy_bit(instr, 20, &mut res, 11);
-    copy_bits(instr, 12, &mut res, 12, 8);
-    copy_bit(instr, 31, &mut res, 20);
+    copy_bits(instr, 21, &mut res, 0, 10);
+    copy_bit(instr, 20, &mut res, 10);
+    copy_bits(instr, 12, &mut res, 11, 8);
+    copy_bit(instr, 31, &mut res, 19);
 
     res
 }
@@ -115,3 +115,21 @@ impl fmt::Display for JInstruction {
         )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_offset() -> Result<(), RISCVError> {
+        for (bits, offset) in [(0xff1ff0ef, -16), (0x0010006f, 2048), (0x7ff7f5ef, 0x7fffe)] {
+            let instr = JInstruction::try_from(bits)?;
+            assert_eq!(offset, i32::from(instr.imm()));
+            assert_eq!(bits, u32::from(instr));
+
+            let built = JInstruction::new(instr.opcode, instr.rd, Immediate::try_from(offset)?)?;
+            assert_eq!(bits, u32::from(built));
+        }
+        Ok(())
+    }
+}
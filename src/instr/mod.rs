@@ -1,19 +1,36 @@
+mod amo_instruction;
 mod b_instruction;
 mod builder;
+mod c_instruction;
+mod classify;
+mod float;
 mod i_instruction;
 mod instrtrait;
 mod instruction;
 mod j_instruction;
+mod operands;
+mod pseudo;
+mod r4_instruction;
 mod r_instruction;
 mod s_instruction;
 mod u_instruction;
+mod v_instruction;
 
+pub use amo_instruction::AmoInstruction;
+pub(crate) use amo_instruction::AMO_OPCODE;
 pub use b_instruction::BInstruction;
 pub use builder::*;
+pub use c_instruction::CInstruction;
+pub(crate) use c_instruction::CompressedOperands;
+pub(crate) use i_instruction::fence_set_name;
 pub use i_instruction::IInstruction;
 pub use instrtrait::*;
 pub use instruction::*;
 pub use j_instruction::JInstruction;
+pub use pseudo::PseudoInstruction;
+pub use r4_instruction::R4Instruction;
 pub use r_instruction::RInstruction;
 pub use s_instruction::SInstruction;
 pub use u_instruction::UInstruction;
+pub(crate) use v_instruction::is_vector;
+pub use v_instruction::VInstruction;
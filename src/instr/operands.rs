@@ -0,0 +1,503 @@
+use super::float::as_fregister;
+use super::{
+    CInstruction, CompressedOperands, IInstruction, Instruction, InstructionTrait, VInstruction,
+};
+use crate::data::find_system_mnemonic;
+use crate::model::{FRegister, Operand, Register, RegisterClass, VRegister, VectorOperand};
+
+const LOAD_OPCODE: u8 = 0b0000011;
+const LOAD_FP_OPCODE: u8 = 0b0000111;
+const JALR_OPCODE: u8 = 0b1100111;
+
+/// Prefixes of vector instructions which also read their destination (the accumulator
+/// of multiply-add operations, or the state updated by the crypto extensions).
+const VECTOR_ACCUMULATORS: [&str; 6] = ["vaes", "vgmul", "vghsh", "vsha2", "vsm3c", "vsm4r"];
+const VECTOR_MULTIPLY_ADD: [&str; 4] = ["macc", "msac", "madd", "msub"];
+
+impl Instruction {
+    /// Returns the operands in the order of the assembly form, e.g.
+    /// `[IntReg(a0), MemOffset { base: sp, offset: 8 }]` for `ld a0, 8(sp)`. Register
+    /// fields are typed by the register file they select, and the offsets of branches
+    /// and jumps are `Target`s (while the one of `auipc` is an `Imm`). The `v0.t` mask
+    /// and the carry-in of vector instructions are `VecReg(v0)`, and the sets of `fence`
+    /// are `Imm`s. Rounding modes, `fli` constants and memory orderings of atomics
+    /// aren't operands.
+    pub fn operands(&self) -> Vec<Operand> {
+        use Instruction::*;
+        let classes = self.register_classes();
+        match self {
+            R(_) | R4(_) => {
+                let registers = [self.rd(), self.rs1(), self.rs2(), self.rs3()];
+                registers
+                    .into_iter()
+                    .zip(classes)
+                    .filter_map(|(reg, class)| typed(reg?, class))
+                    .collect()
+            }
+            Amo(i) if i.is_load_reserved() => {
+                vec![
+                    Operand::IntReg(i.rd()),
+                    Operand::MemOffset {
+                        base: i.rs1(),
+                        offset: 0,
+                    },
+                ]
+            }
+            Amo(i) => vec![
+                Operand::IntReg(i.rd()),
+                Operand::IntReg(i.rs2()),
+                Operand::MemOffset {
+                    base: i.rs1(),
+                    offset: 0,
+                },
+            ],
+            I(_) if find_system_mnemonic(self.into()).is_some() => Vec::new(),
+            I(i) if i.is_fence() => i.fence_sets().map_or(Vec::new(), |(pred, succ)| {
+                vec![Operand::Imm(pred.into()), Operand::Imm(succ.into())]
+            }),
+            I(i) => match i.csr() {
+                Some(csr) => vec![
+                    Operand::IntReg(i.rd()),
+                    Operand::Csr(csr),
+                    i.uimm()
+                        .map_or(Operand::IntReg(i.rs1()), |uimm| Operand::Imm(uimm.into())),
+                ],
+                None if is_load_or_jalr(self) => [
+                    typed(i.rd(), classes[0]),
+                    Some(Operand::MemOffset {
+                        base: i.rs1(),
+                        offset: i.imm().into(),
+                    }),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+                None => [
+                    typed(i.rd(), classes[0]),
+                    typed(i.rs1(), classes[1]),
+                    self.immediate().map(Operand::Imm),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+            },
+            S(i) => [
+                typed(i.rs2(), classes[2]),
+                Some(Operand::MemOffset {
+                    base: i.rs1(),
+                    offset: i.imm().into(),
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            B(i) => vec![
+                Operand::IntReg(i.rs1()),
+                Operand::IntReg(i.rs2()),
+                Operand::Target(i.imm().into()),
+            ],
+            U(i) => vec![Operand::IntReg(i.rd()), Operand::Imm(i.imm().into())],
+            J(i) => vec![Operand::IntReg(i.rd()), Operand::Target(i.imm().into())],
+            V(i) => vector_operands(i),
+            C(i) => compressed_operands(i, classes, self.pc_offset().is_some()),
+        }
+    }
+
+    /// Returns the registers (and CSRs) the instruction reads, in the order of their
+    /// fields. `x0` is never included, since reading it always gives zero. Compressed
+    /// instructions read the registers implied by their operation, e.g. `sp` for `c.lwsp`.
+    /// Vector register groups are represented by their first register.
+    pub fn registers_read(&self) -> Vec<Operand> {
+        self.register_accesses().0
+    }
+
+    /// Returns the registers (and CSRs) the instruction writes. Writes to `x0` are
+    /// discarded and so not included, e.g. `jal ra, ...` writes `ra` but `j ...`
+    /// (`jal zero, ...`) writes nothing, and stores and branches don't have any
+    /// destination.
+    pub fn registers_written(&self) -> Vec<Operand> {
+        self.register_accesses().1
+    }
+
+    /// Returns the registers read and written, without `x0` and duplicates.
+    fn register_accesses(&self) -> (Vec<Operand>, Vec<Operand>) {
+        let instr = self.expand();
+        let (read, written) = match &instr {
+            Instruction::I(_) if find_system_mnemonic((&instr).into()).is_some() => {
+                (Vec::new(), Vec::new())
+            }
+            Instruction::I(i) if i.csr().is_some() => csr_accesses(i),
+            Instruction::V(i) => vector_accesses(i),
+            _ => {
+                let [rd, rs1, rs2, rs3] = instr.register_classes();
+                let sources = [(instr.rs1(), rs1), (instr.rs2(), rs2), (instr.rs3(), rs3)];
+                let mut read: Vec<Operand> = sources
+                    .into_iter()
+                    .filter_map(|(reg, class)| typed(reg?, class))
+                    .collect();
+                let written: Vec<Operand> = instr
+                    .rd()
+                    .and_then(|reg| typed(reg, rd))
+                    .into_iter()
+                    .collect();
+                // amocas compares memory with the value of rd
+                if matches!(&instr, Instruction::Amo(_)) && has_prefix(&instr, &["amocas"]) {
+                    read.extend(written.iter().copied());
+                }
+                (read, written)
+            }
+        };
+        (registers(read), registers(written))
+    }
+}
+
+/// Returns the operand of a register field of the given class (`None` if the field
+/// isn't a register operand).
+fn typed(reg: Register, class: Option<RegisterClass>) -> Option<Operand> {
+    match class? {
+        RegisterClass::Integer => Some(Operand::IntReg(reg)),
+        RegisterClass::Float => Some(Operand::FloatReg(as_fregister(reg))),
+    }
+}
+
+/// Removes `x0` and duplicates, keeping the order of the first occurrences.
+fn registers(operands: Vec<Operand>) -> Vec<Operand> {
+    let mut result: Vec<Operand> = Vec::with_capacity(operands.len());
+    for op in operands {
+        if op != Operand::IntReg(Register::zero()) && !result.contains(&op) {
+            result.push(op);
+        }
+    }
+    result
+}
+
+fn has_prefix(instr: &Instruction, prefixes: &[&str]) -> bool {
+    instr.mnemonic().is_some_and(|m| {
+        let name = m.to_string();
+        prefixes.iter().any(|prefix| name.starts_with(prefix))
+    })
+}
+
+fn is_load_or_jalr(instr: &Instruction) -> bool {
+    matches!(
+        u8::from(*instr.opcode()),
+        LOAD_OPCODE | LOAD_FP_OPCODE | JALR_OPCODE
+    )
+}
+
+/// Zicsr instructions swap the CSR with rd: `csrrw` doesn't read the CSR if rd is `x0`,
+/// while `csrrs` and `csrrc` don't write it if the source is `x0` (or zero).
+fn csr_accesses(i: &IInstruction) -> (Vec<Operand>, Vec<Operand>) {
+    let Some(csr) = i.csr() else {
+        return (Vec::new(), Vec::new());
+    };
+    let source = match i.uimm() {
+        Some(uimm) => uimm != 0,
+        None => i.rs1() != Register::zero(),
+    };
+    let swap = u8::from(i.funct3()) & 0b11 == 0b01;
+
+    let mut read = Vec::new();
+    let mut written = vec![Operand::IntReg(i.rd())];
+    if i.uimm().is_none() {
+        read.push(Operand::IntReg(i.rs1()));
+    }
+    if !swap || i.rd() != Register::zero() {
+        read.push(Operand::Csr(csr));
+    }
+    if swap || source {
+        written.push(Operand::Csr(csr));
+    }
+    (read, written)
+}
+
+fn vector_operands(i: &VInstruction) -> Vec<Operand> {
+    use VectorOperand::*;
+
+    i.operands()
+        .iter()
+        .filter_map(|op| match op {
+            Vd => i.vd().map(Operand::VecReg),
+            Vs1 => i.vs1().map(Operand::VecReg),
+            Vs2 => i.vs2().map(Operand::VecReg),
+            Vs3 => i.vs3().map(Operand::VecReg),
+            Fd => i.fd().and_then(fregister),
+            Fs1 => i.fs1().and_then(fregister),
+            Rd => i.rd().map(Operand::IntReg),
+            Rs1 => i.rs1().map(Operand::IntReg),
+            Rs2 => i.rs2().map(Operand::IntReg),
+            Base => i.rs1().map(|base| Operand::MemOffset { base, offset: 0 }),
+            Simm5 | Uimm5 | Uimm6 => i.imm().map(Operand::Imm),
+            Vtype => i.vtype().map(|vtype| Operand::Imm(u16::from(vtype).into())),
+            Mask => (!i.vm()).then_some(Operand::VecReg(VRegister::v0())),
+            Carry => Some(Operand::VecReg(VRegister::v0())),
+        })
+        .collect()
+}
+
+fn vector_accesses(i: &VInstruction) -> (Vec<Operand>, Vec<Operand>) {
+    let written: Vec<Operand> = [
+        i.vd().map(Operand::VecReg),
+        i.rd().map(Operand::IntReg),
+        i.fd().and_then(fregister),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let instr = Instruction::V(*i);
+    let accumulates = has_prefix(&instr, &VECTOR_ACCUMULATORS)
+        || instr.mnemonic().is_some_and(|m| {
+            let name = m.to_string();
+            VECTOR_MULTIPLY_ADD.iter().any(|op| name.contains(op))
+        });
+    let mask = (!i.vm() && i.operands().contains(&VectorOperand::Mask))
+        || i.operands().contains(&VectorOperand::Carry);
+
+    let read = [
+        i.vs1().map(Operand::VecReg),
+        i.vs2().map(Operand::VecReg),
+        i.vs3().map(Operand::VecReg),
+        i.vd().filter(|_| accumulates).map(Operand::VecReg),
+        mask.then_some(Operand::VecReg(VRegister::v0())),
+        i.rs1().map(Operand::IntReg),
+        i.rs2().map(Operand::IntReg),
+        i.fs1().and_then(fregister),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    (read, written)
+}
+
+fn fregister(number: u8) -> Option<Operand> {
+    FRegister::try_from(number).ok().map(Operand::FloatReg)
+}
+
+/// Returns the operands of a compressed instruction, typed by the classes of its
+/// 32-bit equivalent.
+fn compressed_operands(
+    i: &CInstruction,
+    classes: [Option<RegisterClass>; 4],
+    pc_relative: bool,
+) -> Vec<Operand> {
+    use CompressedOperands::*;
+
+    let reg = |r: Option<Register>| r.map(Operand::IntReg);
+    let imm = i.imm().unwrap_or(0);
+    let imm = Some(if pc_relative {
+        Operand::Target(imm)
+    } else {
+        Operand::Imm(imm)
+    });
+    let memory = i.rs1().map(|base| Operand::MemOffset {
+        base,
+        offset: i.imm().unwrap_or(0),
+    });
+
+    let operands = match i.operands_layout() {
+        Empty => vec![],
+        Rd => vec![reg(i.rd())],
+        Rs1 => vec![reg(i.rs1())],
+        RdRs2 => vec![reg(i.rd()), reg(i.rs2())],
+        RdImm => vec![reg(i.rd()), imm],
+        RdRs1Imm => vec![reg(i.rd()), reg(i.rs1()), imm],
+        Rs1Imm => vec![reg(i.rs1()), imm],
+        Imm => vec![imm],
+        Load => vec![i.rd().and_then(|rd| typed(rd, classes[0])), memory],
+        Store => vec![i.rs2().and_then(|rs2| typed(rs2, classes[2])), memory],
+    };
+    operands.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RISCVError;
+    use crate::model::Csr;
+
+    fn decode(bits: u32) -> Instruction {
+        Instruction::try_from(bits).unwrap()
+    }
+
+    #[test]
+    fn test_operands() -> Result<(), RISCVError> {
+        // lw a0, 8(sp)
+        assert_eq!(
+            vec![
+                Operand::IntReg(Register::a0()),
+                Operand::MemOffset {
+                    base: Register::sp(),
+                    offset: 8
+                }
+            ],
+            decode(0x00812503).operands()
+        );
+        // fsd fa0, 8(a0)
+        assert_eq!(
+            vec![
+                Operand::FloatReg(FRegister::fa0()),
+                Operand::MemOffset {
+                    base: Register::a0(),
+                    offset: 8
+                }
+            ],
+            decode(0x00a53427).operands()
+        );
+        // beq a0, a1, 0x10
+        assert_eq!(
+            vec![
+                Operand::IntReg(Register::a0()),
+                Operand::IntReg(Register::a1()),
+                Operand::Target(16)
+            ],
+            decode(0x00b50863).operands()
+        );
+        // fcvt.s.w fa0, a1
+        assert_eq!(
+            vec![
+                Operand::FloatReg(FRegister::fa0()),
+                Operand::IntReg(Register::a1())
+            ],
+            decode(0xd005f553).operands()
+        );
+        // csrrwi a0, mstatus, 5
+        assert_eq!(
+            vec![
+                Operand::IntReg(Register::a0()),
+                Operand::Csr(Csr::try_from(0x300)?),
+                Operand::Imm(5)
+            ],
+            decode(0x3002d573).operands()
+        );
+        // vadd.vv v1, v2, v3, v0.t
+        assert_eq!(
+            vec![
+                Operand::VecReg(VRegister::from_vd_bits(1 << 7)),
+                Operand::VecReg(VRegister::from_vd_bits(2 << 7)),
+                Operand::VecReg(VRegister::from_vd_bits(3 << 7)),
+                Operand::VecReg(VRegister::v0()),
+            ],
+            decode(0x002180d7).operands()
+        );
+        // c.lwsp a0, 12(sp)
+        let c_lwsp = Instruction::try_from_compressed_le_bytes([0x32, 0x45])?;
+        assert_eq!(
+            vec![
+                Operand::IntReg(Register::a0()),
+                Operand::MemOffset {
+                    base: Register::sp(),
+                    offset: 12
+                }
+            ],
+            c_lwsp.operands()
+        );
+        // c.beqz a0, 0x8 and c.j -0x2 branch and jump to targets, auipc a0, 0x1 doesn't
+        let c_beqz = Instruction::try_from_compressed_le_bytes([0x01, 0xc5])?;
+        assert_eq!(
+            vec![Operand::IntReg(Register::a0()), Operand::Target(8)],
+            c_beqz.operands()
+        );
+        let c_j = Instruction::try_from_compressed_le_bytes([0xfd, 0xbf])?;
+        assert_eq!(vec![Operand::Target(-2)], c_j.operands());
+        assert_eq!(
+            vec![Operand::IntReg(Register::a0()), Operand::Imm(0x1000)],
+            decode(0x00001517).operands()
+        );
+        assert!(decode(0x00000073).operands().is_empty()); // ecall
+        Ok(())
+    }
+
+    #[test]
+    fn test_registers_read_written() -> Result<(), RISCVError> {
+        // sw a0, 8(sp): no destination
+        let sw = decode(0x00a12423);
+        assert_eq!(
+            vec![
+                Operand::IntReg(Register::sp()),
+                Operand::IntReg(Register::a0())
+            ],
+            sw.registers_read()
+        );
+        assert!(sw.registers_written().is_empty());
+
+        // jal ra, 0x10 writes ra, j 0x10 (jal zero) writes nothing
+        assert_eq!(
+            vec![Operand::IntReg(Register::ra())],
+            decode(0x010000ef).registers_written()
+        );
+        assert!(decode(0x0100006f).registers_written().is_empty());
+
+        // add a0, a0, a0 reads a0 once; addi a0, zero, 1 reads nothing
+        assert_eq!(
+            vec![Operand::IntReg(Register::a0())],
+            decode(0x00a50533).registers_read()
+        );
+        assert!(decode(0x00100513).registers_read().is_empty());
+
+        // lr.w a0, (a1), amocas.w a0, a2, (a1)
+        assert_eq!(
+            vec![Operand::IntReg(Register::a1())],
+            decode(0x1005a52f).registers_read()
+        );
+        assert_eq!(
+            vec![
+                Operand::IntReg(Register::a1()),
+                Operand::IntReg(Register::a2()),
+                Operand::IntReg(Register::a0())
+            ],
+            decode(0x28c5a52f).registers_read()
+        );
+
+        // csrr a0, mstatus (csrrs a0, mstatus, zero) doesn't write the CSR
+        let mstatus = Csr::try_from(0x300)?;
+        let csrr = decode(0x30002573);
+        assert_eq!(vec![Operand::Csr(mstatus)], csrr.registers_read());
+        assert_eq!(
+            vec![Operand::IntReg(Register::a0())],
+            csrr.registers_written()
+        );
+        // csrw mstatus, a0 (csrrw zero, mstatus, a0) doesn't read it
+        let csrw = decode(0x30051073);
+        assert_eq!(vec![Operand::IntReg(Register::a0())], csrw.registers_read());
+        assert_eq!(vec![Operand::Csr(mstatus)], csrw.registers_written());
+
+        // fmadd.s fa0, fa1, fa2, fa3
+        let fmadd = decode(0x68c5f543);
+        assert_eq!(
+            vec![
+                Operand::FloatReg(FRegister::fa1()),
+                Operand::FloatReg(FRegister::fa2()),
+                Operand::FloatReg(FRegister::fa3())
+            ],
+            fmadd.registers_read()
+        );
+        assert_eq!(
+            vec![Operand::FloatReg(FRegister::fa0())],
+            fmadd.registers_written()
+        );
+
+        // c.jal 0x10 (RV32) writes ra, c.jr ra reads it
+        let c_jal = Instruction::try_from_compressed_le_bytes([0x41, 0x20])?;
+        assert_eq!(
+            vec![Operand::IntReg(Register::ra())],
+            c_jal.registers_written()
+        );
+        let c_jr = Instruction::try_from_compressed_le_bytes([0x82, 0x80])?;
+        assert_eq!(vec![Operand::IntReg(Register::ra())], c_jr.registers_read());
+        assert!(c_jr.registers_written().is_empty());
+
+        // vse32.v v1, (a0), v0.t reads the mask
+        let vse = decode(0x000560a7);
+        assert_eq!(
+            vec![
+                Operand::VecReg(VRegister::from_vd_bits(1 << 7)),
+                Operand::VecReg(VRegister::v0()),
+                Operand::IntReg(Register::a0())
+            ],
+            vse.registers_read()
+        );
+        assert!(vse.registers_written().is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+
+use crate::model::{CompressedOp, Csr, Mnemonic, Operand, Register, Xlen};
+
+use super::float::as_fregister;
+use super::{
+    BInstruction, CInstruction, IInstruction, Instruction, InstructionTrait, JInstruction,
+    RInstruction,
+};
+
+/// Sign-injection instructions with both sources being the same register,
+/// and the pseudo-instructions they implement.
+const SIGN_INJECTION_ALIASES: [(&str, &str); 12] = [
+    ("fsgnj_s", "fmv_s"),
+    ("fsgnj_d", "fmv_d"),
+    ("fsgnj_h", "fmv_h"),
+    ("fsgnj_q", "fmv_q"),
+    ("fsgnjn_s", "fneg_s"),
+    ("fsgnjn_d", "fneg_d"),
+    ("fsgnjn_h", "fneg_h"),
+    ("fsgnjn_q", "fneg_q"),
+    ("fsgnjx_s", "fabs_s"),
+    ("fsgnjx_d", "fabs_d"),
+    ("fsgnjx_h", "fabs_h"),
+    ("fsgnjx_q", "fabs_q"),
+];
+
+/// A pseudo-instruction of the assembly language (e.g. `li`, `ret`, `beqz`), standing
+/// for a single base instruction with some of its operands fixed.
+///
+/// The canonical pseudo-instruction is the one printed by objdump (unless `-M no-aliases`
+/// is given).
+#[derive(Debug, PartialEq, Clone)]
+pub struct PseudoInstruction {
+    mnemonic: &'static str,
+    operands: Vec<Operand>,
+}
+
+impl PseudoInstruction {
+    fn new(mnemonic: &'static str, operands: Vec<Operand>) -> Option<Self> {
+        Some(Self { mnemonic, operands })
+    }
+
+    /// Finds the canonical pseudo-instruction for the instruction, if it has one.
+    /// Compressed instructions are resolved by their 32-bit equivalents. The base ISA
+    /// selects aliases depending on XLEN (`pack` with `x0` is `zext.h` only in RV32).
+    pub fn resolve(instr: &Instruction, xlen: Xlen) -> Option<Self> {
+        match instr {
+            Instruction::R(i) => resolve_r(i, xlen),
+            Instruction::I(i) => resolve_i(i),
+            Instruction::B(i) => resolve_b(i),
+            Instruction::J(i) => resolve_j(i),
+            Instruction::C(i) => resolve_c(i, xlen),
+            _ => None,
+        }
+    }
+
+    pub fn mnemonic(&self) -> Mnemonic {
+        self.mnemonic.into()
+    }
+
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands
+    }
+}
+
+fn resolve_r(i: &RInstruction, xlen: Xlen) -> Option<PseudoInstruction> {
+    use Operand::IntReg as R;
+
+    let mnemonic: &str = i.mnemonic()?.into();
+    let zero = Register::zero();
+    let (rd, rs1, rs2) = (i.rd(), i.rs1(), i.rs2());
+
+    match mnemonic {
+        "sub" if rs1 == zero => PseudoInstruction::new("neg", vec![R(rd), R(rs2)]),
+        "subw" if rs1 == zero => PseudoInstruction::new("negw", vec![R(rd), R(rs2)]),
+        "sltu" if rs1 == zero => PseudoInstruction::new("snez", vec![R(rd), R(rs2)]),
+        "slt" if rs2 == zero => PseudoInstruction::new("sltz", vec![R(rd), R(rs1)]),
+        "slt" if rs1 == zero => PseudoInstruction::new("sgtz", vec![R(rd), R(rs2)]),
+        "add_uw" if rs2 == zero => PseudoInstruction::new("zext_w", vec![R(rd), R(rs1)]),
+        // zext.h is encoded as pack in RV32 and as packw in RV64
+        "pack" if rs2 == zero && xlen == Xlen::Rv32 => {
+            PseudoInstruction::new("zext_h", vec![R(rd), R(rs1)])
+        }
+        "packw" if rs2 == zero => PseudoInstruction::new("zext_h", vec![R(rd), R(rs1)]),
+        _ if rs1 == rs2 => {
+            let (_, alias) = SIGN_INJECTION_ALIASES
+                .iter()
+                .find(|(base, _)| *base == mnemonic)?;
+            let operands = [rd, rs1].map(|reg| Operand::FloatReg(as_fregister(reg)));
+            PseudoInstruction::new(alias, operands.to_vec())
+        }
+        _ => None,
+    }
+}
+
+fn resolve_i(i: &IInstruction) -> Option<PseudoInstruction> {
+    use Operand::IntReg as R;
+
+    if let Some(csr) = i.csr() {
+        return resolve_csr(i, csr);
+    }
+
+    let mnemonic: &str = i.mnemonic()?.into();
+    let zero = Register::zero();
+    let (rd, rs1, imm) = (i.rd(), i.rs1(), i32::from(i.imm()));
+
+    match mnemonic {
+        "addi" if rd == zero && rs1 == zero && imm == 0 => PseudoInstruction::new("nop", vec![]),
+        // a fence hint ordering only the preceding writes (Zihintpause)
+        "fence" if rd == zero && rs1 == zero && imm == 0x010 => {
+            PseudoInstruction::new("pause", vec![])
+        }
+        "addi" if rs1 == zero => PseudoInstruction::new("li", vec![R(rd), Operand::Imm(imm)]),
+        "addi" if imm == 0 => PseudoInstruction::new("mv", vec![R(rd), R(rs1)]),
+        "addiw" if imm == 0 => PseudoInstruction::new("sext_w", vec![R(rd), R(rs1)]),
+        "xori" if imm == -1 => PseudoInstruction::new("not", vec![R(rd), R(rs1)]),
+        "sltiu" if imm == 1 => PseudoInstruction::new("seqz", vec![R(rd), R(rs1)]),
+        "andi" if imm == 0xff => PseudoInstruction::new("zext_b", vec![R(rd), R(rs1)]),
+        "jalr" if rd == zero && rs1 == Register::ra() && imm == 0 => {
+            PseudoInstruction::new("ret", vec![])
+        }
+        "jalr" if rd == zero || rd == Register::ra() => {
+            let target = if imm == 0 {
+                R(rs1)
+            } else {
+                Operand::MemOffset {
+                    base: rs1,
+                    offset: imm,
+                }
+            };
+            PseudoInstruction::new(if rd == zero { "jr" } else { "jalr" }, vec![target])
+        }
+        _ => None,
+    }
+}
+
+/// Resolves Zicsr instructions. Reads of the counters and the floating-point CSRs
+/// have dedicated pseudo-instructions (e.g. `rdcycle`, `frflags`), other CSRs
+/// are accessed by `csrr`, or written by `csrw`, `csrs`, `csrc` and their `csr*i` forms.
+fn resolve_csr(i: &IInstruction, csr: Csr) -> Option<PseudoInstruction> {
+    use Operand::IntReg as R;
+
+    let mnemonic: &str = i.mnemonic()?.into();
+    let zero = Register::zero();
+    let (rd, rs1) = (i.rd(), i.rs1());
+    let source = i.uimm().map_or(R(rs1), |uimm| Operand::Imm(uimm.into()));
+    // the destination of floating-point CSR swaps is omitted when it's zero
+    let swap = |alias| {
+        let operands = if rd == zero {
+            vec![source]
+        } else {
+            vec![R(rd), source]
+        };
+        PseudoInstruction::new(alias, operands)
+    };
+
+    match (mnemonic, csr.name().unwrap_or_default()) {
+        ("csrrs", name) if rs1 == zero => {
+            let alias = match name {
+                "cycle" => "rdcycle",
+                "time" => "rdtime",
+                "instret" => "rdinstret",
+                "cycleh" => "rdcycleh",
+                "timeh" => "rdtimeh",
+                "instreth" => "rdinstreth",
+                "fcsr" => "frcsr",
+                "frm" => "frrm",
+                "fflags" => "frflags",
+                _ => return PseudoInstruction::new("csrr", vec![R(rd), Operand::Csr(csr)]),
+            };
+            PseudoInstruction::new(alias, vec![R(rd)])
+        }
+        ("csrrw", "fcsr") => swap("fscsr"),
+        ("csrrw", "frm") => swap("fsrm"),
+        ("csrrw", "fflags") => swap("fsflags"),
+        ("csrrwi", "frm") => swap("fsrmi"),
+        ("csrrwi", "fflags") => swap("fsflagsi"),
+        _ if rd != zero => None,
+        (mnemonic, _) => {
+            let alias = match mnemonic {
+                "csrrw" => "csrw",
+                "csrrs" => "csrs",
+                "csrrc" => "csrc",
+                "csrrwi" => "csrwi",
+                "csrrsi" => "csrsi",
+                "csrrci" => "csrci",
+                _ => return None,
+            };
+            PseudoInstruction::new(alias, vec![Operand::Csr(csr), source])
+        }
+    }
+}
+
+fn resolve_b(i: &BInstruction) -> Option<PseudoInstruction> {
+    use Operand::IntReg as R;
+
+    let mnemonic: &str = i.mnemonic()?.into();
+    let zero = Register::zero();
+    let (rs1, rs2, offset) = (i.rs1(), i.rs2(), Operand::Target(i.imm().into()));
+
+    match mnemonic {
+        "beq" if rs2 == zero => PseudoInstruction::new("beqz", vec![R(rs1), offset]),
+        "bne" if rs2 == zero => PseudoInstruction::new("bnez", vec![R(rs1), offset]),
+        "blt" if rs2 == zero => PseudoInstruction::new("bltz", vec![R(rs1), offset]),
+        "bge" if rs2 == zero => PseudoInstruction::new("bgez", vec![R(rs1), offset]),
+        "blt" if rs1 == zero => PseudoInstruction::new("bgtz", vec![R(rs2), offset]),
+        "bge" if rs1 == zero => PseudoInstruction::new("blez", vec![R(rs2), offset]),
+        _ => None,
+    }
+}
+
+fn resolve_j(i: &JInstruction) -> Option<PseudoInstruction> {
+    let offset = Operand::Target(i.imm().into());
+    if i.rd() == Register::zero() {
+        PseudoInstruction::new("j", vec![offset])
+    } else if i.rd() == Register::ra() {
+        PseudoInstruction::new("jal", vec![offset])
+    } else {
+        None
+    }
+}
+
+fn resolve_c(i: &CInstruction, xlen: Xlen) -> Option<PseudoInstruction> {
+    // `c.mv` expands to `add`, which has no alias of its own
+    if i.op() == CompressedOp::Mv {
+        let operands = vec![Operand::IntReg(i.rd()?), Operand::IntReg(i.rs2()?)];
+        return PseudoInstruction::new("mv", operands);
+    }
+    PseudoInstruction::resolve(&i.expand(), xlen)
+}
+
+impl fmt::Display for PseudoInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operands: Vec<String> = self.operands.iter().map(Operand::to_string).collect();
+        if operands.is_empty() {
+            write!(f, "{}", self.mnemonic())
+        } else {
+            write!(f, "{} {}", self.mnemonic(), operands.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pseudo(instr: u32) -> Option<String> {
+        pseudo_in(instr, Xlen::Rv64)
+    }
+
+    fn pseudo_in(instr: u32, xlen: Xlen) -> Option<String> {
+        let instr = Instruction::decode(instr, xlen).unwrap();
+        PseudoInstruction::resolve(&instr, xlen).map(|p| p.to_string())
+    }
+
+    #[test]
+    fn test_integer_aliases() {
+        assert_eq!(Some("nop"), pseudo(0x00000013).as_deref());
+        assert_eq!(Some("li a0, 0x5"), pseudo(0x00500513).as_deref());
+        assert_eq!(Some("mv a0, a1"), pseudo(0x00058513).as_deref());
+        assert_eq!(Some("not a0, a1"), pseudo(0xfff5c513).as_deref());
+        assert_eq!(Some("seqz a0, a1"), pseudo(0x0015b513).as_deref());
+        assert_eq!(Some("sext.w a0, a1"), pseudo(0x0005851b).as_deref());
+        assert_eq!(Some("zext.b a0, a1"), pseudo(0x0ff5f513).as_deref());
+        assert_eq!(Some("neg a0, a1"), pseudo(0x40b00533).as_deref());
+        assert_eq!(Some("negw a0, a1"), pseudo(0x40b0053b).as_deref());
+        assert_eq!(Some("snez a0, a1"), pseudo(0x00b03533).as_deref());
+        assert_eq!(Some("sltz a0, a1"), pseudo(0x0005a533).as_deref());
+        assert_eq!(Some("sgtz a0, a1"), pseudo(0x00b02533).as_deref());
+        assert_eq!(None, pseudo(0x00b50533)); // add a0, a0, a1
+        assert_eq!(None, pseudo(0x00150513)); // addi a0, a0, 1
+        assert_eq!(Some("pause"), pseudo(0x0100000f).as_deref());
+        assert_eq!(None, pseudo(0x0ff0000f)); // fence iorw, iorw
+
+        // pack a0, a0, zero (RV32) and packw a0, a0, zero (RV64)
+        let zext_h = Some("zext.h a0, a0");
+        assert_eq!(zext_h, pseudo_in(0x08054533, Xlen::Rv32).as_deref());
+        assert_eq!(None, pseudo_in(0x08054533, Xlen::Rv64));
+        assert_eq!(zext_h, pseudo_in(0x0805453b, Xlen::Rv64).as_deref());
+        assert_eq!(None, pseudo(0x08b5453b)); // packw a0, a0, a1
+    }
+
+    #[test]
+    fn test_control_flow_aliases() {
+        assert_eq!(Some("ret"), pseudo(0x00008067).as_deref());
+        assert_eq!(Some("jr a0"), pseudo(0x00050067).as_deref());
+        assert_eq!(Some("jr 0x8(a0)"), pseudo(0x00850067).as_deref());
+        assert_eq!(Some("jalr a0"), pseudo(0x000500e7).as_deref());
+        assert_eq!(Some("j 0x10"), pseudo(0x0100006f).as_deref());
+        assert_eq!(Some("jal 0x10"), pseudo(0x010000ef).as_deref());
+        assert_eq!(Some("beqz a0, 0x8"), pseudo(0x00050463).as_deref());
+        assert_eq!(Some("bnez a0, 0x8"), pseudo(0x00051463).as_deref());
+        assert_eq!(Some("bgtz a0, 0x8"), pseudo(0x00a04463).as_deref());
+        assert_eq!(Some("blez a0, 0x8"), pseudo(0x00a05463).as_deref());
+        assert_eq!(None, pseudo(0x00b50463)); // beq a0, a1, 8
+        assert_eq!(None, pseudo(0x010005ef)); // jal a1, 16
+    }
+
+    #[test]
+    fn test_csr_aliases() {
+        assert_eq!(Some("csrr a0, mstatus"), pseudo(0x30002573).as_deref());
+        assert_eq!(Some("csrw satp, a1"), pseudo(0x18059073).as_deref());
+        assert_eq!(Some("csrwi mtvec, 0x5"), pseudo(0x3052d073).as_deref());
+        assert_eq!(Some("rdcycle a0"), pseudo(0xc0002573).as_deref());
+        assert_eq!(Some("frflags a0"), pseudo(0x00102573).as_deref());
+        assert_eq!(Some("fsrm a0, a1"), pseudo(0x00259573).as_deref());
+        assert_eq!(Some("fsrm a1"), pseudo(0x00259073).as_deref());
+        assert_eq!(None, pseudo(0x30059573)); // csrrw a0, mstatus, a1
+    }
+
+    #[test]
+    fn test_float_aliases() {
+        assert_eq!(Some("fmv.s fa0, fa1"), pseudo(0x20b58553).as_deref());
+        assert_eq!(Some("fneg.d fa0, fa1"), pseudo(0x22b59553).as_deref());
+        assert_eq!(Some("fabs.s fa0, fa1"), pseudo(0x20b5a553).as_deref());
+        assert_eq!(None, pseudo(0x20c58553)); // fsgnj.s fa0, fa1, fa2
+    }
+
+    #[test]
+    fn test_compressed_aliases() {
+        assert_eq!(Some("li a0, 0x5"), pseudo(0x4515).as_deref());
+        assert_eq!(Some("mv a0, a1"), pseudo(0x852e).as_deref());
+        assert_eq!(Some("ret"), pseudo(0x8082).as_deref());
+        assert_eq!(Some("nop"), pseudo(0x0001).as_deref());
+        assert_eq!(None, pseudo(0x1141)); // c.addi sp, -16
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+
+use super::float::{register_classes, register_name, rounding_mode_operand};
+use super::InstructionTrait;
+use crate::config::UNKNOWN_MNEMONIC;
+use crate::data::find_float_instruction;
+use crate::error::RISCVError;
+use crate::model::{Funct2, Funct3, InstructionFormat, Mnemonic, Opcode, Register, RoundingMode};
+
+/// R4-type instruction, used by the fused multiply-add operations
+/// (`fmadd`, `fmsub`, `fnmsub`, `fnmadd`).
+///
+/// The `funct2` field selects the operand format (`fmt`: S, D, H or Q), while `funct3`
+/// holds the rounding mode (`rm`).
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct R4Instruction {
+    opcode: Opcode,
+    rs1: Register,
+    rs2: Register,
+    rs3: Register,
+    rd: Register,
+    funct2: Funct2,
+    funct3: Funct3,
+}
+
+impl R4Instruction {
+    pub fn new(
+        opcode: Opcode,
+        rs1: Register,
+        rs2: Register,
+        rs3: Register,
+        rd: Register,
+        funct2: Funct2,
+        funct3: Funct3,
+    ) -> Result<Self, RISCVError> {
+        let format = opcode.format();
+        if format != InstructionFormat::R4 {
+            return Err(RISCVError::UnexpectedFormat(format));
+        }
+
+        Ok(Self {
+            opcode,
+            rs1,
+            rs2,
+            rs3,
+            rd,
+            funct2,
+            funct3,
+        })
+    }
+
+    pub fn rs1(&self) -> Register {
+        self.rs1
+    }
+
+    pub fn rs2(&self) -> Register {
+        self.rs2
+    }
+
+    pub fn rs3(&self) -> Register {
+        self.rs3
+    }
+
+    pub fn rd(&self) -> Register {
+        self.rd
+    }
+
+    /// Returns the operand format field (`fmt`)
+    pub fn funct2(&self) -> Funct2 {
+        self.funct2
+    }
+
+    /// Returns the rounding mode field (`rm`)
+    pub fn funct3(&self) -> Funct3 {
+        self.funct3
+    }
+
+    /// Returns the rounding mode, `None` if reserved
+    pub fn rounding_mode(&self) -> Option<RoundingMode> {
+        RoundingMode::try_from(self.funct3).ok()
+    }
+}
+
+impl InstructionTrait for R4Instruction {
+    fn opcode(&self) -> &Opcode {
+        &self.opcode
+    }
+
+    fn format(&self) -> &InstructionFormat {
+        &InstructionFormat::R4
+    }
+
+    fn mnemonic(&self) -> Option<Mnemonic> {
+        find_float_instruction(self.into()).map(|(mnemonic, _, _)| mnemonic)
+    }
+
+    fn immediate_bits(&self) -> u32 {
+        0
+    }
+}
+
+impl TryFrom<u32> for R4Instruction {
+    type Error = RISCVError;
+
+    fn try_from(instr: u32) -> Result<Self, Self::Error> {
+        let opcode = Opcode::try_from(instr)?;
+        let format = opcode.format();
+
+        if format != InstructionFormat::R4 {
+            return Err(RISCVError::UnexpectedFormat(format));
+        }
+
+        Ok(Self {
+            opcode,
+            rs1: Register::from_rs1_bits(instr),
+            rs2: Register::from_rs2_bits(instr),
+            rs3: Register::from_rs3_bits(instr),
+            rd: Register::from_rd_bits(instr),
+            funct2: Funct2::from(instr),
+            funct3: Funct3::from(instr),
+        })
+    }
+}
+
+impl From<R4Instruction> for u32 {
+    fn from(instr: R4Instruction) -> Self {
+        u32::from(&instr)
+    }
+}
+
+impl From<&R4Instruction> for u32 {
+    fn from(instr: &R4Instruction) -> u32 {
+        u32::from(instr.opcode)
+            | u32::from(instr.funct2)
+            | u32::from(instr.funct3)
+            | instr.rs1.into_rs1_bits()
+            | instr.rs2.into_rs2_bits()
+            | instr.rs3.into_rs3_bits()
+            | instr.rd.into_rd_bits()
+    }
+}
+
+/// Formats the instruction like binutils, with the rounding mode appended unless
+/// it's dynamic (e.g. `fmadd.s fa0, fa1, fa2, fa3, rne`).
+impl fmt::Display for R4Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut operands: Vec<String> = [self.rd, self.rs1, self.rs2, self.rs3]
+            .into_iter()
+            .zip(register_classes(self.opcode, self.into()))
+            .filter_map(|(reg, class)| class.map(|class| register_name(reg, class)))
+            .collect();
+        operands.extend(rounding_mode_operand(self.opcode, self.into()));
+
+        write!(
+            f,
+            "{} {}",
+            self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()),
+            operands.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_two_way_conversion() -> Result<(), RISCVError> {
+        let bits = 0x68c5f543; // fmadd.s fa0, fa1, fa2, fa3 (rm = dyn)
+        let instr = R4Instruction::try_from(bits)?;
+        assert_eq!(Register::a3(), instr.rs3());
+        assert_eq!(0b111, u8::from(instr.funct3()));
+        assert_eq!(Some("fmadd_s".into()), instr.mnemonic());
+        assert_eq!(bits, u32::from(instr));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mnemonics() -> Result<(), RISCVError> {
+        let fnmadd_d = R4Instruction::try_from(0x6ac5f54f)?;
+        assert_eq!(Some("fnmadd_d".into()), fnmadd_d.mnemonic());
+        let fmsub_h = R4Instruction::try_from(0x6cc5f547)?;
+        assert_eq!(Some("fmsub_h".into()), fmsub_h.mnemonic());
+        Ok(())
+    }
+}
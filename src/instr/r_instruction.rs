@@ -1,5 +1,8 @@
 use std::fmt;
 
+use super::float::{
+    fli_constant, register_classes, register_name, rounding_mode, rounding_mode_operand,
+};
 use super::InstructionTrait;
 use crate::config::UNKNOWN_MNEMONIC;
 use crate::error::RISCVError;
@@ -7,9 +10,9 @@ use crate::model::InstructionFormat;
 use crate::model::Mnemonic;
 use crate::model::Opcode;
 use crate::model::Register;
-use crate::model::{Funct3, Funct7};
+use crate::model::{Funct3, Funct7, RoundingMode};
 
-use crate::data::get_mnemonic;
+use crate::data::{find_float_instruction, get_mnemonic};
 
 #[derive(Debug, PartialEq, Copy, Clone)]
 pub struct RInstruction {
@@ -64,6 +67,16 @@ impl RInstruction {
     pub fn funct7(&self) -> Funct7 {
         self.funct7
     }
+
+    /// Returns the constant loaded by `fli` (Zfa), selected by the rs1 field.
+    pub fn fli_constant(&self) -> Option<&'static str> {
+        fli_constant(self.opcode, self.into())
+    }
+
+    /// Returns the rounding mode of floating-point operations which have it (funct3).
+    pub fn rounding_mode(&self) -> Option<RoundingMode> {
+        rounding_mode(self.opcode, self.into())
+    }
 }
 
 impl InstructionTrait for RInstruction {
@@ -76,6 +89,9 @@ impl InstructionTrait for RInstruction {
     }
 
     fn mnemonic(&self) -> Option<Mnemonic> {
+        if self.opcode.is_float() {
+            return find_float_instruction(self.into()).map(|(mnemonic, _, _)| mnemonic);
+        }
         get_mnemonic(self.opcode, Some(self.funct3), Some(self.funct7))
     }
 
@@ -124,15 +140,24 @@ impl From<&RInstruction> for u32 {
     }
 }
 
+/// Floating-point operations use the register file of each operand, and their
+/// rounding mode is appended unless it's dynamic (e.g. `fadd.s fa0, fa1, fa2, rtz`).
 impl fmt::Display for RInstruction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let bits = u32::from(self);
+        let mut operands: Vec<String> = [self.rd, self.rs1, self.rs2]
+            .into_iter()
+            .zip(register_classes(self.opcode, bits))
+            .filter_map(|(reg, class)| class.map(|class| register_name(reg, class)))
+            .collect();
+        operands.extend(self.fli_constant().map(String::from));
+        operands.extend(rounding_mode_operand(self.opcode, bits));
+
         write!(
             f,
-            "{} {}, {}, {}",
+            "{} {}",
             self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()),
-            self.rd,
-            self.rs1,
-            self.rs2
+            operands.join(", ")
         )
     }
 }
@@ -152,4 +177,29 @@ mod test {
         assert_instr(0x02b504b3); // mul s1, a0, a1
         assert_instr(0x00b574b3); // and s1, a0, a1
     }
+
+    #[test]
+    fn test_float_operands() -> Result<(), RISCVError> {
+        for (bits, text) in [
+            (0x00c5f553, "fadd.s fa0, fa1, fa2"),
+            (0x00c59553, "fadd.s fa0, fa1, fa2, rtz"),
+            (0x5805f553, "fsqrt.s fa0, fa1"),
+            (0xc0159553, "fcvt.wu.s a0, fa1, rtz"),
+            (0xd205f553, "fcvt.d.w fa0, a1"),
+            (0xa0c5a553, "feq.s a0, fa1, fa2"),
+            (0xe0059553, "fclass.s a0, fa1"),
+            (0xf0050553, "fmv.w.x fa0, a0"),
+            (0x22c58553, "fsgnj.d fa0, fa1, fa2"),
+            (0xf0180553, "fli.s fa0, 1.0"),
+        ] {
+            let instr = RInstruction::try_from(bits)?;
+            assert_eq!(text, instr.to_string());
+        }
+
+        let fadd = RInstruction::try_from(0x00c59553)?;
+        assert_eq!(Some(RoundingMode::Rtz), fadd.rounding_mode());
+        let fsgnj = RInstruction::try_from(0x22c58553)?;
+        assert_eq!(None, fsgnj.rounding_mode());
+        Ok(())
+    }
 }
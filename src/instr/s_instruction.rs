@@ -1,8 +1,9 @@
 use std::fmt;
 
+use super::float::{register_classes, register_name};
 use super::InstructionTrait;
 use crate::config::UNKNOWN_MNEMONIC;
-use crate::data::get_mnemonic;
+use crate::data::{find_float_instruction, get_mnemonic};
 use crate::error::RISCVError;
 use crate::model::{
     Funct3, Immediate, InstructionFormat, Mnemonic, Opcode, RawBitsConverter, Register,
@@ -66,6 +67,9 @@ impl InstructionTrait for SInstruction {
     }
 
     fn mnemonic(&self) -> Option<Mnemonic> {
+        if self.opcode.is_float() {
+            return find_float_instruction(self.into()).map(|(mnemonic, _, _)| mnemonic);
+        }
         get_mnemonic(self.opcode, Some(self.funct3), None)
     }
 
@@ -117,11 +121,12 @@ impl From<&SInstruction> for u32 {
 
 impl fmt::Display for SInstruction {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [_, _, rs2, _] = register_classes(self.opcode, self.into());
         write!(
             f,
             "{} {}, {}({})",
             self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()),
-            self.rs2,
+            rs2.map_or(self.rs2.to_string(), |class| register_name(self.rs2, class)),
             self.imm,
             self.rs1
         )
@@ -0,0 +1,383 @@
+use std::fmt;
+
+use super::InstructionTrait;
+use crate::config::UNKNOWN_MNEMONIC;
+use crate::data::find_vector_instruction;
+use crate::error::RISCVError;
+use crate::model::{
+    FRegister, Funct3, Funct6, InstructionFormat, Mnemonic, Opcode, Register, VRegister,
+    VectorCategory, VectorOperand, Vtype,
+};
+
+const OP_V: u8 = 0b1010111;
+const OP_VE: u8 = 0b1110111;
+const LOAD_FP: u8 = 0b0000111;
+const STORE_FP: u8 = 0b0100111;
+
+/// Checks whether the encoding belongs to the V extension (or the vector crypto
+/// extensions). Vector loads and stores share their opcodes with scalar
+/// floating-point ones and are told apart by the width (funct3).
+pub(crate) fn is_vector(instr: u32) -> bool {
+    let funct3 = (instr >> 12) & 0b111;
+    match (instr & 0x7f) as u8 {
+        OP_V | OP_VE => true,
+        LOAD_FP | STORE_FP => matches!(funct3, 0b000 | 0b101 | 0b110 | 0b111),
+        _ => false,
+    }
+}
+
+/// Instruction of the V extension.
+///
+/// Arithmetic instructions keep the operation in `funct6` and the category of
+/// operands in funct3. For loads and stores the upper bits hold `nf`, `mew` and
+/// `mop` fields instead, and funct3 is the element width. Operations are masked
+/// by `v0` when `vm` is cleared.
+///
+/// The register fields are kept as encoded (at the positions of rd, rs1 and rs2)
+/// and their meaning depends on the instruction, see [`VInstruction::operands`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct VInstruction {
+    opcode: Opcode,
+    funct3: Funct3,
+    funct6: Funct6,
+    vm: bool,
+    rd: Register,
+    rs1: Register,
+    rs2: Register,
+}
+
+impl VInstruction {
+    pub fn funct3(&self) -> Funct3 {
+        self.funct3
+    }
+
+    /// Returns the upper six bits. For loads and stores they hold `nf`, `mew` and `mop`.
+    pub fn funct6(&self) -> Funct6 {
+        self.funct6
+    }
+
+    /// Returns `true` if the operation is unmasked, `false` if it's masked by `v0`.
+    pub fn vm(&self) -> bool {
+        self.vm
+    }
+
+    pub fn category(&self) -> VectorCategory {
+        VectorCategory::from_bits(self.opcode.into(), self.funct3.into())
+    }
+
+    fn is_memory_access(&self) -> bool {
+        matches!(
+            self.category(),
+            VectorCategory::Load | VectorCategory::Store
+        )
+    }
+
+    /// Returns the operands in the order of the assembly form (empty if the
+    /// instruction is not recognized).
+    pub fn operands(&self) -> &'static [VectorOperand] {
+        find_vector_instruction(self.into()).map_or(&[], |(_, operands)| operands)
+    }
+
+    fn has_operand(&self, operand: VectorOperand) -> bool {
+        self.operands().contains(&operand)
+    }
+
+    fn vregister(&self, reg: Register) -> VRegister {
+        VRegister::from_vd_bits(reg.into_rd_bits())
+    }
+
+    pub fn vd(&self) -> Option<VRegister> {
+        self.has_operand(VectorOperand::Vd)
+            .then(|| self.vregister(self.rd))
+    }
+
+    pub fn vs1(&self) -> Option<VRegister> {
+        self.has_operand(VectorOperand::Vs1)
+            .then(|| self.vregister(self.rs1))
+    }
+
+    pub fn vs2(&self) -> Option<VRegister> {
+        self.has_operand(VectorOperand::Vs2)
+            .then(|| self.vregister(self.rs2))
+    }
+
+    /// Returns the register stored to memory by vector stores
+    pub fn vs3(&self) -> Option<VRegister> {
+        self.has_operand(VectorOperand::Vs3)
+            .then(|| self.vregister(self.rd))
+    }
+
+    /// Returns the integer destination register
+    pub fn rd(&self) -> Option<Register> {
+        self.has_operand(VectorOperand::Rd).then_some(self.rd)
+    }
+
+    /// Returns the number of the floating-point destination register
+    pub fn fd(&self) -> Option<u8> {
+        self.has_operand(VectorOperand::Fd).then(|| self.rd.into())
+    }
+
+    /// Returns the number of the floating-point source register (rs1 field)
+    pub fn fs1(&self) -> Option<u8> {
+        self.has_operand(VectorOperand::Fs1)
+            .then(|| self.rs1.into())
+    }
+
+    /// Returns the integer register read from the rs1 field (including the base address)
+    pub fn rs1(&self) -> Option<Register> {
+        (self.has_operand(VectorOperand::Rs1) || self.has_operand(VectorOperand::Base))
+            .then_some(self.rs1)
+    }
+
+    /// Returns the integer register read from the rs2 field (e.g. the stride)
+    pub fn rs2(&self) -> Option<Register> {
+        self.has_operand(VectorOperand::Rs2).then_some(self.rs2)
+    }
+
+    /// Returns the immediate operand (`simm5`, `uimm5` or `uimm6`), except `vtype`
+    pub fn imm(&self) -> Option<i32> {
+        let bits = u8::from(self.rs1);
+        self.operands().iter().find_map(|op| match op {
+            VectorOperand::Simm5 => Some(i32::from((bits as i8) << 3 >> 3)),
+            VectorOperand::Uimm5 => Some(i32::from(bits)),
+            VectorOperand::Uimm6 => {
+                Some(i32::from(bits) | i32::from(u8::from(self.funct6) & 1) << 5)
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the `vtype` immediate of `vsetvli` and `vsetivli`
+    pub fn vtype(&self) -> Option<Vtype> {
+        if !self.has_operand(VectorOperand::Vtype) {
+            return None;
+        }
+        let bits = u32::from(self) >> 20;
+        // vsetivli has a 10-bit immediate, vsetvli an 11-bit one
+        let mask = if bits >> 10 == 0b11 { 0x3ff } else { 0x7ff };
+        Some(Vtype::from((bits & mask) as u16))
+    }
+
+    /// Returns the `nf` field of loads and stores, the number of fields
+    /// in a segment minus one (or the number of whole registers minus one)
+    pub fn nf(&self) -> Option<u8> {
+        self.is_memory_access().then(|| u8::from(self.funct6) >> 3)
+    }
+
+    /// Returns the `mew` bit of loads and stores (extended element width)
+    pub fn mew(&self) -> Option<bool> {
+        self.is_memory_access()
+            .then(|| u8::from(self.funct6) & 0b100 != 0)
+    }
+
+    /// Returns the addressing mode (`mop`) of loads and stores: unit-stride (`0b00`),
+    /// indexed-unordered (`0b01`), strided (`0b10`) or indexed-ordered (`0b11`)
+    pub fn mop(&self) -> Option<u8> {
+        self.is_memory_access()
+            .then(|| u8::from(self.funct6) & 0b11)
+    }
+
+    /// Returns the additional unit-stride load mode (`lumop`), held by the rs2 field
+    pub fn lumop(&self) -> Option<u8> {
+        (self.category() == VectorCategory::Load && self.mop() == Some(0)).then(|| self.rs2.into())
+    }
+
+    /// Returns the additional unit-stride store mode (`sumop`), held by the rs2 field
+    pub fn sumop(&self) -> Option<u8> {
+        (self.category() == VectorCategory::Store && self.mop() == Some(0)).then(|| self.rs2.into())
+    }
+
+    /// Returns the width of memory elements in bits (of the indices for indexed accesses)
+    pub fn eew(&self) -> Option<u16> {
+        if !self.is_memory_access() {
+            return None;
+        }
+        match u8::from(self.funct3) {
+            0b000 => Some(8),
+            0b101 => Some(16),
+            0b110 => Some(32),
+            _ => Some(64),
+        }
+    }
+}
+
+impl InstructionTrait for VInstruction {
+    fn opcode(&self) -> &Opcode {
+        &self.opcode
+    }
+
+    fn format(&self) -> &InstructionFormat {
+        &InstructionFormat::V
+    }
+
+    fn mnemonic(&self) -> Option<Mnemonic> {
+        find_vector_instruction(self.into()).map(|(mnemonic, _)| mnemonic)
+    }
+
+    /// Immediates are kept in the register and funct6 fields.
+    fn immediate_bits(&self) -> u32 {
+        0
+    }
+}
+
+impl TryFrom<u32> for VInstruction {
+    type Error = RISCVError;
+
+    fn try_from(instr: u32) -> Result<Self, Self::Error> {
+        if !is_vector(instr) {
+            return Err(RISCVError::InvalidOpcode);
+        }
+
+        Ok(Self {
+            opcode: Opcode::try_from(instr)?,
+            funct3: Funct3::from(instr),
+            funct6: Funct6::from(instr),
+            vm: (instr >> 25) & 1 == 1,
+            rd: Register::from_rd_bits(instr),
+            rs1: Register::from_rs1_bits(instr),
+            rs2: Register::from_rs2_bits(instr),
+        })
+    }
+}
+
+impl From<VInstruction> for u32 {
+    fn from(instr: VInstruction) -> Self {
+        u32::from(&instr)
+    }
+}
+
+impl From<&VInstruction> for u32 {
+    fn from(instr: &VInstruction) -> u32 {
+        u32::from(instr.opcode)
+            | u32::from(instr.funct3)
+            | u32::from(instr.funct6)
+            | u32::from(instr.vm) << 25
+            | instr.rd.into_rd_bits()
+            | instr.rs1.into_rs1_bits()
+            | instr.rs2.into_rs2_bits()
+    }
+}
+
+/// Formats the instruction like binutils, e.g. `vadd.vv v1, v2, v3, v0.t`.
+impl fmt::Display for VInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use VectorOperand::*;
+
+        let operands: Vec<String> = self
+            .operands()
+            .iter()
+            .filter_map(|op| match op {
+                Vd | Vs3 => Some(self.vregister(self.rd).to_string()),
+                Vs1 => Some(self.vregister(self.rs1).to_string()),
+                Vs2 => Some(self.vregister(self.rs2).to_string()),
+                Rd => Some(self.rd.to_string()),
+                Fd => Some(FRegister::from_rd_bits(self.rd.into_rd_bits()).to_string()),
+                Rs1 => Some(self.rs1.to_string()),
+                Fs1 => Some(FRegister::from_rs1_bits(self.rs1.into_rs1_bits()).to_string()),
+                Rs2 => Some(self.rs2.to_string()),
+                Base => Some(format!("({})", self.rs1)),
+                Simm5 | Uimm5 | Uimm6 => self.imm().map(|imm| imm.to_string()),
+                Vtype => self.vtype().map(|vtype| vtype.to_string()),
+                Mask => (!self.vm).then(|| "v0.t".to_string()),
+                Carry => Some(VRegister::v0().to_string()),
+            })
+            .collect();
+
+        write!(
+            f,
+            "{} {}",
+            self.mnemonic().unwrap_or(UNKNOWN_MNEMONIC.into()),
+            operands.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn disasm(bits: u32) -> Result<String, RISCVError> {
+        let instr = VInstruction::try_from(bits)?;
+        assert_eq!(bits, u32::from(instr));
+        Ok(instr.to_string())
+    }
+
+    #[test]
+    fn test_arithmetic() -> Result<(), RISCVError> {
+        assert_eq!("vadd.vv v1, v2, v3, v0.t", disasm(0x002180d7)?);
+        assert_eq!("vadd.vv v1, v2, v3", disasm(0x022180d7)?);
+        assert_eq!("vadd.vx v1, v2, a0", disasm(0x022540d7)?);
+        assert_eq!("vadd.vi v1, v2, -16", disasm(0x022830d7)?);
+        assert_eq!("vfadd.vf v1, v2, fa0", disasm(0x022550d7)?);
+        assert_eq!("vfmv.f.s fa0, v2", disasm(0x42201557)?);
+        assert_eq!("vmacc.vv v1, v3, v2, v0.t", disasm(0xb421a0d7)?);
+        assert_eq!("vmerge.vvm v1, v2, v3, v0", disasm(0x5c2180d7)?);
+        assert_eq!("vmv.x.s a0, v2", disasm(0x42202557)?);
+
+        let instr = VInstruction::try_from(0x002180d7)?;
+        assert_eq!(VectorCategory::Opivv, instr.category());
+        assert_eq!(0, u8::from(instr.funct6()));
+        assert!(!instr.vm());
+        assert_eq!(VRegister::try_from(3).ok(), instr.vs1());
+        assert_eq!(None, instr.rs1());
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration() -> Result<(), RISCVError> {
+        assert_eq!("vsetvli a0, a1, e32,m2,ta,mu", disasm(0x0515f557)?);
+        assert_eq!("vsetivli a0, 4, e8,m1,tu,mu", disasm(0xc0027557)?);
+        assert_eq!("vsetvl a0, a1, a2", disasm(0x80c5f557)?);
+
+        let instr = VInstruction::try_from(0x0515f557)?;
+        assert_eq!(VectorCategory::Opcfg, instr.category());
+        assert_eq!(Some(Vtype::from(0b0101_0001)), instr.vtype());
+        Ok(())
+    }
+
+    #[test]
+    fn test_memory() -> Result<(), RISCVError> {
+        assert_eq!("vle32.v v1, (a0)", disasm(0x02056087)?);
+        assert_eq!("vlse32.v v1, (a0), a1", disasm(0x0ab56087)?);
+        assert_eq!("vluxei32.v v1, (a0), v2, v0.t", disasm(0x04256087)?);
+        assert_eq!("vse32.v v1, (a0)", disasm(0x020560a7)?);
+        assert_eq!("vlseg3e16.v v4, (a0), v0.t", disasm(0x40055207)?);
+
+        let instr = VInstruction::try_from(0x40055207)?;
+        assert_eq!(VectorCategory::Load, instr.category());
+        assert_eq!(Some(2), instr.nf());
+        assert_eq!(Some(false), instr.mew());
+        assert_eq!(Some(0), instr.mop());
+        assert_eq!(Some(0), instr.lumop());
+        assert_eq!(Some(16), instr.eew());
+        assert_eq!(Some(Register::a0()), instr.rs1());
+
+        let fault_first = VInstruction::try_from(0x03056087)?; // vle32ff.v v1, (a0)
+        assert_eq!(Some(0b10000), fault_first.lumop());
+        assert_eq!(Some("vle32ff_v".into()), fault_first.mnemonic());
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_vector() {
+        // flw and fsw share the opcodes of vector loads and stores
+        assert!(!is_vector(0x00452507));
+        assert!(!is_vector(0x00a52227));
+        assert!(VInstruction::try_from(0x00452507).is_err());
+    }
+
+    #[test]
+    fn test_format_float_scalar() {
+        use crate::disasm::{DisasmConfig, InstructionFormatter};
+        use crate::instr::Instruction;
+
+        let formatter = InstructionFormatter::new(DisasmConfig::default());
+        for (bits, expected) in [
+            (0x022550d7, "vfadd.vf v1, v2, fa0"),
+            (0x42201557, "vfmv.f.s fa0, v2"),
+        ] {
+            let instr = Instruction::try_from(bits).unwrap();
+            assert_eq!(expected, formatter.instruction(&instr));
+        }
+    }
+}
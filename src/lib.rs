@@ -9,10 +9,27 @@
 //! - **RISC-V ISA Representation**
 //!   - Strongly typed models for various instruction components: opcodes, funct3, funct7, immediate values, and registers.
 //!   - Use of Rust’s advanced type system to ensure that only valid values can be constructed.
+//!   - Typed operands of instructions, and the registers they actually read and write.
+//!   - Classification of instructions (branches, calls, returns, memory accesses, fences, ...).
 //!
 //! - **Disassembler**
 //!   - An iterator-based disassembler that reads a binary source and produces structured instruction representations.
 //!   - Extensible design to support custom instructions and extensions.
+//!   - ELF32/ELF64 loader disassembling executable sections at their addresses.
+//!   - Symbol labels (from ELF `.symtab`, map files, or built programmatically) in objdump-style output.
+//!   - Control-flow graphs of disassembled code, with basic blocks and Graphviz DOT export.
+//!   - Recursive traversal from entry points and symbols, reporting unreached bytes (jump tables, literal pools, padding) as data directives.
+//!
+//! - **Assembly Parser**
+//!   - Parses instructions (including common pseudo-instructions) from their textual form.
+//!   - Two-pass assembler with labels and directives, producing a flat binary.
+//!   - ELF writer packaging assembled code as an executable or a relocatable object.
+//!   - Errors point at the offending line and column.
+//!
+//! - **Simulator**
+//!   - Interpreter of RV32I/RV64I code, with a pluggable memory and precise traps.
+//!   - M and A extensions, with the defined results of division by zero and `lr`/`sc` reservations.
+//!   - F, D and Zfh extensions, bit-exact with IEEE 754 rounding, `fflags` and NaN-boxing.
 //!
 //! - **Idiomatic Rust Design**
 //!   - Minimal runtime overhead with extensive compile-time checks.
@@ -22,9 +39,8 @@
 //! The current version of the library has some limitations, that are planned to be addressed
 //! in the future versions:
 //!
-//! - support for compressed instruction (16-bit instructions),
 //! - support for custom instructions of size different than 32-bits,
-//! - assembly code parsing
+//! - assembly of compressed instructions
 //!
 //! ## Examples of use
 //!
@@ -135,23 +151,66 @@
 //! ```
 //! # use riscv_isa::{Instruction, InstructionTrait, RISCVError};
 //! # fn test() -> Result<(), RISCVError> {
-//! let instr = Instruction::try_from(0x00a5d463)?;
+//! let instr = Instruction::try_from(0x1ca5c263)?;
 //! assert_eq!("B", instr.format().to_string());
-//! assert_eq!("BLT a1, a0, 0x101c4", instr.to_string());
+//! assert_eq!(Some(0x1c4), instr.pc_offset());
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Branch and jump targets are printed as absolute addresses when the formatter
+//! knows the instruction's address (configurable with `target_format`):
+//!
+//! ```
+//! # use riscv_isa::{DisasmConfig, Instruction, InstructionFormatter, RISCVError, TargetFormat};
+//! # fn test() -> Result<(), RISCVError> {
+//! let instr = Instruction::try_from(0x1ca5c263)?;
+//! let mut config = DisasmConfig::default();
+//! config.target_format = TargetFormat::Both;
+//! let formatter = InstructionFormatter::new(config);
+//! assert_eq!("blt a1, a0, 0x1c4 <0x101c4>", formatter.instruction_at(&instr, 0x10000));
+//! # Ok(())
+//! # }
+//! # test().unwrap();
+//! ```
+//!
+//! Instructions can also be parsed from assembly:
+//!
+//! ```
+//! # use riscv_isa::{AsmParser, Instruction, ParseError, Xlen};
+//! # fn test() -> Result<(), ParseError> {
+//! let instr: Instruction = "blt a1, a0, 0x1c4".parse()?;
+//! assert_eq!(0x1ca5c263, u32::from(instr));
+//!
+//! let parser = AsmParser::new(Xlen::Rv64);
+//! let program = parser.parse("li a0, 42\nret")?;
+//! assert_eq!(2, program.len());
+//!
+//! let error = parser.parse_line("addi a0, x32, 1").unwrap_err();
+//! assert_eq!("1:10: expected register, found `x32`", error.to_string());
+//! # Ok(())
+//! # }
+//! # test().unwrap();
+//! ```
 
+mod asm;
+mod cfg;
 mod config;
 pub mod data;
 mod disasm;
+mod elf;
 mod error;
 mod instr;
 mod model;
+mod sim;
 pub mod utils;
 
+pub use asm::*;
+pub use cfg::*;
 pub use config::*;
 pub use disasm::*;
+pub use elf::*;
 pub use error::RISCVError;
 pub use instr::*;
 pub use model::*;
+pub use sim::*;
@@ -1,38 +1,102 @@
+pub mod asm;
+pub mod cfg;
 pub mod config;
 pub mod data;
 mod disasm;
+pub mod elf;
 mod error;
 pub mod instr;
 pub mod model;
+pub mod sim;
 pub mod utils;
 
 pub use disasm::*;
+use elf::ElfFile;
 pub use error::RISCVError;
 
 use std::env;
 use std::fs::File;
-use std::io::{BufReader, Result};
+use std::io::{BufReader, Cursor, Read, Result};
 
 fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        eprintln!("Usage: {} <filename>", args[0]);
+        eprintln!("Usage: {} <filename> [mapfile]", args[0]);
         std::process::exit(1);
     }
     let filename = &args[1];
 
-    let file = File::open(filename)?;
-    let reader = BufReader::new(file);
+    let mut data = Vec::new();
+    File::open(filename)?.read_to_end(&mut data)?;
 
-    let mut config = DisasmConfig::default();
-    config.mnemonic_uppercase = false;
-    config.mnemonic_separator = "\t".to_string();
-    config.register_separator = "\t".to_string();
+    let config = DisasmConfig {
+        mnemonic_uppercase: false,
+        mnemonic_separator: "\t".to_string(),
+        register_separator: "\t".to_string(),
+        ..Default::default()
+    };
 
-    let mut disasm = Disasm::with_config(reader, config);
+    let symbols = match args.get(2) {
+        Some(mapfile) => read_symbols(mapfile)?,
+        None => SymbolTable::new(),
+    };
+
+    if data.starts_with(b"\x7fELF") {
+        return disassemble_elf(data, config, symbols);
+    }
+
+    let mut disasm = Disasm::with_config(Cursor::new(data), config).with_symbols(symbols);
     if let Err(e) = disasm.print_all() {
         println!("Error disassembling instruction: {:?}", e);
     }
 
     Ok(())
 }
+
+/// Reads the symbols from a map file (e.g. produced by `nm`).
+fn read_symbols(filename: &str) -> Result<SymbolTable> {
+    match SymbolTable::from_map_file(BufReader::new(File::open(filename)?)) {
+        Ok(symbols) => Ok(symbols),
+        Err(e) => {
+            eprintln!("Error reading map file: {:?}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Disassembles executable sections of an ELF file, each starting at its address.
+/// The symbols of the map file, if given, are used instead of the ELF symbol table.
+fn disassemble_elf(data: Vec<u8>, config: DisasmConfig, symbols: SymbolTable) -> Result<()> {
+    let elf = match ElfFile::parse(data) {
+        Ok(elf) => elf,
+        Err(e) => {
+            eprintln!("Error reading ELF file: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    for section in elf.executable_sections() {
+        println!("\nDisassembly of section {}:\n", section.name);
+        let config = DisasmConfig {
+            start_addr: section.addr,
+            mnemonic_separator: config.mnemonic_separator.clone(),
+            register_separator: config.register_separator.clone(),
+            ..elf.disasm_config()
+        };
+        match elf.disasm_with_config(section, config) {
+            Ok(disasm) => {
+                let mut disasm = if symbols.is_empty() {
+                    disasm
+                } else {
+                    disasm.with_symbols(symbols.clone())
+                };
+                if let Err(e) = disasm.print_all() {
+                    println!("Error disassembling instruction: {:?}", e);
+                }
+            }
+            Err(e) => eprintln!("Error reading section {}: {}", section.name, e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use super::Funct3;
+use crate::error::RISCVError;
+use std::fmt;
+
+/// Width of the memory operand of an atomic instruction, encoded in funct3.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AmoWidth {
+    /// Byte (Zabha)
+    B,
+    /// Halfword (Zabha)
+    H,
+    /// Word
+    W,
+    /// Doubleword (RV64, or `amocas.d` on a register pair in RV32)
+    D,
+    /// Quadword (`amocas.q`)
+    Q,
+}
+
+impl TryFrom<Funct3> for AmoWidth {
+    type Error = RISCVError;
+
+    fn try_from(funct3: Funct3) -> Result<Self, Self::Error> {
+        use AmoWidth::*;
+        match u8::from(funct3) {
+            0b000 => Ok(B),
+            0b001 => Ok(H),
+            0b010 => Ok(W),
+            0b011 => Ok(D),
+            0b100 => Ok(Q),
+            _ => Err(RISCVError::InvalidFunctValue(3)),
+        }
+    }
+}
+
+impl From<AmoWidth> for Funct3 {
+    fn from(width: AmoWidth) -> Self {
+        use AmoWidth::*;
+        let bits: u8 = match width {
+            B => 0b000,
+            H => 0b001,
+            W => 0b010,
+            D => 0b011,
+            Q => 0b100,
+        };
+        Funct3::from(u32::from(bits) << 12)
+    }
+}
+
+/// Formats the width as the mnemonic suffix (e.g. `w` in `amoadd.w`).
+impl fmt::Display for AmoWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let suffix = format!("{:?}", self).to_lowercase();
+        write!(f, "{}", suffix)
+    }
+}
@@ -0,0 +1,160 @@
+use super::{InstructionFormat, Xlen};
+
+/// Operation encoded by a 16-bit (compressed) instruction.
+///
+/// Unlike 32-bit instructions, compressed ones can't be identified by the opcode and funct
+/// fields alone, so every operation of the C extension (and Zcb) has its own variant.
+/// Some encodings are shared between operations of different base ISAs (e.g. `c.flw`
+/// in RV32 and `c.ld` in RV64), see [`CompressedOp::supports`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum CompressedOp {
+    Addi4spn,
+    Fld,
+    Lw,
+    Flw,
+    Lbu,
+    Lhu,
+    Lh,
+    Sb,
+    Sh,
+    Fsd,
+    Sw,
+    Fsw,
+    Nop,
+    Addi,
+    Jal,
+    Li,
+    Addi16sp,
+    Lui,
+    Srli,
+    Srai,
+    Andi,
+    Sub,
+    Xor,
+    Or,
+    And,
+    Mul,
+    ZextB,
+    SextB,
+    ZextH,
+    SextH,
+    Not,
+    J,
+    Beqz,
+    Bnez,
+    Slli,
+    Fldsp,
+    Lwsp,
+    Flwsp,
+    Jr,
+    Mv,
+    Ebreak,
+    Jalr,
+    Add,
+    Fsdsp,
+    Swsp,
+    Fswsp,
+    Ld,
+    Sd,
+    Addiw,
+    Subw,
+    Addw,
+    ZextW,
+    Ldsp,
+    Sdsp,
+}
+
+impl CompressedOp {
+    /// All compressed operations, in the order of their encodings
+    /// (RV32 ones first, followed by RV64-only ones).
+    pub const ALL: [CompressedOp; 54] = {
+        use CompressedOp::*;
+        [
+            Addi4spn, Fld, Lw, Flw, Lbu, Lhu, Lh, Sb, Sh, Fsd, Sw, Fsw, Nop, Addi, Jal, Li,
+            Addi16sp, Lui, Srli, Srai, Andi, Sub, Xor, Or, And, Mul, ZextB, SextB, ZextH, SextH,
+            Not, J, Beqz, Bnez, Slli, Fldsp, Lwsp, Flwsp, Jr, Mv, Ebreak, Jalr, Add, Fsdsp, Swsp,
+            Fswsp, Ld, Sd, Addiw, Subw, Addw, ZextW, Ldsp, Sdsp,
+        ]
+    };
+
+    /// Checks whether the operation is defined for the given base ISA.
+    pub fn supports(&self, xlen: Xlen) -> bool {
+        use CompressedOp::*;
+        match self {
+            Jal | Flw | Fsw | Flwsp | Fswsp => xlen == Xlen::Rv32,
+            Ld | Sd | Addiw | Subw | Addw | ZextW | Ldsp | Sdsp => xlen >= Xlen::Rv64,
+            _ => true,
+        }
+    }
+
+    /// Returns the format of the compressed instruction.
+    pub fn format(&self) -> &'static InstructionFormat {
+        use CompressedOp::*;
+        use InstructionFormat as F;
+        match self {
+            Jr | Mv | Ebreak | Jalr | Add => &F::CR,
+            Nop | Addi | Li | Addi16sp | Lui | Slli | Fldsp | Lwsp | Flwsp | Addiw | Ldsp => &F::CI,
+            Fsdsp | Swsp | Fswsp | Sdsp => &F::CSS,
+            Addi4spn => &F::CIW,
+            Fld | Lw | Flw | Lbu | Lhu | Lh | Ld => &F::CL,
+            Fsd | Sw | Fsw | Sb | Sh | Sd => &F::CS,
+            Sub | Xor | Or | And | Mul | ZextB | SextB | ZextH | SextH | Not | Subw | Addw
+            | ZextW => &F::CA,
+            Srli | Srai | Andi | Beqz | Bnez => &F::CB,
+            Jal | J => &F::CJ,
+        }
+    }
+
+    /// Returns the major opcode of the 32-bit instruction the operation is equivalent to.
+    /// It depends on XLEN only for `c.zext.h`, as `zext.h` is encoded in OP-32 in RV64.
+    pub fn base_opcode(&self, xlen: Xlen) -> u8 {
+        use CompressedOp::*;
+        match self {
+            ZextH if xlen >= Xlen::Rv64 => 0b0111011,
+            Lw | Lbu | Lhu | Lh | Lwsp | Ld | Ldsp => 0b0000011,
+            Fld | Flw | Fldsp | Flwsp => 0b0000111,
+            Addi4spn | Nop | Addi | Li | Addi16sp | Srli | Srai | Andi | Slli | ZextB | SextB
+            | SextH | Not => 0b0010011,
+            Addiw => 0b0011011,
+            Sw | Sb | Sh | Swsp | Sd | Sdsp => 0b0100011,
+            Fsd | Fsw | Fsdsp | Fswsp => 0b0100111,
+            Sub | Xor | Or | And | Mul | ZextH | Mv | Add => 0b0110011,
+            Lui => 0b0110111,
+            Subw | Addw | ZextW => 0b0111011,
+            Beqz | Bnez => 0b1100011,
+            Jr | Jalr => 0b1100111,
+            Jal | J => 0b1101111,
+            Ebreak => 0b1110011,
+        }
+    }
+
+    /// Returns the funct3 field of the 32-bit instruction the operation is equivalent to
+    /// (zero for formats without funct3).
+    pub fn base_funct3(&self) -> u8 {
+        use CompressedOp::*;
+        match self {
+            Addi4spn | Nop | Addi | Li | Addi16sp | Sb | Sub | Mul | Beqz | Jr | Mv | Ebreak
+            | Jalr | Add | Jal | J | Lui | Addiw | Subw | Addw | ZextW => 0b000,
+            Lh | Sh | Bnez | Slli | SextB | SextH => 0b001,
+            Lw | Flw | Sw | Fsw | Lwsp | Flwsp | Swsp | Fswsp => 0b010,
+            Fld | Fsd | Fldsp | Fsdsp | Ld | Sd | Ldsp | Sdsp => 0b011,
+            Lbu | Xor | Not | ZextH => 0b100,
+            Lhu | Srli | Srai => 0b101,
+            Or => 0b110,
+            And | Andi | ZextB => 0b111,
+        }
+    }
+
+    /// Returns the funct7 field of the 32-bit instruction the operation is equivalent to
+    /// (the function code for shift-immediates, zero for formats without funct7).
+    pub fn base_funct7(&self) -> u8 {
+        use CompressedOp::*;
+        match self {
+            Sub | Subw | Srai => 0b0100000,
+            SextB | SextH => 0b0110000,
+            Mul => 0b0000001,
+            ZextH | ZextW => 0b0000100,
+            _ => 0,
+        }
+    }
+}
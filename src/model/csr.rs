@@ -0,0 +1,107 @@
+use crate::data::{find_csr_from_name, find_csr_name};
+use crate::error::RISCVError;
+use std::fmt;
+
+const CSR_MASK: u32 = 0xfff;
+
+/// Control and status register address, the 12-bit `csr` field of Zicsr instructions.
+///
+/// Standard CSRs are printed by name (e.g. `mstatus`), others as a hex number.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Csr(u16);
+
+impl Csr {
+    /// Finds a standard CSR by its name (case-insensitive).
+    pub fn from_name(name: &str) -> Option<Self> {
+        find_csr_from_name(name).map(Self)
+    }
+
+    pub fn from_csr_bits(instr: u32) -> Self {
+        Self(((instr >> 20) & CSR_MASK) as u16)
+    }
+
+    pub fn into_csr_bits(&self) -> u32 {
+        u32::from(self.0) << 20
+    }
+
+    /// Returns the name of a standard CSR.
+    pub fn name(&self) -> Option<&'static str> {
+        find_csr_name(self.0)
+    }
+
+    /// Checks whether the CSR is read-only (the top two address bits are set).
+    pub fn is_read_only(&self) -> bool {
+        self.0 >> 10 == 0b11
+    }
+
+    /// Returns the lowest privilege level allowed to access the CSR:
+    /// 0 (user), 1 (supervisor), 2 (hypervisor) or 3 (machine).
+    pub fn privilege(&self) -> u8 {
+        ((self.0 >> 8) & 0b11) as u8
+    }
+}
+
+impl TryFrom<u16> for Csr {
+    type Error = RISCVError;
+
+    fn try_from(csr: u16) -> Result<Self, Self::Error> {
+        if u32::from(csr) <= CSR_MASK {
+            Ok(Self(csr))
+        } else {
+            Err(RISCVError::InvalidCsr)
+        }
+    }
+}
+
+impl From<Csr> for u16 {
+    fn from(csr: Csr) -> Self {
+        csr.0
+    }
+}
+
+impl From<Csr> for u32 {
+    fn from(csr: Csr) -> Self {
+        csr.0.into()
+    }
+}
+
+impl fmt::Display for Csr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "0x{:x}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        assert_eq!("mstatus", Csr::try_from(0x300).unwrap().to_string());
+        assert_eq!("satp", Csr::try_from(0x180).unwrap().to_string());
+        assert_eq!("hpmcounter17", Csr::try_from(0xc11).unwrap().to_string());
+        assert_eq!("pmpaddr63", Csr::try_from(0x3ef).unwrap().to_string());
+        assert_eq!("vlenb", Csr::try_from(0xc22).unwrap().to_string());
+        assert_eq!("0x7c0", Csr::try_from(0x7c0).unwrap().to_string());
+
+        assert_eq!(Csr::try_from(0x305).ok(), Csr::from_name("MTVEC"));
+        assert_eq!(None, Csr::from_name("mfoo"));
+        assert_eq!(Err(RISCVError::InvalidCsr), Csr::try_from(0x1000));
+    }
+
+    #[test]
+    fn test_fields() {
+        let csr = Csr::from_csr_bits(0xc0002573); // csrrs a0, cycle, zero
+        assert_eq!(0xc00, u16::from(csr));
+        assert_eq!(0xc0000000, csr.into_csr_bits());
+        assert!(csr.is_read_only());
+        assert_eq!(0, csr.privilege());
+
+        let mstatus = Csr::from_name("mstatus").unwrap();
+        assert!(!mstatus.is_read_only());
+        assert_eq!(3, mstatus.privilege());
+    }
+}
@@ -4,6 +4,7 @@ use super::TryFromOpcodeBinary;
 
 pub enum RISCVExtension {
     I,
+    C,
     Zifencei,
     M,
     A,
@@ -30,12 +31,21 @@ pub enum RISCVExtension {
     Zbkb,
     Zba,
     Zfh,
+    Zfbfmin,
     Zvkned,
     Zvkg,
     Zvknha,
     Zvksh,
     Zvksed,
     Zbkx,
+    Zvbb,
+    Zvbc,
+    Zvfbfmin,
+    Zvfbfwma,
+    Zcb,
+    Zabha,
+    Zacas,
+    Zicfiss,
     Custom,
 }
 
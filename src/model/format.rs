@@ -8,8 +8,19 @@ pub enum InstructionFormat {
     I,
     J,
     R,
+    R4,
     S,
     U,
+    V,
+    CR,
+    CI,
+    CSS,
+    CIW,
+    CL,
+    CS,
+    CA,
+    CB,
+    CJ,
 }
 
 impl fmt::Display for InstructionFormat {
@@ -18,6 +29,14 @@ impl fmt::Display for InstructionFormat {
     }
 }
 
+impl InstructionFormat {
+    /// Returns `true` for the formats of 16-bit (compressed) instructions.
+    pub fn is_compressed(&self) -> bool {
+        use InstructionFormat::*;
+        matches!(self, CR | CI | CSS | CIW | CL | CS | CA | CB | CJ)
+    }
+}
+
 impl TryFrom<u32> for InstructionFormat {
     type Error = RISCVError;
 
@@ -34,11 +53,13 @@ impl TryFromOpcodeBinary for InstructionFormat {
         use InstructionFormat::*;
         match opcode >> 2 {
             0b11000 => Ok(B),
-            0b00100 | 0b00000 | 0b11001 | 0b11100 => Ok(I),
+            0b00100 | 0b00110 | 0b00000 | 0b00001 | 0b00011 | 0b11001 | 0b11100 => Ok(I),
             0b11011 => Ok(J),
-            0b01100 | 0b01011 | 0b01110 => Ok(R),
-            0b01000 => Ok(S),
-            0b01101 => Ok(U),
+            0b01100 | 0b01011 | 0b01110 | 0b10100 => Ok(R),
+            0b10000..=0b10011 => Ok(R4),
+            0b01000 | 0b01001 => Ok(S),
+            0b00101 | 0b01101 => Ok(U),
+            0b10101 | 0b11101 => Ok(V),
             _ => Err(RISCVError::UnrecognizedInstructionFormat),
         }
     }
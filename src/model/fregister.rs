@@ -0,0 +1,177 @@
+use crate::error::RISCVError;
+use std::fmt;
+
+use super::REGISTER_MASK;
+
+/// Floating-point register (`f0`–`f31`) of the F, D, Q and Zfh extensions,
+/// displayed with its ABI name.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct FRegister(u8);
+
+macro_rules! fregister_constructors {
+    ($($name:ident => $index:expr),*) => {
+        $(
+            #[doc = concat!("Constructor for `", stringify!($name), "` register")]
+            pub fn $name() -> Self { Self($index) }
+        )*
+    };
+}
+
+impl FRegister {
+    fregister_constructors! {
+        ft0 => 0,
+        ft1 => 1,
+        ft2 => 2,
+        ft3 => 3,
+        ft4 => 4,
+        ft5 => 5,
+        ft6 => 6,
+        ft7 => 7,
+        fs0 => 8,
+        fs1 => 9,
+        fa0 => 10,
+        fa1 => 11,
+        fa2 => 12,
+        fa3 => 13,
+        fa4 => 14,
+        fa5 => 15,
+        fa6 => 16,
+        fa7 => 17,
+        fs2 => 18,
+        fs3 => 19,
+        fs4 => 20,
+        fs5 => 21,
+        fs6 => 22,
+        fs7 => 23,
+        fs8 => 24,
+        fs9 => 25,
+        fs10 => 26,
+        fs11 => 27,
+        ft8 => 28,
+        ft9 => 29,
+        ft10 => 30,
+        ft11 => 31
+    }
+
+    /// Finds a register by its ABI name (e.g. `fa0`) or its number (e.g. `f10`),
+    /// case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        if let Some(index) = name.strip_prefix('f').and_then(|i| i.parse::<u8>().ok()) {
+            return Self::try_from(index).ok();
+        }
+        (0..32).map(Self).find(|reg| reg.to_string() == name)
+    }
+
+    fn from_instr_bits(instr: u32, shift: u32) -> Self {
+        Self(((instr >> shift) & REGISTER_MASK) as u8)
+    }
+
+    pub fn from_rs1_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 15)
+    }
+
+    pub fn from_rs2_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 20)
+    }
+
+    pub fn from_rs3_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 27)
+    }
+
+    pub fn from_rd_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 7)
+    }
+
+    pub fn into_rs1_bits(&self) -> u32 {
+        u32::from(self.0) << 15
+    }
+
+    pub fn into_rs2_bits(&self) -> u32 {
+        u32::from(self.0) << 20
+    }
+
+    pub fn into_rs3_bits(&self) -> u32 {
+        u32::from(self.0) << 27
+    }
+
+    pub fn into_rd_bits(&self) -> u32 {
+        u32::from(self.0) << 7
+    }
+}
+
+impl TryFrom<u8> for FRegister {
+    type Error = RISCVError;
+
+    fn try_from(reg: u8) -> Result<Self, Self::Error> {
+        if reg < 32 {
+            Ok(Self(reg))
+        } else {
+            Err(RISCVError::InvalidRegister)
+        }
+    }
+}
+
+impl From<FRegister> for u8 {
+    fn from(reg: FRegister) -> Self {
+        reg.0
+    }
+}
+
+impl From<FRegister> for u32 {
+    fn from(reg: FRegister) -> Self {
+        reg.0.into()
+    }
+}
+
+impl fmt::Display for FRegister {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self.0 {
+            0..=7 => format!("ft{}", self.0),
+            8 | 9 => format!("fs{}", self.0 - 8),
+            10..=17 => format!("fa{}", self.0 - 10),
+            18..=27 => format!("fs{}", self.0 - 16),
+            28..=31 => format!("ft{}", self.0 - 20),
+            _ => unreachable!(),
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_abi_names() {
+        let names: Vec<String> = (0..32)
+            .map(|i| FRegister::try_from(i).unwrap().to_string())
+            .collect();
+        assert_eq!("ft0", names[0]);
+        assert_eq!("fs1", names[9]);
+        assert_eq!("fa0", names[10]);
+        assert_eq!("fs2", names[18]);
+        assert_eq!("fs11", names[27]);
+        assert_eq!("ft8", names[28]);
+        assert_eq!("ft11", names[31]);
+        assert!(FRegister::try_from(32).is_err());
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Some(FRegister::fa0()), FRegister::from_name("fa0"));
+        assert_eq!(Some(FRegister::fa0()), FRegister::from_name("f10"));
+        assert_eq!(Some(FRegister::ft11()), FRegister::from_name("FT11"));
+        assert_eq!(None, FRegister::from_name("f32"));
+        assert_eq!(None, FRegister::from_name("a0"));
+    }
+
+    #[test]
+    fn test_fields() {
+        let instr = 12 << 20 | 11 << 15 | 10 << 7;
+        assert_eq!(FRegister::fa2(), FRegister::from_rs2_bits(instr));
+        assert_eq!(FRegister::fa1(), FRegister::from_rs1_bits(instr));
+        assert_eq!(FRegister::fa0(), FRegister::from_rd_bits(instr));
+        assert_eq!(10 << 7, FRegister::fa0().into_rd_bits());
+    }
+}
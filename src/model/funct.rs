@@ -50,8 +50,17 @@ impl<const BITS: u8, const SHIFT: u8> fmt::Display for FunctField<BITS, SHIFT> {
     }
 }
 
+/// Type alias for Funct2 (the `fmt` field of R4-type instructions)
+pub type Funct2 = FunctField<2, 25>;
+
 /// Type alias for Funct3
 pub type Funct3 = FunctField<3, 12>;
 
 /// Type alias for Funct7
 pub type Funct7 = FunctField<7, 25>;
+
+/// Type alias for Funct5 (the operation of atomic instructions)
+pub type Funct5 = FunctField<5, 27>;
+
+/// Type alias for Funct6 (the operation of vector instructions, above the `vm` bit)
+pub type Funct6 = FunctField<6, 26>;
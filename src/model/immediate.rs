@@ -105,13 +105,12 @@ mod test {
         let result = Immediate::<START, END>::try_from(val);
         match err {
             Some(e_exp) => match result {
-                Ok(_) => assert!(false, "Immediate creation expected to fail for {}", val),
+                Ok(_) => panic!("Immediate creation expected to fail for {}", val),
                 Err(e) => assert_eq!(e_exp, e),
             },
             None => match result {
                 Ok(imm) => assert_eq!(val, imm.into()),
-                Err(e) => assert!(
-                    false,
+                Err(e) => panic!(
                     "Immediate creation expected to work for {}, but it failed with {} instead",
                     val, e
                 ),
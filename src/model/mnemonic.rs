@@ -1,6 +1,5 @@
 use std::fmt;
 
-
 #[derive(Debug, PartialEq)]
 pub struct Mnemonic(&'static str);
 
@@ -16,8 +15,10 @@ impl From<Mnemonic> for &'static str {
     }
 }
 
+/// Formats the mnemonic as written in assembly, with dots in place of underscores
+/// (e.g. `fence.i` or `amoadd.w`).
 impl fmt::Display for Mnemonic {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.0.replace('_', "."))
     }
 }
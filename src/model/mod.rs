@@ -1,21 +1,45 @@
+mod amo_width;
+mod compressed_op;
+mod csr;
 mod extension;
 mod format;
+mod fregister;
 mod funct;
 mod immediate;
 mod mnemonic;
 mod opcode;
+mod operand;
 mod raw_bits_converter;
 mod register;
+mod register_class;
+mod rounding_mode;
 mod size;
 mod try_from_opcode_binary;
+mod vector_category;
+mod vector_operand;
+mod vregister;
+mod vtype;
+mod xlen;
 
+pub use amo_width::*;
+pub use compressed_op::*;
+pub use csr::*;
 pub use extension::*;
 pub use format::*;
+pub use fregister::*;
 pub use funct::*;
 pub use immediate::*;
 pub use mnemonic::*;
 pub use opcode::*;
+pub use operand::*;
 pub use raw_bits_converter::*;
 pub use register::*;
+pub use register_class::*;
+pub use rounding_mode::*;
 pub use size::*;
 pub use try_from_opcode_binary::*;
+pub use vector_category::*;
+pub use vector_operand::*;
+pub use vregister::*;
+pub use vtype::*;
+pub use xlen::*;
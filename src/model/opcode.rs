@@ -60,6 +60,16 @@ impl Opcode {
         }
     }
 
+    /// Checks whether the opcode belongs to floating-point instructions (loads, stores,
+    /// fused multiply-add and OP-FP). Vector loads and stores share the opcodes of
+    /// floating-point ones, but they are decoded as vector instructions.
+    pub fn is_float(&self) -> bool {
+        matches!(
+            self.0,
+            0b0000111 | 0b0100111 | 0b1000011 | 0b1000111 | 0b1001011 | 0b1001111 | 0b1010011
+        )
+    }
+
     pub fn instruction_size(&self) -> InstructionSize {
         match InstructionSize::try_from_opcode_binary(self.0) {
             Ok(size) => size,
@@ -0,0 +1,33 @@
+use std::fmt;
+
+use super::{Csr, FRegister, Register, VRegister};
+
+/// An operand of an instruction, as it's written in the assembly form.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Operand {
+    IntReg(Register),
+    FloatReg(FRegister),
+    VecReg(VRegister),
+    Csr(Csr),
+    Imm(i32),
+    /// Target of a branch or jump, as an offset from the address of the instruction
+    Target(i32),
+    /// Memory operand written as `offset(base)`
+    MemOffset {
+        base: Register,
+        offset: i32,
+    },
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::IntReg(reg) => write!(f, "{}", reg),
+            Operand::FloatReg(reg) => write!(f, "{}", reg),
+            Operand::VecReg(reg) => write!(f, "{}", reg),
+            Operand::Csr(csr) => write!(f, "{}", csr),
+            Operand::Imm(imm) | Operand::Target(imm) => write!(f, "0x{:x}", imm),
+            Operand::MemOffset { base, offset } => write!(f, "0x{:x}({})", offset, base),
+        }
+    }
+}
@@ -5,5 +5,6 @@ pub trait RawBitsConverter<T> {
     where
         Self: Sized;
 
+    #[allow(clippy::wrong_self_convention)]
     fn into_raw_bits(&self) -> T;
 }
@@ -51,6 +51,19 @@ impl Register {
         t6 => 31
     }
 
+    /// Finds a register by its ABI name (e.g. `a0`, or `fp` for `s0`) or its
+    /// number (e.g. `x10`), case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_lowercase();
+        if name == "fp" {
+            return Some(Self::s0());
+        }
+        if let Some(index) = name.strip_prefix('x').and_then(|i| i.parse::<u8>().ok()) {
+            return Self::try_from(index).ok();
+        }
+        (0..32).map(Self).find(|reg| reg.to_string() == name)
+    }
+
     fn from_instr_bits(instr: u32, shift: u32) -> Self {
         let bits = (instr >> shift) & REGISTER_MASK;
         match Self::try_from(bits as u8) {
@@ -67,6 +80,10 @@ impl Register {
         Self::from_instr_bits(instr, 20)
     }
 
+    pub fn from_rs3_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 27)
+    }
+
     pub fn from_rd_bits(instr: u32) -> Self {
         Self::from_instr_bits(instr, 7)
     }
@@ -79,6 +96,10 @@ impl Register {
         u32::from(self) << 20
     }
 
+    pub fn into_rs3_bits(&self) -> u32 {
+        u32::from(self) << 27
+    }
+
     pub fn into_rd_bits(&self) -> u32 {
         u32::from(self) << 7
     }
@@ -173,6 +194,17 @@ mod tests {
         assert!(Register::try_from(255).is_err());
     }
 
+    #[test]
+    fn test_from_name() {
+        assert_eq!(Some(Register::a0()), Register::from_name("a0"));
+        assert_eq!(Some(Register::a0()), Register::from_name("X10"));
+        assert_eq!(Some(Register::s0()), Register::from_name("fp"));
+        assert_eq!(Some(Register::t6()), Register::from_name("t6"));
+        assert_eq!(None, Register::from_name("x32"));
+        assert_eq!(None, Register::from_name("x01a"));
+        assert_eq!(None, Register::from_name("fa0"));
+    }
+
     #[test]
     fn test_from_rs1_bits() {
         let instr: u32 = 13 << 15;
@@ -0,0 +1,8 @@
+/// Register file an operand belongs to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RegisterClass {
+    /// Integer registers (`x0`–`x31`), see [`Register`](super::Register)
+    Integer,
+    /// Floating-point registers (`f0`–`f31`), see [`FRegister`](super::FRegister)
+    Float,
+}
@@ -0,0 +1,91 @@
+use super::Funct3;
+use crate::error::RISCVError;
+use std::fmt;
+
+/// Rounding mode of floating-point operations, encoded in funct3 (`rm`).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even
+    Rne,
+    /// Round towards zero
+    Rtz,
+    /// Round down (towards negative infinity)
+    Rdn,
+    /// Round up (towards positive infinity)
+    Rup,
+    /// Round to nearest, ties to max magnitude
+    Rmm,
+    /// Dynamic rounding mode, selected by the `frm` CSR
+    Dyn,
+}
+
+impl RoundingMode {
+    /// Finds a rounding mode by its name (e.g. `rtz`), case-insensitively.
+    pub fn from_name(name: &str) -> Option<Self> {
+        use RoundingMode::*;
+        [Rne, Rtz, Rdn, Rup, Rmm, Dyn]
+            .into_iter()
+            .find(|rm| rm.to_string().eq_ignore_ascii_case(name))
+    }
+}
+
+impl TryFrom<Funct3> for RoundingMode {
+    type Error = RISCVError;
+
+    fn try_from(funct3: Funct3) -> Result<Self, Self::Error> {
+        use RoundingMode::*;
+        match u8::from(funct3) {
+            0b000 => Ok(Rne),
+            0b001 => Ok(Rtz),
+            0b010 => Ok(Rdn),
+            0b011 => Ok(Rup),
+            0b100 => Ok(Rmm),
+            0b111 => Ok(Dyn),
+            _ => Err(RISCVError::InvalidRoundingMode),
+        }
+    }
+}
+
+impl From<RoundingMode> for Funct3 {
+    fn from(rm: RoundingMode) -> Self {
+        use RoundingMode::*;
+        let bits: u8 = match rm {
+            Rne => 0b000,
+            Rtz => 0b001,
+            Rdn => 0b010,
+            Rup => 0b011,
+            Rmm => 0b100,
+            Dyn => 0b111,
+        };
+        Funct3::from(u32::from(bits) << 12)
+    }
+}
+
+/// Formats the rounding mode as an assembly operand (e.g. `rtz`).
+impl fmt::Display for RoundingMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = format!("{:?}", self).to_lowercase();
+        write!(f, "{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion() -> Result<(), RISCVError> {
+        for bits in [0b000u8, 0b001, 0b010, 0b011, 0b100, 0b111] {
+            let funct3 = Funct3::try_from(bits)?;
+            assert_eq!(funct3, Funct3::from(RoundingMode::try_from(funct3)?));
+        }
+        assert_eq!(
+            Err(RISCVError::InvalidRoundingMode),
+            RoundingMode::try_from(Funct3::try_from(0b101u8)?)
+        );
+        assert_eq!("rmm", RoundingMode::Rmm.to_string());
+        assert_eq!(Some(RoundingMode::Rtz), RoundingMode::from_name("RTZ"));
+        assert_eq!(None, RoundingMode::from_name("foo"));
+        Ok(())
+    }
+}
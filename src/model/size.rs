@@ -3,7 +3,7 @@ use std::fmt;
 
 use super::TryFromOpcodeBinary;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 #[non_exhaustive]
 pub enum InstructionSize {
     Size16,
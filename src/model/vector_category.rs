@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// Category of a vector instruction: the operand types of arithmetic instructions
+/// (encoded in funct3 of OP-V), configuration-setting instructions, or a memory access.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum VectorCategory {
+    /// Integer vector-vector
+    Opivv,
+    /// Floating-point vector-vector
+    Opfvv,
+    /// Mask and multiply vector-vector
+    Opmvv,
+    /// Integer vector-immediate
+    Opivi,
+    /// Integer vector-scalar
+    Opivx,
+    /// Floating-point vector-scalar
+    Opfvf,
+    /// Mask and multiply vector-scalar
+    Opmvx,
+    /// Configuration-setting (`vsetvli`, `vsetivli`, `vsetvl`)
+    Opcfg,
+    Load,
+    Store,
+}
+
+impl VectorCategory {
+    pub(crate) fn from_bits(opcode: u8, funct3: u8) -> Self {
+        use VectorCategory::*;
+        match (opcode, funct3) {
+            (0b0000111, _) => Load,
+            (0b0100111, _) => Store,
+            (_, 0b000) => Opivv,
+            (_, 0b001) => Opfvv,
+            (_, 0b010) => Opmvv,
+            (_, 0b011) => Opivi,
+            (_, 0b100) => Opivx,
+            (_, 0b101) => Opfvf,
+            (_, 0b110) => Opmvx,
+            _ => Opcfg,
+        }
+    }
+}
+
+impl fmt::Display for VectorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_uppercase())
+    }
+}
@@ -0,0 +1,40 @@
+/// Operand of a vector instruction, as it appears in the assembly form.
+///
+/// The same encoding fields hold different kinds of operands, depending on the
+/// instruction (e.g. rs1 is a vector register in `vadd.vv`, an integer register
+/// in `vadd.vx` and a floating-point register in `vfadd.vf`).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum VectorOperand {
+    /// Vector destination (rd field)
+    Vd,
+    /// Vector source (rs1 field)
+    Vs1,
+    /// Vector source (rs2 field)
+    Vs2,
+    /// Vector register stored to memory (rd field)
+    Vs3,
+    /// Integer destination
+    Rd,
+    /// Floating-point destination (rd field)
+    Fd,
+    /// Integer source (rs1 field)
+    Rs1,
+    /// Floating-point source (rs1 field)
+    Fs1,
+    /// Integer source (rs2 field), e.g. the stride of strided loads
+    Rs2,
+    /// Base address of loads and stores (rs1 field), written as `(rs1)`
+    Base,
+    /// Signed 5-bit immediate (rs1 field)
+    Simm5,
+    /// Unsigned 5-bit immediate (rs1 field)
+    Uimm5,
+    /// Unsigned 6-bit immediate (rs1 field and bit 26)
+    Uimm6,
+    /// The `vtype` immediate of `vsetvli`/`vsetivli`
+    Vtype,
+    /// Optional `v0.t` mask, present when `vm` is cleared
+    Mask,
+    /// `v0` used as carry-in or merge mask (fixed `vm` cleared)
+    Carry,
+}
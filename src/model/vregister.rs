@@ -0,0 +1,90 @@
+use crate::error::RISCVError;
+use std::fmt;
+
+use super::REGISTER_MASK;
+
+/// Vector register (`v0`–`v31`) of the V extension.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct VRegister(u8);
+
+impl VRegister {
+    /// Constructor for `v0` register, which holds the mask of masked operations
+    pub fn v0() -> Self {
+        Self(0)
+    }
+
+    fn from_instr_bits(instr: u32, shift: u32) -> Self {
+        Self(((instr >> shift) & REGISTER_MASK) as u8)
+    }
+
+    pub fn from_vs1_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 15)
+    }
+
+    pub fn from_vs2_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 20)
+    }
+
+    pub fn from_vd_bits(instr: u32) -> Self {
+        Self::from_instr_bits(instr, 7)
+    }
+
+    pub fn into_vs1_bits(&self) -> u32 {
+        u32::from(self.0) << 15
+    }
+
+    pub fn into_vs2_bits(&self) -> u32 {
+        u32::from(self.0) << 20
+    }
+
+    pub fn into_vd_bits(&self) -> u32 {
+        u32::from(self.0) << 7
+    }
+}
+
+impl TryFrom<u8> for VRegister {
+    type Error = RISCVError;
+
+    fn try_from(reg: u8) -> Result<Self, Self::Error> {
+        if reg < 32 {
+            Ok(Self(reg))
+        } else {
+            Err(RISCVError::InvalidRegister)
+        }
+    }
+}
+
+impl From<VRegister> for u8 {
+    fn from(reg: VRegister) -> Self {
+        reg.0
+    }
+}
+
+impl From<VRegister> for u32 {
+    fn from(reg: VRegister) -> Self {
+        reg.0.into()
+    }
+}
+
+impl fmt::Display for VRegister {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vregister() {
+        assert!(VRegister::try_from(31).is_ok());
+        assert!(VRegister::try_from(32).is_err());
+
+        let instr = 3 << 20 | 2 << 15 | 1 << 7;
+        assert_eq!("v3", VRegister::from_vs2_bits(instr).to_string());
+        assert_eq!("v2", VRegister::from_vs1_bits(instr).to_string());
+        assert_eq!(VRegister::try_from(1), Ok(VRegister::from_vd_bits(instr)));
+        assert_eq!(1 << 7, VRegister::from_vd_bits(instr).into_vd_bits());
+    }
+}
@@ -0,0 +1,88 @@
+use std::fmt;
+
+/// The `vtype` immediate of `vsetvli`/`vsetivli`: selected element width (`vsew`),
+/// register group multiplier (`vlmul`) and the tail/mask agnostic policies (`vta`, `vma`).
+///
+/// Formats like binutils, e.g. `e32,m2,ta,mu`. Encodings with reserved values are
+/// printed as a plain number.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Vtype(u16);
+
+impl Vtype {
+    /// Returns the selected element width in bits, `None` if reserved.
+    pub fn sew(&self) -> Option<u16> {
+        match (self.0 >> 3) & 0b111 {
+            vsew @ 0b000..=0b011 => Some(8 << vsew),
+            _ => None,
+        }
+    }
+
+    /// Returns the register group multiplier as a name (`m1`, `m2`, `m4`, `m8`,
+    /// or fractional `mf2`, `mf4`, `mf8`), `None` if reserved.
+    pub fn lmul(&self) -> Option<&'static str> {
+        match self.0 & 0b111 {
+            0b000 => Some("m1"),
+            0b001 => Some("m2"),
+            0b010 => Some("m4"),
+            0b011 => Some("m8"),
+            0b101 => Some("mf8"),
+            0b110 => Some("mf4"),
+            0b111 => Some("mf2"),
+            _ => None,
+        }
+    }
+
+    /// Checks whether tail elements are agnostic (`ta`), or undisturbed (`tu`).
+    pub fn tail_agnostic(&self) -> bool {
+        self.0 & (1 << 6) != 0
+    }
+
+    /// Checks whether masked-off elements are agnostic (`ma`), or undisturbed (`mu`).
+    pub fn mask_agnostic(&self) -> bool {
+        self.0 & (1 << 7) != 0
+    }
+}
+
+impl From<u16> for Vtype {
+    fn from(bits: u16) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<Vtype> for u16 {
+    fn from(vtype: Vtype) -> Self {
+        vtype.0
+    }
+}
+
+impl fmt::Display for Vtype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (self.sew(), self.lmul(), self.0 >> 8) {
+            (Some(sew), Some(lmul), 0) => write!(
+                f,
+                "e{},{},{},{}",
+                sew,
+                lmul,
+                if self.tail_agnostic() { "ta" } else { "tu" },
+                if self.mask_agnostic() { "ma" } else { "mu" }
+            ),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("e32,m2,ta,mu", Vtype::from(0b0101_0001).to_string());
+        assert_eq!("e8,mf8,tu,ma", Vtype::from(0b1000_0101).to_string());
+        assert_eq!("e64,m1,ta,ma", Vtype::from(0b1101_1000).to_string());
+        // reserved lmul, sew and upper bits
+        assert_eq!("4", Vtype::from(0b100).to_string());
+        assert_eq!("32", Vtype::from(0b10_0000).to_string());
+        assert_eq!("256", Vtype::from(0x100).to_string());
+    }
+}
@@ -0,0 +1,43 @@
+use crate::error::RISCVError;
+use std::fmt;
+
+/// Width of the integer registers (XLEN), which selects the base integer ISA.
+///
+/// Some encodings are only defined for the wider base ISAs (e.g. `ld`, `addiw`,
+/// shift amounts above 31), and some compressed encodings mean different
+/// operations depending on XLEN (e.g. `c.jal` in RV32 is `c.addiw` in RV64).
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum Xlen {
+    #[default]
+    Rv32,
+    Rv64,
+}
+
+impl Xlen {
+    /// Returns the width of the registers in bits.
+    pub fn bits(&self) -> u32 {
+        match self {
+            Xlen::Rv32 => 32,
+            Xlen::Rv64 => 64,
+        }
+    }
+}
+
+impl TryFrom<u32> for Xlen {
+    type Error = RISCVError;
+
+    fn try_from(bits: u32) -> Result<Self, Self::Error> {
+        match bits {
+            32 => Ok(Xlen::Rv32),
+            64 => Ok(Xlen::Rv64),
+            _ => Err(RISCVError::UnsupportedXlen(bits)),
+        }
+    }
+}
+
+impl fmt::Display for Xlen {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RV{}", self.bits())
+    }
+}
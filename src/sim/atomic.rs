@@ -0,0 +1,244 @@
+use super::{execute::sign_extend, Cpu, Memory, Trap};
+use crate::{disasm::Address, instr::AmoInstruction, model::AmoWidth};
+
+impl<M: Memory> Cpu<M> {
+    /// Executes an A extension instruction (or a Zabha or Zacas one), returning the
+    /// value of `rd`: the value loaded from the memory, sign-extended, or for `sc`
+    /// zero on success and one on failure.
+    ///
+    /// `lr` reserves the bytes it loads. `sc` succeeds if they're still reserved, and
+    /// always ends the reservation. Stores of the hart itself don't end it, so
+    /// [`clear_reservation`](Cpu::clear_reservation) can be used to emulate accesses
+    /// of other agents (or a context switch).
+    pub(super) fn atomic(
+        &mut self,
+        instr: &AmoInstruction,
+        name: &str,
+        illegal: Trap,
+    ) -> Result<u64, Trap> {
+        let width = match instr.width() {
+            AmoWidth::B => 1,
+            AmoWidth::H => 2,
+            AmoWidth::W => 4,
+            AmoWidth::D => 8,
+            AmoWidth::Q => 16,
+        };
+        // amocas.d in RV32 and amocas.q operate on register pairs
+        if u32::from(width) * 8 > self.xlen().bits() {
+            return Err(illegal);
+        }
+        let address = self.register(instr.rs1());
+        let src = self.register(instr.rs2());
+
+        let old = match name.split('_').next() {
+            Some("lr") => {
+                let value = self.load(address, width)?;
+                self.set_reservation(Some((address, width)));
+                value
+            }
+            Some("sc") => {
+                if !address.is_multiple_of(Address::from(width)) {
+                    return Err(Trap::StoreAddressMisaligned(address));
+                }
+                // bytes past the end of the address space are never reserved
+                let reserved = self.reservation().is_some_and(|(start, size)| {
+                    let end = address.checked_add(Address::from(width));
+                    let reserved_end = start.checked_add(Address::from(size));
+                    start <= address && end.zip(reserved_end).is_some_and(|(e, r)| e <= r)
+                });
+                if reserved {
+                    self.store(address, width, src)?;
+                }
+                self.set_reservation(None);
+                return Ok(u64::from(!reserved));
+            }
+            Some("amocas") => {
+                let old = self.amo_load(address, width)?;
+                let expected = sign_extend(self.register(instr.rd()), width);
+                if sign_extend(old, width) == expected {
+                    self.store(address, width, src)?;
+                }
+                old
+            }
+            op => {
+                let old = self.amo_load(address, width)?;
+                let new = op
+                    .and_then(|op| amo_op(op, old, src, width))
+                    .ok_or(illegal)?;
+                self.store(address, width, new)?;
+                old
+            }
+        };
+        Ok(sign_extend(old, width))
+    }
+
+    /// Loads the operand of an AMO, which reports faults as a store would.
+    fn amo_load(&mut self, address: Address, width: u8) -> Result<u64, Trap> {
+        self.load(address, width).map_err(|trap| match trap {
+            Trap::LoadAddressMisaligned(a) => Trap::StoreAddressMisaligned(a),
+            Trap::LoadAccessFault(a) => Trap::StoreAccessFault(a),
+            trap => trap,
+        })
+    }
+}
+
+/// Computes the value an AMO stores, from the loaded value and `rs2`, both holding
+/// `width` bytes.
+fn amo_op(op: &str, old: u64, src: u64, width: u8) -> Option<u64> {
+    let (signed_old, signed_src) = (
+        sign_extend(old, width) as i64,
+        sign_extend(src, width) as i64,
+    );
+    let mask = u64::MAX >> (64 - 8 * u32::from(width));
+    let (unsigned_old, unsigned_src) = (old & mask, src & mask);
+    Some(match op {
+        "amoswap" => src,
+        "amoadd" => old.wrapping_add(src),
+        "amoxor" => old ^ src,
+        "amoand" => old & src,
+        "amoor" => old | src,
+        "amomin" => signed_old.min(signed_src) as u64,
+        "amomax" => signed_old.max(signed_src) as u64,
+        "amominu" => unsigned_old.min(unsigned_src),
+        "amomaxu" => unsigned_old.max(unsigned_src),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::AsmParser;
+    use crate::instr::Instruction;
+    use crate::model::{Register, Xlen};
+    use crate::sim::test_utils::run;
+    use crate::sim::FlatMemory;
+
+    const DATA: Address = 0x800;
+
+    fn word(cpu: &mut Cpu<FlatMemory>, address: Address) -> u64 {
+        cpu.load(address, 4).unwrap()
+    }
+
+    #[test]
+    fn test_amo() {
+        let source = "
+            li t0, -5
+            sw t0, 0(a0)
+            li t1, 3
+            amoadd.w a1, t1, (a0)
+            amomin.w a2, t1, (a0)
+            amomaxu.w a3, t1, (a0)
+            amoswap.w.aqrl a4, t0, (a0)
+            amomax.w a5, t1, (a0)
+            ecall
+        ";
+        let (mut cpu, _) = run(Xlen::Rv64, DATA, source);
+        let minus = |n: i64| n as u64;
+        assert_eq!(cpu.register(Register::a1()), minus(-5));
+        assert_eq!(cpu.register(Register::a2()), minus(-2));
+        assert_eq!(cpu.register(Register::a3()), minus(-2));
+        assert_eq!(cpu.register(Register::a4()), minus(-2));
+        assert_eq!(cpu.register(Register::a5()), minus(-5));
+        assert_eq!(word(&mut cpu, DATA), 3);
+    }
+
+    #[test]
+    fn test_lr_sc() {
+        let source = "
+            li t0, 1
+            lr.w a1, (a0)
+            sc.w a2, t0, (a0)
+            sc.w a3, t0, (a0)
+            lr.w a4, (a0)
+            addi t1, a0, 4
+            sc.w a5, t0, (t1)
+            ecall
+        ";
+        let (mut cpu, trap) = run(Xlen::Rv32, DATA, source);
+        assert_eq!(cpu.register(Register::a1()), 0);
+        assert_eq!(cpu.register(Register::a2()), 0);
+        // the reservation ends with the first sc, even if it fails
+        assert_eq!(cpu.register(Register::a3()), 1);
+        assert_eq!(cpu.register(Register::a4()), 1);
+        assert_eq!(cpu.register(Register::a5()), 1);
+        assert_eq!(word(&mut cpu, DATA), 1);
+        assert_eq!(word(&mut cpu, DATA + 4), 0);
+        assert_eq!(cpu.reservation(), None);
+        assert_eq!(trap, Trap::EnvironmentCall(0x11c));
+    }
+
+    #[test]
+    fn test_reservation() {
+        let mut cpu = Cpu::new(Xlen::Rv64, FlatMemory::new(0, 0x100));
+        let amo = |line: &str| match AsmParser::new(Xlen::Rv64).parse_line(line) {
+            Ok(Some(Instruction::Amo(amo))) => amo,
+            _ => unreachable!(),
+        };
+        let (lr_d, sc_w) = (amo("lr.d a1, (a0)"), amo("sc.w a2, t0, (a1)"));
+        let illegal = Trap::IllegalInstruction(0);
+        cpu.set_register(Register::a0(), 0x10);
+        cpu.set_register(Register::t0(), 7);
+
+        // a word within the reserved doubleword
+        cpu.atomic(&lr_d, "lr_d", illegal).unwrap();
+        assert_eq!(cpu.reservation(), Some((0x10, 8)));
+        cpu.set_register(Register::a1(), 0x14);
+        assert_eq!(cpu.atomic(&sc_w, "sc_w", illegal), Ok(0));
+        assert_eq!(cpu.load(0x14, 4), Ok(7));
+
+        cpu.atomic(&lr_d, "lr_d", illegal).unwrap();
+        cpu.clear_reservation();
+        assert_eq!(cpu.atomic(&sc_w, "sc_w", illegal), Ok(1));
+
+        cpu.set_register(Register::a0(), 0x14);
+        assert_eq!(
+            cpu.atomic(&lr_d, "lr_d", illegal),
+            Err(Trap::LoadAddressMisaligned(0x14))
+        );
+        cpu.set_register(Register::a1(), 0x12);
+        assert_eq!(
+            cpu.atomic(&sc_w, "sc_w", illegal),
+            Err(Trap::StoreAddressMisaligned(0x12))
+        );
+        // a reservation at the end of the address space
+        cpu.set_reservation(Some((u64::MAX - 3, 8)));
+        cpu.set_register(Register::a1(), u64::MAX - 3);
+        assert_eq!(cpu.atomic(&sc_w, "sc_w", illegal), Ok(1));
+        assert_eq!(cpu.reservation(), None);
+
+        let amoadd_w = amo("amoadd.w a2, t0, (a1)");
+        cpu.set_register(Register::a1(), 0x200);
+        assert_eq!(
+            cpu.atomic(&amoadd_w, "amoadd_w", illegal),
+            Err(Trap::StoreAccessFault(0x200))
+        );
+    }
+
+    #[test]
+    fn test_amocas() {
+        let source = "
+            li t0, 5
+            sw t0, 0(a0)
+            li a1, 4
+            amocas.w a1, t0, (a0)
+            li a2, 5
+            li t1, 6
+            amocas.w a2, t1, (a0)
+            ecall
+        ";
+        let (mut cpu, _) = run(Xlen::Rv32, DATA, source);
+        assert_eq!(cpu.register(Register::a1()), 5);
+        assert_eq!(cpu.register(Register::a2()), 5);
+        assert_eq!(word(&mut cpu, DATA), 6);
+    }
+
+    #[test]
+    fn test_amo_op() {
+        assert_eq!(amo_op("amominu", 0xff, 1, 1), Some(1));
+        assert_eq!(amo_op("amomin", 0xff, 1, 1), Some(u64::MAX));
+        assert_eq!(amo_op("amomaxu", 0x8000, 0x7fff, 2), Some(0x8000));
+        assert_eq!(amo_op("amomax", 0x8000, 0x7fff, 2), Some(0x7fff));
+        assert_eq!(amo_op("amocas", 0, 0, 4), None);
+    }
+}
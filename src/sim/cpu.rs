@@ -0,0 +1,297 @@
+use super::{FFlags, Memory, Trap};
+use crate::{
+    disasm::Address,
+    instr::Instruction,
+    model::{FRegister, InstructionSize, Register, TryFromOpcodeBinary, Xlen},
+};
+
+/// An interpreter of RISC-V code: a hart with the integer registers, the PC, the
+/// reservation of `lr`/`sc`, and the floating-point registers with `fcsr`,
+/// executing instructions from the memory one [`step`](Self::step) at a time.
+///
+/// Values of registers are kept zero-extended to 64 bits, so in RV32 only the lower
+/// 32 bits are ever set. Floating-point registers are 64-bit (the D extension), and
+/// narrower values are NaN-boxed in them.
+#[derive(Debug, Clone)]
+pub struct Cpu<M: Memory> {
+    xlen: Xlen,
+    compressed: bool,
+    pc: Address,
+    registers: [u64; 32],
+    reservation: Option<(Address, u8)>,
+    fregisters: [u64; 32],
+    fflags: FFlags,
+    frm: u8,
+    memory: M,
+}
+
+impl<M: Memory> Cpu<M> {
+    /// Creates a CPU of the base ISA, with the registers and the PC set to zero.
+    pub fn new(xlen: Xlen, memory: M) -> Self {
+        Self {
+            xlen,
+            compressed: true,
+            pc: 0,
+            registers: [0; 32],
+            reservation: None,
+            fregisters: [0; 32],
+            fflags: FFlags::default(),
+            frm: 0,
+            memory,
+        }
+    }
+
+    pub fn with_pc(mut self, pc: Address) -> Self {
+        self.set_pc(pc);
+        self
+    }
+
+    /// Enables or disables the C extension (enabled by default). Without it
+    /// compressed instructions are illegal and jumps must be aligned to 4 bytes.
+    pub fn with_compressed(self, compressed: bool) -> Self {
+        Self { compressed, ..self }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.xlen
+    }
+
+    pub fn pc(&self) -> Address {
+        self.pc
+    }
+
+    pub fn set_pc(&mut self, pc: Address) {
+        self.pc = self.wrap(pc);
+    }
+
+    /// Returns the value of the register, zero-extended from XLEN bits.
+    pub fn register(&self, r: Register) -> u64 {
+        self.registers[usize::from(u8::from(r))]
+    }
+
+    /// Sets the register to the value truncated to XLEN bits. Writes to `x0` are ignored.
+    pub fn set_register(&mut self, r: Register, value: u64) {
+        let index = usize::from(u8::from(r));
+        if index != 0 {
+            self.registers[index] = self.wrap(value);
+        }
+    }
+
+    /// Returns the address and the size of the bytes reserved by `lr`, if any.
+    pub fn reservation(&self) -> Option<(Address, u8)> {
+        self.reservation
+    }
+
+    /// Ends the reservation made by `lr`, so the next `sc` fails.
+    pub fn clear_reservation(&mut self) {
+        self.reservation = None;
+    }
+
+    /// Returns the raw 64 bits of the floating-point register.
+    pub fn fregister(&self, r: FRegister) -> u64 {
+        self.fregisters[usize::from(u8::from(r))]
+    }
+
+    /// Sets the raw 64 bits of the floating-point register. Single and half-precision
+    /// values have to be NaN-boxed (the upper bits set) to be seen as such.
+    pub fn set_fregister(&mut self, r: FRegister, bits: u64) {
+        self.fregisters[usize::from(u8::from(r))] = bits;
+    }
+
+    /// Returns the exceptions accrued since the flags were last cleared.
+    pub fn fflags(&self) -> FFlags {
+        self.fflags
+    }
+
+    pub fn set_fflags(&mut self, fflags: FFlags) {
+        self.fflags = fflags;
+    }
+
+    /// Returns the dynamic rounding mode (the `frm` CSR), as its 3-bit encoding.
+    pub fn frm(&self) -> u8 {
+        self.frm
+    }
+
+    /// Sets the dynamic rounding mode, ignoring the bits above the lowest 3. The
+    /// reserved modes 5 to 7 make instructions using it illegal.
+    pub fn set_frm(&mut self, frm: u8) {
+        self.frm = frm & 0b111;
+    }
+
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
+    pub fn memory_mut(&mut self) -> &mut M {
+        &mut self.memory
+    }
+
+    pub fn into_memory(self) -> M {
+        self.memory
+    }
+
+    /// Fetches and decodes the instruction at the PC.
+    pub fn fetch(&mut self) -> Result<Instruction, Trap> {
+        let pc = self.pc;
+        if !pc.is_multiple_of(self.instruction_alignment()) {
+            return Err(Trap::InstructionAddressMisaligned(pc));
+        }
+        let low = self.fetch_half(pc)?;
+        let bits = match InstructionSize::try_from_opcode_binary(low as u8) {
+            Ok(InstructionSize::Size16) if self.compressed => u32::from(low),
+            Ok(InstructionSize::Size32) => {
+                let high = self.fetch_half(self.wrap(pc.wrapping_add(2)))?;
+                u32::from(low) | u32::from(high) << 16
+            }
+            _ => return Err(Trap::IllegalInstruction(u32::from(low))),
+        };
+        Instruction::decode(bits, self.xlen).map_err(|_| Trap::IllegalInstruction(bits))
+    }
+
+    /// Fetches, decodes and executes the instruction at the PC, returning it.
+    pub fn step(&mut self) -> Result<Instruction, Trap> {
+        let instr = self.fetch()?;
+        self.execute(&instr)?;
+        Ok(instr)
+    }
+
+    /// Executes instructions until one of them traps, or until `max_steps` are
+    /// executed (returning `None` then).
+    pub fn run(&mut self, max_steps: usize) -> Option<Trap> {
+        (0..max_steps).find_map(|_| self.step().err())
+    }
+
+    fn fetch_half(&mut self, address: Address) -> Result<u16, Trap> {
+        let mut bytes = [0; 2];
+        self.memory
+            .read(address, &mut bytes)
+            .map_err(|_| Trap::InstructionAccessFault(address))?;
+        Ok(u16::from_le_bytes(bytes))
+    }
+
+    /// Returns the alignment of instructions (and of the targets of jumps) in bytes.
+    pub(super) fn instruction_alignment(&self) -> Address {
+        if self.compressed {
+            2
+        } else {
+            4
+        }
+    }
+
+    pub(super) fn is_compressed_enabled(&self) -> bool {
+        self.compressed
+    }
+
+    pub(super) fn set_reservation(&mut self, reservation: Option<(Address, u8)>) {
+        self.reservation = reservation;
+    }
+
+    /// Reads `width` bytes of a naturally aligned value, zero-extended.
+    pub(super) fn load(&mut self, address: Address, width: u8) -> Result<u64, Trap> {
+        if !address.is_multiple_of(Address::from(width)) {
+            return Err(Trap::LoadAddressMisaligned(address));
+        }
+        let mut bytes = [0; 8];
+        self.memory
+            .read(address, &mut bytes[..usize::from(width)])
+            .map_err(|_| Trap::LoadAccessFault(address))?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    /// Writes the lowest `width` bytes of the value to a naturally aligned address.
+    pub(super) fn store(&mut self, address: Address, width: u8, value: u64) -> Result<(), Trap> {
+        if !address.is_multiple_of(Address::from(width)) {
+            return Err(Trap::StoreAddressMisaligned(address));
+        }
+        self.memory
+            .write(address, &value.to_le_bytes()[..usize::from(width)])
+            .map_err(|_| Trap::StoreAccessFault(address))
+    }
+
+    /// Truncates the value to XLEN bits.
+    pub(super) fn wrap(&self, value: u64) -> u64 {
+        match self.xlen {
+            Xlen::Rv32 => value & 0xffff_ffff,
+            _ => value,
+        }
+    }
+
+    /// Interprets the lowest XLEN bits of the value as a signed number.
+    pub(super) fn signed(&self, value: u64) -> i64 {
+        match self.xlen {
+            Xlen::Rv32 => i64::from(value as i32),
+            _ => value as i64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::FlatMemory;
+
+    fn new_cpu(xlen: Xlen, code: &[u8]) -> Cpu<FlatMemory> {
+        let memory = FlatMemory::new(0x1000, 0x100)
+            .with_contents(0x1000, code)
+            .unwrap();
+        Cpu::new(xlen, memory).with_pc(0x1000)
+    }
+
+    #[test]
+    fn test_registers() {
+        let mut cpu = new_cpu(Xlen::Rv32, &[]);
+        cpu.set_register(Register::a0(), 0x1_2345_6789);
+        assert_eq!(cpu.register(Register::a0()), 0x2345_6789);
+        cpu.set_register(Register::zero(), 1);
+        assert_eq!(cpu.register(Register::zero()), 0);
+        assert_eq!(cpu.signed(0xffff_ffff), -1);
+
+        let mut cpu = new_cpu(Xlen::Rv64, &[]);
+        cpu.set_register(Register::a0(), 0x1_2345_6789);
+        assert_eq!(cpu.register(Register::a0()), 0x1_2345_6789);
+        assert_eq!(cpu.signed(0xffff_ffff), 0xffff_ffff);
+    }
+
+    #[test]
+    fn test_fetch() {
+        // addi a0, a0, 1; c.addi a0, 1
+        let mut cpu = new_cpu(Xlen::Rv32, &[0x13, 0x05, 0x15, 0x00, 0x05, 0x05]);
+        assert_eq!(u32::from(cpu.fetch().unwrap()), 0x00150513);
+        cpu.set_pc(0x1004);
+        assert!(cpu.fetch().unwrap().is_compressed());
+
+        cpu.set_pc(0x1001);
+        assert_eq!(cpu.fetch(), Err(Trap::InstructionAddressMisaligned(0x1001)));
+        cpu.set_pc(0x2000);
+        assert_eq!(cpu.fetch(), Err(Trap::InstructionAccessFault(0x2000)));
+
+        let mut cpu = cpu.with_compressed(false).with_pc(0x1004);
+        assert_eq!(cpu.fetch(), Err(Trap::IllegalInstruction(0x0505)));
+        cpu.set_pc(0x1002);
+        assert_eq!(cpu.fetch(), Err(Trap::InstructionAddressMisaligned(0x1002)));
+
+        // addiw is only defined in RV64
+        let mut cpu = new_cpu(Xlen::Rv32, &[0x1b, 0x05, 0x15, 0x00]);
+        assert_eq!(cpu.fetch(), Err(Trap::IllegalInstruction(0x0015051b)));
+    }
+
+    #[test]
+    fn test_load_store() {
+        let mut cpu = new_cpu(Xlen::Rv64, &[]);
+        cpu.store(0x1010, 8, 0x0102_0304_0506_0708).unwrap();
+        assert_eq!(cpu.load(0x1010, 4), Ok(0x0506_0708));
+        assert_eq!(cpu.load(0x1016, 2), Ok(0x0102));
+        assert_eq!(
+            cpu.load(0x1011, 2),
+            Err(Trap::LoadAddressMisaligned(0x1011))
+        );
+        assert_eq!(cpu.load(0x1100, 1), Err(Trap::LoadAccessFault(0x1100)));
+        assert_eq!(
+            cpu.store(0x1014, 8, 0),
+            Err(Trap::StoreAddressMisaligned(0x1014))
+        );
+        assert_eq!(cpu.store(0x1100, 4, 0), Err(Trap::StoreAccessFault(0x1100)));
+        // state isn't changed by faulting accesses
+        assert_eq!(cpu.load(0x1010, 8), Ok(0x0102_0304_0506_0708));
+    }
+}
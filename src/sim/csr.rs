@@ -0,0 +1,101 @@
+use super::{Cpu, FFlags, Memory, Trap};
+use crate::instr::IInstruction;
+
+const FFLAGS: u16 = 0x001;
+const FRM: u16 = 0x002;
+const FCSR: u16 = 0x003;
+
+impl<M: Memory> Cpu<M> {
+    /// Executes a Zicsr instruction, returning the old value of the CSR for `rd`.
+    ///
+    /// Only the floating-point CSRs (`fflags`, `frm` and `fcsr`) are implemented,
+    /// accesses to others are illegal. As in the specification, `csrrw` doesn't read
+    /// the CSR if rd is `x0`, and `csrrs`/`csrrc` don't write it if the source is zero.
+    pub(super) fn csr_access(
+        &mut self,
+        instr: &IInstruction,
+        name: &str,
+        illegal: Trap,
+    ) -> Result<u64, Trap> {
+        let csr = instr.csr().ok_or(illegal)?;
+        let address = u16::from(csr);
+        let old = self.read_csr(address).ok_or(illegal)?;
+        let (src, zero_source) = match instr.uimm() {
+            Some(uimm) => (u64::from(uimm), uimm == 0),
+            None => (self.register(instr.rs1()), u8::from(instr.rs1()) == 0),
+        };
+        let new = match name.trim_end_matches('i') {
+            "csrrw" => Some(src),
+            "csrrs" => (!zero_source).then_some(old | src),
+            "csrrc" => (!zero_source).then_some(old & !src),
+            _ => return Err(illegal),
+        };
+        if let Some(new) = new {
+            if csr.is_read_only() {
+                return Err(illegal);
+            }
+            self.write_csr(address, new);
+        }
+        Ok(old)
+    }
+
+    fn read_csr(&self, address: u16) -> Option<u64> {
+        let (fflags, frm) = (u64::from(self.fflags().bits()), u64::from(self.frm()));
+        match address {
+            FFLAGS => Some(fflags),
+            FRM => Some(frm),
+            FCSR => Some(frm << 5 | fflags),
+            _ => None,
+        }
+    }
+
+    fn write_csr(&mut self, address: u16, value: u64) {
+        match address {
+            FFLAGS => self.set_fflags(FFlags::from_bits(value as u8)),
+            FRM => self.set_frm(value as u8),
+            FCSR => {
+                self.set_fflags(FFlags::from_bits(value as u8));
+                self.set_frm((value >> 5) as u8);
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asm::AsmParser;
+    use crate::model::{Register, Xlen};
+    use crate::sim::{Cpu, FFlags, FlatMemory, Trap};
+
+    #[test]
+    fn test_csr_access() {
+        let mut cpu = Cpu::new(Xlen::Rv32, FlatMemory::new(0, 0));
+        let execute = |cpu: &mut Cpu<FlatMemory>, line: &str| {
+            let instr = AsmParser::new(Xlen::Rv32)
+                .parse_line(line)
+                .unwrap()
+                .unwrap();
+            cpu.execute(&instr).map(|_| cpu.register(Register::a0()))
+        };
+        cpu.set_register(Register::a1(), 0xff);
+
+        assert_eq!(execute(&mut cpu, "csrrw a0, fcsr, a1"), Ok(0));
+        assert_eq!((cpu.frm(), cpu.fflags().bits()), (0b111, 0b11111));
+        assert_eq!(execute(&mut cpu, "csrrci a0, fflags, 0x11"), Ok(0b11111));
+        assert_eq!(cpu.fflags(), FFlags::UF | FFlags::OF | FFlags::DZ);
+        assert_eq!(execute(&mut cpu, "csrrwi a0, frm, 1"), Ok(0b111));
+        assert_eq!(execute(&mut cpu, "csrrs a0, fcsr, zero"), Ok(0b001_01110));
+
+        // unimplemented and read-only CSRs
+        let cycle = "csrrs a0, cycle, zero";
+        assert!(matches!(
+            execute(&mut cpu, cycle),
+            Err(Trap::IllegalInstruction(_))
+        ));
+        assert!(matches!(
+            execute(&mut cpu, "csrrw a0, mscratch, a1"),
+            Err(Trap::IllegalInstruction(_))
+        ));
+    }
+}
@@ -0,0 +1,375 @@
+use super::{muldiv::muldiv_word, Cpu, Memory, Trap};
+use crate::{
+    disasm::Address,
+    instr::{Instruction, InstructionTrait, AMO_OPCODE},
+    model::Xlen,
+};
+
+const LOAD: u8 = 0b0000011;
+const LOAD_FP: u8 = 0b0000111;
+const MISC_MEM: u8 = 0b0001111;
+const OP_IMM: u8 = 0b0010011;
+const AUIPC: u8 = 0b0010111;
+const OP_IMM_32: u8 = 0b0011011;
+const STORE: u8 = 0b0100011;
+const STORE_FP: u8 = 0b0100111;
+const OP: u8 = 0b0110011;
+const LUI: u8 = 0b0110111;
+const OP_32: u8 = 0b0111011;
+const MADD: u8 = 0b1000011;
+const MSUB: u8 = 0b1000111;
+const NMSUB: u8 = 0b1001011;
+const NMADD: u8 = 0b1001111;
+const OP_FP: u8 = 0b1010011;
+const BRANCH: u8 = 0b1100011;
+const JALR: u8 = 0b1100111;
+const JAL: u8 = 0b1101111;
+const SYSTEM: u8 = 0b1110011;
+
+impl<M: Memory> Cpu<M> {
+    /// Executes the instruction as if it was at the PC, and moves the PC to the next
+    /// instruction (or to the target of a jump). The instruction has to be decoded
+    /// for the XLEN of the CPU. Compressed instructions are executed as their 32-bit
+    /// equivalents.
+    ///
+    /// Nothing is changed when the instruction traps, so the PC stays at it (e.g. to
+    /// let the caller emulate an `ecall` and move on).
+    pub fn execute(&mut self, instr: &Instruction) -> Result<(), Trap> {
+        let illegal = Trap::IllegalInstruction(u32::from(instr));
+        if instr.is_compressed() && !self.is_compressed_enabled() {
+            return Err(illegal);
+        }
+        let size = if instr.is_compressed() { 2 } else { 4 };
+        let expanded = instr.expand();
+        let name: &'static str = expanded.mnemonic().ok_or(illegal)?.into();
+
+        let pc = self.pc();
+        let rs1 = expanded.rs1().map_or(0, |r| self.register(r));
+        let rs2 = expanded.rs2().map_or(0, |r| self.register(r));
+        let imm = self.wrap(i64::from(expanded.immediate().unwrap_or(0)) as u64);
+        let rv64 = self.xlen() == Xlen::Rv64;
+        let mut next = self.wrap(pc.wrapping_add(size));
+
+        let result = match u8::from(*expanded.opcode()) {
+            LUI => Some(imm),
+            AUIPC => Some(pc.wrapping_add(imm)),
+            JAL => {
+                let link = next;
+                next = self.jump_target(pc.wrapping_add(imm))?;
+                Some(link)
+            }
+            JALR => {
+                let link = next;
+                next = self.jump_target(rs1.wrapping_add(imm) & !1)?;
+                Some(link)
+            }
+            BRANCH => {
+                if self.branch_taken(name, rs1, rs2).ok_or(illegal)? {
+                    next = self.jump_target(pc.wrapping_add(imm))?;
+                }
+                None
+            }
+            LOAD => {
+                let (width, signed) = load_width(name)
+                    .filter(|(width, _)| *width < 8 || rv64)
+                    .ok_or(illegal)?;
+                let value = self.load(self.wrap(rs1.wrapping_add(imm)), width)?;
+                Some(if signed {
+                    sign_extend(value, width)
+                } else {
+                    value
+                })
+            }
+            STORE => {
+                let width = store_width(name)
+                    .filter(|width| *width < 8 || rv64)
+                    .ok_or(illegal)?;
+                self.store(self.wrap(rs1.wrapping_add(imm)), width, rs2)?;
+                None
+            }
+            OP_IMM => Some(self.alu(name, rs1, imm).ok_or(illegal)?),
+            OP => Some(
+                self.alu(name, rs1, rs2)
+                    .or_else(|| self.muldiv(name, rs1, rs2))
+                    .ok_or(illegal)?,
+            ),
+            OP_IMM_32 if rv64 => Some(alu_word(name, rs1, imm).ok_or(illegal)?),
+            OP_32 if rv64 => Some(
+                alu_word(name, rs1, rs2)
+                    .or_else(|| muldiv_word(name, rs1, rs2))
+                    .ok_or(illegal)?,
+            ),
+            AMO_OPCODE => match &expanded {
+                Instruction::Amo(amo) => Some(self.atomic(amo, name, illegal)?),
+                _ => return Err(illegal),
+            },
+            LOAD_FP | STORE_FP | MADD | MSUB | NMSUB | NMADD | OP_FP => {
+                self.float(&expanded, name, illegal)?
+            }
+            // a single hart sees its own accesses in order, so fences have no effect
+            MISC_MEM => None,
+            SYSTEM => match (&expanded, name) {
+                (_, "ecall") => return Err(Trap::EnvironmentCall(pc)),
+                (_, "ebreak") => return Err(Trap::Breakpoint(pc)),
+                (Instruction::I(i), _) if i.csr().is_some() => {
+                    Some(self.csr_access(i, name, illegal)?)
+                }
+                _ => return Err(illegal),
+            },
+            _ => return Err(illegal),
+        };
+
+        if let (Some(value), Some(rd)) = (result, expanded.rd()) {
+            self.set_register(rd, value);
+        }
+        self.set_pc(next);
+        Ok(())
+    }
+
+    /// Checks the alignment of the target of a jump or a taken branch.
+    fn jump_target(&self, target: u64) -> Result<Address, Trap> {
+        let target = self.wrap(target);
+        if !target.is_multiple_of(self.instruction_alignment()) {
+            return Err(Trap::InstructionAddressMisaligned(target));
+        }
+        Ok(target)
+    }
+
+    fn branch_taken(&self, name: &str, a: u64, b: u64) -> Option<bool> {
+        Some(match name {
+            "beq" => a == b,
+            "bne" => a != b,
+            "blt" => self.signed(a) < self.signed(b),
+            "bge" => self.signed(a) >= self.signed(b),
+            "bltu" => a < b,
+            "bgeu" => a >= b,
+            _ => return None,
+        })
+    }
+
+    /// Computes the result of an integer register-register or register-immediate
+    /// operation on XLEN-bit values.
+    fn alu(&self, name: &str, a: u64, b: u64) -> Option<u64> {
+        let shamt = b as u32 & (self.xlen().bits() - 1);
+        let value = match name {
+            "add" | "addi" => a.wrapping_add(b),
+            "sub" => a.wrapping_sub(b),
+            "sll" | "slli" => a << shamt,
+            "slt" | "slti" => u64::from(self.signed(a) < self.signed(b)),
+            "sltu" | "sltiu" => u64::from(a < b),
+            "xor" | "xori" => a ^ b,
+            "or" | "ori" => a | b,
+            "and" | "andi" => a & b,
+            "srl" | "srli" => a >> shamt,
+            "sra" | "srai" => (self.signed(a) >> shamt) as u64,
+            _ => return None,
+        };
+        Some(self.wrap(value))
+    }
+}
+
+/// Computes the result of an RV64 operation on words (e.g. `addw`), sign-extended
+/// from 32 bits.
+fn alu_word(name: &str, a: u64, b: u64) -> Option<u64> {
+    let (a, b) = (a as u32, b as u32);
+    let shamt = b & 31;
+    let value = match name {
+        "addw" | "addiw" => a.wrapping_add(b),
+        "subw" => a.wrapping_sub(b),
+        "sllw" | "slliw" => a << shamt,
+        "srlw" | "srliw" => a >> shamt,
+        "sraw" | "sraiw" => ((a as i32) >> shamt) as u32,
+        _ => return None,
+    };
+    Some(value as i32 as u64)
+}
+
+/// Returns the width of a load in bytes, and whether the value is sign-extended.
+fn load_width(name: &str) -> Option<(u8, bool)> {
+    match name {
+        "lb" => Some((1, true)),
+        "lh" => Some((2, true)),
+        "lw" => Some((4, true)),
+        "ld" => Some((8, true)),
+        "lbu" => Some((1, false)),
+        "lhu" => Some((2, false)),
+        "lwu" => Some((4, false)),
+        _ => None,
+    }
+}
+
+fn store_width(name: &str) -> Option<u8> {
+    match name {
+        "sb" => Some(1),
+        "sh" => Some(2),
+        "sw" => Some(4),
+        "sd" => Some(8),
+        _ => None,
+    }
+}
+
+/// Sign-extends a value of `width` bytes to 64 bits.
+pub(super) fn sign_extend(value: u64, width: u8) -> u64 {
+    let shift = 64 - 8 * u32::from(width);
+    ((value << shift) as i64 >> shift) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Register;
+    use crate::sim::test_utils::run;
+    use crate::sim::FlatMemory;
+
+    fn a0(cpu: &Cpu<FlatMemory>) -> u64 {
+        cpu.register(Register::a0())
+    }
+
+    #[test]
+    fn test_loop() {
+        let source = "
+            li a0, 0
+            li t0, 10
+        loop:
+            add a0, a0, t0
+            addi t0, t0, -1
+            bnez t0, loop
+            ecall
+        ";
+        let (cpu, trap) = run(Xlen::Rv32, 0, source);
+        assert_eq!(a0(&cpu), 55);
+        assert_eq!(trap, Trap::EnvironmentCall(0x114));
+        assert_eq!(cpu.pc(), 0x114);
+    }
+
+    #[test]
+    fn test_calls() {
+        let source = "
+        _start:
+            li a0, 5
+            jal ra, double
+            jal ra, double
+            ebreak
+        double:
+            add a0, a0, a0
+            ret
+        ";
+        let (cpu, trap) = run(Xlen::Rv32, 0, source);
+        assert_eq!(a0(&cpu), 20);
+        assert_eq!(cpu.register(Register::ra()), 0x10c);
+        assert_eq!(trap, Trap::Breakpoint(0x10c));
+    }
+
+    #[test]
+    fn test_memory() {
+        let source = "
+            li a1, buf
+            li a0, -2
+            sw a0, 0(a1)
+            lb a2, 0(a1)
+            lbu a3, 0(a1)
+            lhu a4, 2(a1)
+            lw a5, 0(a1)
+            lw a0, 1(a1)
+        .data
+        buf:
+            .word 0
+        ";
+        let (cpu, trap) = run(Xlen::Rv32, 0, source);
+        let buf = 0x120;
+        assert_eq!(cpu.register(Register::a1()), buf);
+        assert_eq!(cpu.register(Register::a2()), 0xffff_fffe);
+        assert_eq!(cpu.register(Register::a3()), 0xfe);
+        assert_eq!(cpu.register(Register::a4()), 0xffff);
+        assert_eq!(cpu.register(Register::a5()), 0xffff_fffe);
+        assert_eq!(trap, Trap::LoadAddressMisaligned(buf + 1));
+        assert_eq!(cpu.pc(), 0x11c);
+    }
+
+    #[test]
+    fn test_rv32_arithmetic() {
+        let source = "
+            lui a1, 0x80000
+            srai a2, a1, 4
+            srli a3, a1, 4
+            slli a4, a1, 1
+            sltiu a5, zero, -1
+            slt a6, a1, zero
+            auipc a7, 1
+            sub a0, zero, a1
+            ecall
+        ";
+        let (cpu, _) = run(Xlen::Rv32, 0, source);
+        assert_eq!(cpu.register(Register::a1()), 0x8000_0000);
+        assert_eq!(cpu.register(Register::a2()), 0xf800_0000);
+        assert_eq!(cpu.register(Register::a3()), 0x0800_0000);
+        assert_eq!(cpu.register(Register::a4()), 0);
+        assert_eq!(cpu.register(Register::a5()), 1);
+        assert_eq!(cpu.register(Register::a6()), 1);
+        assert_eq!(cpu.register(Register::a7()), 0x1118);
+        assert_eq!(a0(&cpu), 0x8000_0000);
+    }
+
+    #[test]
+    fn test_rv64_arithmetic() {
+        let source = "
+            lui a1, 0x80000
+            srli a2, a1, 32
+            addiw a3, a1, -1
+            slliw a4, a2, 31
+            sraw a5, a1, a2
+            li t0, buf
+            sd a1, 0(t0)
+            lwu a6, 4(t0)
+            ld a7, 0(t0)
+            ecall
+        .data
+        buf:
+            .word 0, 0
+        ";
+        let (cpu, _) = run(Xlen::Rv64, 0, source);
+        assert_eq!(cpu.register(Register::a1()), 0xffff_ffff_8000_0000);
+        assert_eq!(cpu.register(Register::a2()), 0xffff_ffff);
+        assert_eq!(cpu.register(Register::a3()), 0x7fff_ffff);
+        assert_eq!(cpu.register(Register::a4()), 0xffff_ffff_8000_0000);
+        assert_eq!(cpu.register(Register::a5()), 0xffff_ffff_ffff_ffff);
+        assert_eq!(cpu.register(Register::a6()), 0xffff_ffff);
+        assert_eq!(cpu.register(Register::a7()), 0xffff_ffff_8000_0000);
+    }
+
+    #[test]
+    fn test_traps() {
+        let memory = FlatMemory::new(0, 0x100);
+        let mut cpu = Cpu::new(Xlen::Rv32, memory).with_pc(0x10);
+
+        // jalr clears the lowest bit of the target
+        cpu.set_register(Register::t0(), 0x43);
+        let jalr = Instruction::try_from(0x000280e7).unwrap();
+        cpu.execute(&jalr).unwrap();
+        assert_eq!((cpu.pc(), cpu.register(Register::ra())), (0x42, 0x14));
+
+        let mut cpu = cpu.with_compressed(false).with_pc(0x10);
+        assert_eq!(
+            cpu.execute(&jalr),
+            Err(Trap::InstructionAddressMisaligned(0x42))
+        );
+        assert_eq!((cpu.pc(), cpu.register(Register::ra())), (0x10, 0x14));
+
+        // c.addi a0, 1
+        let c_addi = Instruction::decode(0x0505, Xlen::Rv32).unwrap();
+        assert_eq!(cpu.execute(&c_addi), Err(Trap::IllegalInstruction(0x0505)));
+        let mut cpu = cpu.with_compressed(true);
+        cpu.execute(&c_addi).unwrap();
+        assert_eq!((cpu.pc(), a0(&cpu)), (0x12, 1));
+
+        // csrrw, sw to unmapped memory
+        let csrrw = Instruction::try_from(0x34011073).unwrap();
+        assert_eq!(
+            cpu.execute(&csrrw),
+            Err(Trap::IllegalInstruction(0x34011073))
+        );
+        let sw = Instruction::try_from(0x10a02023).unwrap();
+        assert_eq!(cpu.execute(&sw), Err(Trap::StoreAccessFault(0x100)));
+        assert_eq!(cpu.step(), Err(Trap::IllegalInstruction(0)));
+    }
+}
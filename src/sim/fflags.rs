@@ -0,0 +1,72 @@
+use std::fmt;
+use std::ops::{BitOr, BitOrAssign};
+
+/// Accrued exceptions of floating-point operations, as held by the `fflags` CSR.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FFlags(u8);
+
+impl FFlags {
+    /// Inexact
+    pub const NX: FFlags = FFlags(1 << 0);
+    /// Underflow
+    pub const UF: FFlags = FFlags(1 << 1);
+    /// Overflow
+    pub const OF: FFlags = FFlags(1 << 2);
+    /// Divide by zero
+    pub const DZ: FFlags = FFlags(1 << 3);
+    /// Invalid operation
+    pub const NV: FFlags = FFlags(1 << 4);
+
+    /// Creates the flags from the bits of `fflags`, ignoring the bits above them.
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & 0b11111)
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    pub fn contains(&self, flags: FFlags) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl BitOr for FFlags {
+    type Output = FFlags;
+
+    fn bitor(self, rhs: FFlags) -> FFlags {
+        FFlags(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for FFlags {
+    fn bitor_assign(&mut self, rhs: FFlags) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Lists the set flags, e.g. `NV|NX` (or `-` if none is set).
+impl fmt::Display for FFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<&str> = [
+            (FFlags::NV, "NV"),
+            (FFlags::DZ, "DZ"),
+            (FFlags::OF, "OF"),
+            (FFlags::UF, "UF"),
+            (FFlags::NX, "NX"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.contains(*flag))
+        .map(|(_, name)| name)
+        .collect();
+        if names.is_empty() {
+            write!(f, "-")
+        } else {
+            write!(f, "{}", names.join("|"))
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use super::{execute::sign_extend, softfloat::Format, Cpu, FFlags, Memory, Trap};
+use crate::{
+    instr::Instruction,
+    model::{FRegister, RoundingMode, Xlen},
+};
+
+impl<M: Memory> Cpu<M> {
+    /// Executes an F, D or Zfh instruction (including the loads and stores), writing
+    /// a floating-point result to rd itself and returning an integer one (e.g. of
+    /// `feq.s` or `fcvt.w.d`) for `rd`.
+    ///
+    /// The exceptions raised are accrued in `fflags` only when the instruction
+    /// completes. A reserved rounding mode is illegal, in the rm field or in `frm`
+    /// when the dynamic one is selected.
+    /// The Q, Zfa and Zfbfmin instructions aren't supported.
+    pub(super) fn float(
+        &mut self,
+        instr: &Instruction,
+        name: &str,
+        illegal: Trap,
+    ) -> Result<Option<u64>, Trap> {
+        let x = instr.rs1().map_or(0, |r| self.register(r));
+        let imm = i64::from(instr.immediate().unwrap_or(0)) as u64;
+        match (name, instr.frd(), instr.frs2()) {
+            ("flw" | "fld" | "flh", Some(frd), _) => {
+                let format = memory_format(name).ok_or(illegal)?;
+                let value = self.load(self.wrap(x.wrapping_add(imm)), format.width())?;
+                self.write_float(frd, format, value);
+                return Ok(None);
+            }
+            ("fsw" | "fsd" | "fsh", _, Some(frs2)) => {
+                let format = memory_format(name).ok_or(illegal)?;
+                let value = self.fregister(frs2);
+                self.store(self.wrap(x.wrapping_add(imm)), format.width(), value)?;
+                return Ok(None);
+            }
+            _ => {}
+        }
+
+        // the rounding mode doesn't matter for instructions without it
+        let rm = match instr.rounding_mode() {
+            Some(RoundingMode::Dyn) => dynamic_rounding_mode(self.frm()).ok_or(illegal)?,
+            Some(rm) => rm,
+            None if instr.has_rounding_mode() => return Err(illegal),
+            None => RoundingMode::Rne,
+        };
+        // fcvt.s.w converts from w to s, fadd.s from s to s
+        let (op, formats) = name.split_once('_').ok_or(illegal)?;
+        let (dest, src) = formats.split_once('_').unwrap_or((formats, formats));
+        let [a, b, c] = [instr.frs1(), instr.frs2(), instr.frs3()].map(|r| {
+            r.zip(format(src))
+                .map_or(0, |(r, format)| self.read_float(r, format))
+        });
+        let integer = |name: &str| {
+            integer_format(name).filter(|(_, bits)| *bits == 32 || self.xlen() == Xlen::Rv64)
+        };
+
+        use FloatResult::*;
+        let mut flags = FFlags::default();
+        let f = &mut flags;
+        let result = match (op, format(dest), format(src)) {
+            ("fadd", Some(fmt), _) => Float(fmt.add(a, b, rm, f)),
+            ("fsub", Some(fmt), _) => Float(fmt.sub(a, b, rm, f)),
+            ("fmul", Some(fmt), _) => Float(fmt.mul(a, b, rm, f)),
+            ("fdiv", Some(fmt), _) => Float(fmt.div(a, b, rm, f)),
+            ("fsqrt", Some(fmt), _) => Float(fmt.sqrt(a, rm, f)),
+            ("fmadd", Some(fmt), _) => Float(fmt.fma(a, b, c, rm, f)),
+            ("fmsub", Some(fmt), _) => Float(fmt.fma(a, b, fmt.negate(c), rm, f)),
+            ("fnmsub", Some(fmt), _) => Float(fmt.fma(fmt.negate(a), b, c, rm, f)),
+            ("fnmadd", Some(fmt), _) => Float(fmt.fma(fmt.negate(a), b, fmt.negate(c), rm, f)),
+            ("fsgnj", Some(fmt), _) => Float(fmt.with_sign(a, fmt.sign(b))),
+            ("fsgnjn", Some(fmt), _) => Float(fmt.with_sign(a, !fmt.sign(b))),
+            ("fsgnjx", Some(fmt), _) => Float(fmt.with_sign(a, fmt.sign(a) != fmt.sign(b))),
+            ("fmin", Some(fmt), _) => Float(fmt.min_max(a, b, false, f)),
+            ("fmax", Some(fmt), _) => Float(fmt.min_max(a, b, true, f)),
+            ("feq", Some(fmt), _) => Int(u64::from(fmt.eq(a, b, f))),
+            ("flt", Some(fmt), _) => Int(u64::from(fmt.lt(a, b, f))),
+            ("fle", Some(fmt), _) => Int(u64::from(fmt.le(a, b, f))),
+            ("fclass", Some(fmt), _) => Int(fmt.classify(a)),
+            ("fcvt", Some(to), Some(from)) => Float(to.convert(from, a, rm, f)),
+            ("fcvt", None, Some(from)) => {
+                let (signed, bits) = integer(dest).ok_or(illegal)?;
+                let value = from.convert_to_int(a, signed, bits, rm, f);
+                Int(sign_extend(value, (bits / 8) as u8))
+            }
+            ("fcvt", Some(to), None) => {
+                let (signed, bits) = integer(src).ok_or(illegal)?;
+                Float(to.convert_from_int(x, signed, bits, rm, f))
+            }
+            // the raw bits of the register, which don't have to be NaN-boxed
+            ("fmv", None, _) if dest == "x" => {
+                let fmt = memory_format(src)
+                    .filter(|fmt| u32::from(fmt.width()) * 8 <= self.xlen().bits());
+                let fmt = fmt.ok_or(illegal)?;
+                let frs1 = instr.frs1().ok_or(illegal)?;
+                Int(sign_extend(self.fregister(frs1) & fmt.mask(), fmt.width()))
+            }
+            ("fmv", _, None) if src == "x" => {
+                let fmt = memory_format(dest)
+                    .filter(|fmt| u32::from(fmt.width()) * 8 <= self.xlen().bits());
+                Float(x & fmt.ok_or(illegal)?.mask())
+            }
+            _ => return Err(illegal),
+        };
+
+        let value = match result {
+            Float(value) => {
+                let format = format(dest)
+                    .or_else(|| memory_format(dest))
+                    .ok_or(illegal)?;
+                self.write_float(instr.frd().ok_or(illegal)?, format, value);
+                None
+            }
+            Int(value) => Some(value),
+        };
+        self.set_fflags(self.fflags() | flags);
+        Ok(value)
+    }
+
+    /// Reads a value of the format from the register, where a narrower value has to
+    /// be NaN-boxed (all the upper bits set), or is read as the canonical NaN.
+    fn read_float(&self, r: FRegister, format: Format) -> u64 {
+        let bits = self.fregister(r);
+        if bits | format.mask() == u64::MAX {
+            bits & format.mask()
+        } else {
+            format.canonical_nan()
+        }
+    }
+
+    /// Writes a value of the format to the register, NaN-boxing a narrower value.
+    fn write_float(&mut self, r: FRegister, format: Format, value: u64) {
+        self.set_fregister(r, value | !format.mask());
+    }
+}
+
+/// The result of a floating-point instruction, written to a register of its class.
+enum FloatResult {
+    Float(u64),
+    Int(u64),
+}
+
+/// Returns the format of an arithmetic instruction suffix (e.g. `s` of `fadd.s`).
+fn format(suffix: &str) -> Option<Format> {
+    match suffix {
+        "h" => Some(Format::HALF),
+        "s" => Some(Format::SINGLE),
+        "d" => Some(Format::DOUBLE),
+        _ => None,
+    }
+}
+
+/// Returns the format moved by a load, store or `fmv` (e.g. `w` of `flw`).
+fn memory_format(name: &str) -> Option<Format> {
+    match name.chars().last()? {
+        'h' => Some(Format::HALF),
+        'w' => Some(Format::SINGLE),
+        'd' => Some(Format::DOUBLE),
+        _ => None,
+    }
+}
+
+/// Returns the signedness and the size in bits of an integer conversion suffix.
+fn integer_format(suffix: &str) -> Option<(bool, u32)> {
+    match suffix {
+        "w" => Some((true, 32)),
+        "wu" => Some((false, 32)),
+        "l" => Some((true, 64)),
+        "lu" => Some((false, 64)),
+        _ => None,
+    }
+}
+
+/// Decodes the rounding mode of `frm`, which can't be the dynamic or a reserved one.
+fn dynamic_rounding_mode(frm: u8) -> Option<RoundingMode> {
+    match frm {
+        0 => Some(RoundingMode::Rne),
+        1 => Some(RoundingMode::Rtz),
+        2 => Some(RoundingMode::Rdn),
+        3 => Some(RoundingMode::Rup),
+        4 => Some(RoundingMode::Rmm),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::asm::AsmParser;
+    use crate::disasm::Address;
+    use crate::instr::Instruction;
+    use crate::model::{FRegister, Register, Xlen};
+    use crate::sim::test_utils::run;
+    use crate::sim::{Cpu, FFlags, FlatMemory, Trap};
+
+    const DATA: Address = 0x800;
+
+    fn execute(cpu: &mut Cpu<FlatMemory>, line: &str) -> Result<(), Trap> {
+        let instr = AsmParser::new(cpu.xlen())
+            .parse_line(line)
+            .unwrap()
+            .unwrap();
+        cpu.execute(&instr)
+    }
+
+    fn boxed(f: f32) -> u64 {
+        0xffff_ffff_0000_0000 | u64::from(f.to_bits())
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let source = "
+            li t0, 3
+            fcvt.s.w fa0, t0
+            li t0, 1
+            fcvt.s.w fa1, t0
+            fdiv.s fa2, fa1, fa0
+            fcvt.d.s fa3, fa2
+            fmadd.d fa4, fa3, fa3, fa3
+            fcvt.w.s a1, fa2, rup
+            feq.s a2, fa0, fa0
+            fclass.d a3, fa4
+            fsw fa2, 0(a0)
+            fld fa5, 0(a0)
+            ecall
+        ";
+        let (mut cpu, trap) = run(Xlen::Rv64, DATA, source);
+        assert!(matches!(trap, Trap::EnvironmentCall(_)), "{:?}", trap);
+        let third = 1.0f32 / 3.0;
+        assert_eq!(cpu.fregister(FRegister::fa2()), boxed(third));
+        let third = f64::from(third);
+        assert_eq!(cpu.fregister(FRegister::fa3()), third.to_bits());
+        assert_eq!(
+            cpu.fregister(FRegister::fa4()),
+            third.mul_add(third, third).to_bits()
+        );
+        assert_eq!(cpu.register(Register::a1()), 1);
+        assert_eq!(cpu.register(Register::a2()), 1);
+        assert_eq!(cpu.register(Register::a3()), 1 << 6);
+        // fsw stores the single, fld sees its bits (and zeros above them)
+        assert_eq!(cpu.fregister(FRegister::fa5()), cpu.load(DATA, 4).unwrap());
+        assert_eq!(cpu.fflags(), FFlags::NX);
+    }
+
+    #[test]
+    fn test_nan_boxing() {
+        let mut cpu = Cpu::new(Xlen::Rv64, FlatMemory::new(0, 0));
+        cpu.set_register(Register::a1(), 0xffff_ffff_bf80_0000);
+        execute(&mut cpu, "fmv.w.x fa1, a1").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa1()), boxed(-1.0));
+        execute(&mut cpu, "fmv.x.w a2, fa1").unwrap();
+        assert_eq!(cpu.register(Register::a2()), 0xffff_ffff_bf80_0000);
+
+        // a double isn't a boxed single, so it's read as the canonical NaN
+        cpu.set_fregister(FRegister::fa2(), 1f64.to_bits());
+        execute(&mut cpu, "fadd.s fa3, fa1, fa2").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa3()), boxed(f32::NAN));
+        assert_eq!(cpu.fregister(FRegister::fa3()), 0xffff_ffff_7fc0_0000);
+        execute(&mut cpu, "fsgnjn.s fa4, fa2, fa1").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa4()), 0xffff_ffff_7fc0_0000);
+        assert!(cpu.fflags().is_empty());
+
+        // half-precision values are boxed too
+        execute(&mut cpu, "fcvt.h.s fa5, fa1").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa5()), 0xffff_ffff_ffff_bc00);
+        execute(&mut cpu, "fmv.x.h a3, fa5").unwrap();
+        assert_eq!(cpu.register(Register::a3()), 0xffff_ffff_ffff_bc00);
+        execute(&mut cpu, "fcvt.d.h fa6, fa5").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa6()), (-1f64).to_bits());
+    }
+
+    #[test]
+    fn test_flags_and_rounding() {
+        let mut cpu = Cpu::new(Xlen::Rv32, FlatMemory::new(0, 0));
+        cpu.set_fregister(FRegister::fa1(), boxed(1.0));
+        cpu.set_fregister(FRegister::fa2(), boxed(0.0));
+        execute(&mut cpu, "fdiv.s fa0, fa1, fa2").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa0()), boxed(f32::INFINITY));
+        execute(&mut cpu, "fsqrt.s fa0, fa0").unwrap();
+        execute(&mut cpu, "fcvt.wu.s a0, fa0").unwrap();
+        // the flags accrue
+        assert_eq!(cpu.register(Register::a0()), 0xffff_ffff);
+        assert_eq!(cpu.fflags(), FFlags::DZ | FFlags::NV);
+        cpu.set_fflags(FFlags::default());
+
+        // the dynamic rounding mode is frm (round down here)
+        cpu.set_register(Register::a1(), 0xffff_ffff);
+        cpu.set_frm(2);
+        execute(&mut cpu, "fcvt.s.wu fa3, a1").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa3()), boxed(4294967040.0));
+        execute(&mut cpu, "fcvt.s.wu fa3, a1, rne").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa3()), boxed(4294967296.0));
+        assert_eq!(cpu.fflags(), FFlags::NX);
+
+        // a reserved frm only traps instructions using it, and changes nothing
+        cpu.set_frm(5);
+        let pc = cpu.pc();
+        assert!(matches!(
+            execute(&mut cpu, "fadd.s fa4, fa1, fa1"),
+            Err(Trap::IllegalInstruction(_))
+        ));
+        assert_eq!((cpu.pc(), cpu.fregister(FRegister::fa4())), (pc, 0));
+        execute(&mut cpu, "fadd.s fa4, fa1, fa1, rtz").unwrap();
+        execute(&mut cpu, "fmin.s fa4, fa4, fa1").unwrap();
+        assert_eq!(cpu.fregister(FRegister::fa4()), boxed(1.0));
+    }
+
+    #[test]
+    fn test_reserved_rounding_mode() {
+        let mut cpu = Cpu::new(Xlen::Rv32, FlatMemory::new(0, 0));
+        cpu.set_fregister(FRegister::fa1(), boxed(1.0));
+        cpu.set_fregister(FRegister::fa2(), boxed(1.0));
+        // fadd.s fa0, fa1, fa2 with rm 5 and 6, fcvt.wu.s and fmadd.s with rm 5
+        for bits in [0x00c5d553, 0x00c5e553, 0xc015d553, 0x68c5d543] {
+            let instr = Instruction::decode(bits, Xlen::Rv32).unwrap();
+            assert!(instr.has_rounding_mode());
+            assert_eq!(instr.rounding_mode(), None);
+            assert_eq!(cpu.execute(&instr), Err(Trap::IllegalInstruction(bits)));
+        }
+        assert_eq!((cpu.pc(), cpu.fregister(FRegister::fa0())), (0, 0));
+        assert_eq!(cpu.register(Register::a0()), 0);
+        assert!(cpu.fflags().is_empty());
+    }
+
+    #[test]
+    fn test_rv32_double() {
+        let source = "
+            li t0, -7
+            fcvt.d.w fa0, t0
+            fsd fa0, 0(a0)
+            lw a1, 0(a0)
+            lw a2, 4(a0)
+            fcvt.wu.d a3, fa0
+            ecall
+        ";
+        let (cpu, trap) = run(Xlen::Rv32, DATA, source);
+        assert!(matches!(trap, Trap::EnvironmentCall(_)), "{:?}", trap);
+        let bits = (-7f64).to_bits();
+        assert_eq!(cpu.register(Register::a1()), bits & 0xffff_ffff);
+        assert_eq!(cpu.register(Register::a2()), bits >> 32);
+        assert_eq!(cpu.register(Register::a3()), 0);
+        assert_eq!(cpu.fflags(), FFlags::NV);
+    }
+}
@@ -0,0 +1,112 @@
+use thiserror::Error;
+
+use crate::disasm::Address;
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryError {
+    #[error("Address not mapped: 0x{0:x}")]
+    Unmapped(Address),
+
+    #[error("Address is read-only: 0x{0:x}")]
+    ReadOnly(Address),
+}
+
+/// Memory seen by the [`Cpu`](super::Cpu). Implementations decide what's mapped
+/// where, so they can back RAM, ROM or memory-mapped devices. Accesses are
+/// little-endian and never straddle an alignment boundary larger than their size,
+/// as misaligned accesses trap before reaching the memory.
+pub trait Memory {
+    /// Reads `buf.len()` bytes starting at the address.
+    fn read(&mut self, address: Address, buf: &mut [u8]) -> Result<(), MemoryError>;
+
+    /// Writes the bytes starting at the address.
+    fn write(&mut self, address: Address, data: &[u8]) -> Result<(), MemoryError>;
+}
+
+/// A contiguous block of RAM at a base address.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlatMemory {
+    base: Address,
+    bytes: Vec<u8>,
+}
+
+impl FlatMemory {
+    /// Creates zeroed memory of the size (in bytes) at the base address.
+    pub fn new(base: Address, size: usize) -> Self {
+        Self {
+            base,
+            bytes: vec![0; size],
+        }
+    }
+
+    /// Copies the data to the memory at the address (e.g. to load a program).
+    pub fn with_contents(mut self, address: Address, data: &[u8]) -> Result<Self, MemoryError> {
+        self.write(address, data)?;
+        Ok(self)
+    }
+
+    pub fn base(&self) -> Address {
+        self.base
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the range of `bytes` holding `len` bytes at the address.
+    fn range(&self, address: Address, len: usize) -> Result<std::ops::Range<usize>, MemoryError> {
+        let unmapped = MemoryError::Unmapped(address);
+        let start = usize::try_from(address.checked_sub(self.base).ok_or(unmapped)?)
+            .map_err(|_| unmapped)?;
+        let end = start.checked_add(len).ok_or(unmapped)?;
+        if end > self.bytes.len() {
+            return Err(unmapped);
+        }
+        Ok(start..end)
+    }
+}
+
+impl Memory for FlatMemory {
+    fn read(&mut self, address: Address, buf: &mut [u8]) -> Result<(), MemoryError> {
+        let range = self.range(address, buf.len())?;
+        buf.copy_from_slice(&self.bytes[range]);
+        Ok(())
+    }
+
+    fn write(&mut self, address: Address, data: &[u8]) -> Result<(), MemoryError> {
+        let range = self.range(address, data.len())?;
+        self.bytes[range].copy_from_slice(data);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_memory() {
+        let mut memory = FlatMemory::new(0x1000, 16)
+            .with_contents(0x1004, &[1, 2, 3, 4])
+            .unwrap();
+        let mut buf = [0; 4];
+        memory.read(0x1003, &mut buf).unwrap();
+        assert_eq!(buf, [0, 1, 2, 3]);
+
+        memory.write(0x100c, &[0xff; 4]).unwrap();
+        assert_eq!(&memory.bytes()[12..], &[0xff; 4]);
+
+        assert_eq!(
+            memory.read(0xfff, &mut buf),
+            Err(MemoryError::Unmapped(0xfff))
+        );
+        assert_eq!(
+            memory.write(0x100d, &[0; 4]),
+            Err(MemoryError::Unmapped(0x100d))
+        );
+        assert_eq!(
+            memory.read(Address::MAX, &mut buf),
+            Err(MemoryError::Unmapped(Address::MAX))
+        );
+    }
+}
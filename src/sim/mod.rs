@@ -0,0 +1,17 @@
+mod atomic;
+mod cpu;
+mod csr;
+mod execute;
+mod fflags;
+mod float;
+mod memory;
+mod muldiv;
+mod softfloat;
+#[cfg(test)]
+mod test_utils;
+mod trap;
+
+pub use cpu::*;
+pub use fflags::*;
+pub use memory::*;
+pub use trap::*;
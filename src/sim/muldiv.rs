@@ -0,0 +1,143 @@
+use super::{Cpu, Memory};
+
+impl<M: Memory> Cpu<M> {
+    /// Computes the result of an M extension instruction on XLEN-bit values.
+    ///
+    /// Division never traps: dividing by zero gives all ones as the quotient and the
+    /// dividend as the remainder, and the overflow of the most negative number divided
+    /// by -1 gives the dividend as the quotient and zero as the remainder.
+    pub(super) fn muldiv(&self, name: &str, a: u64, b: u64) -> Option<u64> {
+        let bits = self.xlen().bits();
+        let (sa, sb) = (self.signed(a), self.signed(b));
+        let value = match name {
+            "mul" => a.wrapping_mul(b),
+            "mulh" => ((i128::from(sa) * i128::from(sb)) >> bits) as u64,
+            "mulhsu" => ((i128::from(sa) * i128::from(b)) >> bits) as u64,
+            "mulhu" => ((u128::from(a) * u128::from(b)) >> bits) as u64,
+            "div" if b == 0 => u64::MAX,
+            "div" => sa.wrapping_div(sb) as u64,
+            "divu" => a.checked_div(b).unwrap_or(u64::MAX),
+            "rem" if b == 0 => a,
+            "rem" => sa.wrapping_rem(sb) as u64,
+            "remu" => a.checked_rem(b).unwrap_or(a),
+            _ => return None,
+        };
+        Some(self.wrap(value))
+    }
+}
+
+/// Computes the result of an RV64 M extension operation on words (e.g. `divw`),
+/// sign-extended from 32 bits.
+pub(super) fn muldiv_word(name: &str, a: u64, b: u64) -> Option<u64> {
+    let (a, b) = (a as u32, b as u32);
+    let (sa, sb) = (a as i32, b as i32);
+    let value = match name {
+        "mulw" => a.wrapping_mul(b),
+        "divw" if b == 0 => u32::MAX,
+        "divw" => sa.wrapping_div(sb) as u32,
+        "divuw" => a.checked_div(b).unwrap_or(u32::MAX),
+        "remw" if b == 0 => a,
+        "remw" => sa.wrapping_rem(sb) as u32,
+        "remuw" => a.checked_rem(b).unwrap_or(a),
+        _ => return None,
+    };
+    Some(value as i32 as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asm::AsmParser;
+    use crate::model::{Register, Xlen};
+    use crate::sim::{FlatMemory, Trap};
+
+    fn cpu(xlen: Xlen) -> Cpu<FlatMemory> {
+        Cpu::new(xlen, FlatMemory::new(0, 0))
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let rv32 = cpu(Xlen::Rv32);
+        let minus_two = 0xffff_fffe;
+        assert_eq!(rv32.muldiv("mul", minus_two, 3), Some(0xffff_fffa));
+        assert_eq!(rv32.muldiv("mulh", minus_two, 3), Some(0xffff_ffff));
+        assert_eq!(rv32.muldiv("mulhu", minus_two, 3), Some(2));
+        assert_eq!(rv32.muldiv("mulhsu", minus_two, 3), Some(0xffff_ffff));
+        assert_eq!(rv32.muldiv("mulhsu", 3, minus_two), Some(2));
+
+        let rv64 = cpu(Xlen::Rv64);
+        assert_eq!(rv64.muldiv("mulh", u64::MAX, u64::MAX), Some(0));
+        assert_eq!(rv64.muldiv("mulhu", u64::MAX, u64::MAX), Some(u64::MAX - 1));
+        assert_eq!(rv64.muldiv("mulhsu", u64::MAX, 2), Some(u64::MAX));
+        assert_eq!(rv64.muldiv("andn", 1, 2), None);
+
+        assert_eq!(
+            muldiv_word("mulw", 0x1_0000_0003, 0xffff_ffff),
+            Some(-3i64 as u64)
+        );
+    }
+
+    #[test]
+    fn test_division() {
+        for (xlen, min) in [(Xlen::Rv32, 0x8000_0000), (Xlen::Rv64, 1 << 63)] {
+            let cpu = cpu(xlen);
+            let minus_one = cpu.wrap(u64::MAX);
+            let minus_seven = cpu.wrap(-7i64 as u64);
+
+            assert_eq!(
+                cpu.muldiv("div", minus_seven, 2),
+                Some(cpu.wrap(-3i64 as u64))
+            );
+            assert_eq!(cpu.muldiv("rem", minus_seven, 2), Some(minus_one));
+            assert_eq!(cpu.muldiv("divu", 7, 2), Some(3));
+            assert_eq!(cpu.muldiv("remu", 7, 2), Some(1));
+
+            // division by zero
+            assert_eq!(cpu.muldiv("div", minus_seven, 0), Some(minus_one));
+            assert_eq!(cpu.muldiv("divu", 7, 0), Some(minus_one));
+            assert_eq!(cpu.muldiv("rem", minus_seven, 0), Some(minus_seven));
+            assert_eq!(cpu.muldiv("remu", 7, 0), Some(7));
+
+            // overflow
+            assert_eq!(cpu.muldiv("div", min, minus_one), Some(min));
+            assert_eq!(cpu.muldiv("rem", min, minus_one), Some(0));
+        }
+
+        let min = 0xffff_ffff_8000_0000;
+        assert_eq!(muldiv_word("divw", 0x8000_0000, u64::MAX), Some(min));
+        assert_eq!(muldiv_word("remw", 0x8000_0000, u64::MAX), Some(0));
+        assert_eq!(muldiv_word("divw", 7, 0), Some(u64::MAX));
+        assert_eq!(muldiv_word("divuw", 7, 0x1_0000_0000), Some(u64::MAX));
+        assert_eq!(muldiv_word("remw", 0x8000_0000, 0), Some(min));
+        assert_eq!(muldiv_word("remuw", 0x1_0000_0007, 0), Some(7));
+        assert_eq!(muldiv_word("divuw", 0xffff_fffe, 1), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_execute() {
+        let mut cpu = cpu(Xlen::Rv64);
+        cpu.set_register(Register::a1(), 7);
+        for (line, expected) in [
+            ("div a0, a1, zero", u64::MAX),
+            ("remw a0, a1, zero", 7),
+            ("mulw a0, a1, a1", 49),
+        ] {
+            let instr = AsmParser::new(Xlen::Rv64)
+                .parse_line(line)
+                .unwrap()
+                .unwrap();
+            cpu.execute(&instr).unwrap();
+            assert_eq!(cpu.register(Register::a0()), expected, "{}", line);
+        }
+
+        // Zbb isn't supported
+        let andn = AsmParser::new(Xlen::Rv64)
+            .parse_line("andn a0, a1, a1")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            cpu.execute(&andn),
+            Err(Trap::IllegalInstruction(u32::from(andn)))
+        );
+    }
+}
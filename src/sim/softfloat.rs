@@ -0,0 +1,880 @@
+use std::cmp::Ordering;
+
+use super::FFlags;
+use crate::model::RoundingMode;
+
+/// An IEEE 754 binary interchange format, with operations on values of it held in
+/// the lowest bits of `u64`. Results are correctly rounded in the given rounding mode
+/// (which has to be resolved from `frm` by the caller), and follow the RISC-V rules:
+/// NaN results are always the canonical NaN, and tininess is detected after rounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Format {
+    exp_bits: u32,
+    frac_bits: u32,
+}
+
+/// A value unpacked from its encoding. Finite values are `sig * 2^exp`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Class {
+    Zero,
+    Finite { exp: i32, sig: u64 },
+    Infinite,
+    Nan,
+}
+
+impl Format {
+    pub const HALF: Format = Format {
+        exp_bits: 5,
+        frac_bits: 10,
+    };
+    pub const SINGLE: Format = Format {
+        exp_bits: 8,
+        frac_bits: 23,
+    };
+    pub const DOUBLE: Format = Format {
+        exp_bits: 11,
+        frac_bits: 52,
+    };
+
+    /// Returns the size of values in bytes.
+    pub fn width(&self) -> u8 {
+        ((1 + self.exp_bits + self.frac_bits) / 8) as u8
+    }
+
+    /// Returns the mask of the bits of a value.
+    pub fn mask(&self) -> u64 {
+        u64::MAX >> (64 - 8 * u32::from(self.width()))
+    }
+
+    fn sign_bit(&self) -> u64 {
+        1 << (self.exp_bits + self.frac_bits)
+    }
+
+    fn max_exp(&self) -> u64 {
+        (1 << self.exp_bits) - 1
+    }
+
+    fn bias(&self) -> i32 {
+        (1 << (self.exp_bits - 1)) - 1
+    }
+
+    fn quiet_bit(&self) -> u64 {
+        1 << (self.frac_bits - 1)
+    }
+
+    pub fn canonical_nan(&self) -> u64 {
+        self.max_exp() << self.frac_bits | self.quiet_bit()
+    }
+
+    fn infinity(&self, sign: bool) -> u64 {
+        self.zero(sign) | self.max_exp() << self.frac_bits
+    }
+
+    fn zero(&self, sign: bool) -> u64 {
+        if sign {
+            self.sign_bit()
+        } else {
+            0
+        }
+    }
+
+    fn max_finite(&self, sign: bool) -> u64 {
+        self.infinity(sign) - 1
+    }
+
+    pub fn negate(&self, a: u64) -> u64 {
+        a ^ self.sign_bit()
+    }
+
+    pub fn sign(&self, a: u64) -> bool {
+        a & self.sign_bit() != 0
+    }
+
+    /// Replaces the sign of the value (even a NaN).
+    pub fn with_sign(&self, a: u64, sign: bool) -> u64 {
+        a & !self.sign_bit() | self.zero(sign)
+    }
+
+    fn is_nan(&self, a: u64) -> bool {
+        self.unpack(a).1 == Class::Nan
+    }
+
+    fn is_signaling(&self, a: u64) -> bool {
+        self.is_nan(a) && a & self.quiet_bit() == 0
+    }
+
+    fn unpack(&self, a: u64) -> (bool, Class) {
+        let exp = (a >> self.frac_bits) & self.max_exp();
+        let frac = a & (self.quiet_bit() << 1).wrapping_sub(1);
+        let class = match (exp, frac) {
+            (0, 0) => Class::Zero,
+            (0, _) => Class::Finite {
+                exp: 1 - self.bias() - self.frac_bits as i32,
+                sig: frac,
+            },
+            (e, 0) if e == self.max_exp() => Class::Infinite,
+            (e, _) if e == self.max_exp() => Class::Nan,
+            (e, _) => Class::Finite {
+                exp: e as i32 - self.bias() - self.frac_bits as i32,
+                sig: frac | 1 << self.frac_bits,
+            },
+        };
+        (self.sign(a), class)
+    }
+
+    /// Returns the canonical NaN, raising the invalid flag if an operand is a
+    /// signaling NaN.
+    fn propagate_nan(&self, operands: &[u64], flags: &mut FFlags) -> u64 {
+        if operands.iter().any(|a| self.is_signaling(*a)) {
+            *flags |= FFlags::NV;
+        }
+        self.canonical_nan()
+    }
+
+    fn invalid(&self, flags: &mut FFlags) -> u64 {
+        *flags |= FFlags::NV;
+        self.canonical_nan()
+    }
+
+    /// Rounds the non-negative value `sig * 2^exp` to the format. When bits were
+    /// discarded while computing it, the lowest bit of `sig` has to be set (jammed),
+    /// and `sig` has to have at least two bits more than the precision.
+    fn round_pack(
+        &self,
+        sign: bool,
+        exp: i32,
+        sig: u128,
+        rm: RoundingMode,
+        flags: &mut FFlags,
+    ) -> u64 {
+        if sig == 0 {
+            return self.zero(sign);
+        }
+        let p = self.frac_bits as i32;
+        let emin = 1 - self.bias();
+        // exponent of the leading bit, and of the last bit which fits in the result
+        let e = exp + 127 - sig.leading_zeros() as i32;
+        let mut ulp = e.max(emin) - p;
+
+        let (mut kept, inexact) = round(sig, ulp - exp, sign, rm);
+        if kept >> (p + 1) != 0 {
+            kept >>= 1;
+            ulp += 1;
+        }
+        if inexact {
+            *flags |= FFlags::NX;
+            // tiny if the result rounded with an unbounded exponent is below 2^emin
+            if e < emin - 1
+                || (e == emin - 1 && round(sig, e - p - exp, sign, rm).0 >> (p + 1) == 0)
+            {
+                *flags |= FFlags::UF;
+            }
+        }
+
+        let biased = if kept >> p != 0 {
+            ulp + p + self.bias()
+        } else {
+            0
+        };
+        if biased >= self.max_exp() as i32 {
+            *flags |= FFlags::OF | FFlags::NX;
+            let to_infinity = match rm {
+                RoundingMode::Rtz => false,
+                RoundingMode::Rdn => sign,
+                RoundingMode::Rup => !sign,
+                _ => true,
+            };
+            return if to_infinity {
+                self.infinity(sign)
+            } else {
+                self.max_finite(sign)
+            };
+        }
+        self.zero(sign)
+            | (biased as u64) << self.frac_bits
+            | (kept as u64 & (self.quiet_bit() * 2 - 1))
+    }
+
+    pub fn add(&self, a: u64, b: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        let ((sa, ca), (sb, cb)) = (self.unpack(a), self.unpack(b));
+        match (ca, cb) {
+            (Class::Nan, _) | (_, Class::Nan) => self.propagate_nan(&[a, b], flags),
+            (Class::Infinite, Class::Infinite) if sa != sb => self.invalid(flags),
+            (Class::Infinite, _) => a,
+            (_, Class::Infinite) => b,
+            (Class::Zero, Class::Zero) if sa != sb => self.zero(rm == RoundingMode::Rdn),
+            (Class::Zero, _) => b,
+            (_, Class::Zero) => a,
+            (Class::Finite { exp: ea, sig: ga }, Class::Finite { exp: eb, sig: gb }) => self
+                .add_finite(
+                    (sa, ea, u128::from(ga)),
+                    (sb, eb, u128::from(gb)),
+                    rm,
+                    flags,
+                ),
+        }
+    }
+
+    pub fn sub(&self, a: u64, b: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        self.add(a, self.negate(b), rm, flags)
+    }
+
+    /// Adds non-zero finite values given as `(sign, exp, sig)`.
+    fn add_finite(
+        &self,
+        a: (bool, i32, u128),
+        b: (bool, i32, u128),
+        rm: RoundingMode,
+        flags: &mut FFlags,
+    ) -> u64 {
+        // leading bits at bit 125, leaving room for the carry
+        let normalize = |(sign, exp, sig): (bool, i32, u128)| {
+            let shift = sig.leading_zeros() as i32 - 2;
+            (sign, exp - shift, sig << shift)
+        };
+        let (a, b) = (normalize(a), normalize(b));
+        let ((sx, ex, gx), (sy, ey, gy)) = if (a.1, a.2) >= (b.1, b.2) {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let gy = shift_right_jam(gy, ex - ey);
+        let sig = if sx == sy { gx + gy } else { gx - gy };
+        if sig == 0 {
+            return self.zero(rm == RoundingMode::Rdn);
+        }
+        self.round_pack(sx, ex, sig, rm, flags)
+    }
+
+    pub fn mul(&self, a: u64, b: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        let ((sa, ca), (sb, cb)) = (self.unpack(a), self.unpack(b));
+        let sign = sa != sb;
+        match (ca, cb) {
+            (Class::Nan, _) | (_, Class::Nan) => self.propagate_nan(&[a, b], flags),
+            (Class::Infinite, Class::Zero) | (Class::Zero, Class::Infinite) => self.invalid(flags),
+            (Class::Infinite, _) | (_, Class::Infinite) => self.infinity(sign),
+            (Class::Zero, _) | (_, Class::Zero) => self.zero(sign),
+            (Class::Finite { exp: ea, sig: ga }, Class::Finite { exp: eb, sig: gb }) => {
+                let sig = u128::from(ga) * u128::from(gb);
+                self.round_pack(sign, ea + eb, sig, rm, flags)
+            }
+        }
+    }
+
+    pub fn div(&self, a: u64, b: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        let ((sa, ca), (sb, cb)) = (self.unpack(a), self.unpack(b));
+        let sign = sa != sb;
+        match (ca, cb) {
+            (Class::Nan, _) | (_, Class::Nan) => self.propagate_nan(&[a, b], flags),
+            (Class::Infinite, Class::Infinite) | (Class::Zero, Class::Zero) => self.invalid(flags),
+            (Class::Infinite, _) => self.infinity(sign),
+            (_, Class::Infinite) => self.zero(sign),
+            (_, Class::Zero) => {
+                *flags |= FFlags::DZ;
+                self.infinity(sign)
+            }
+            (Class::Zero, _) => self.zero(sign),
+            (Class::Finite { exp: ea, sig: ga }, Class::Finite { exp: eb, sig: gb }) => {
+                // the dividend scaled up, so the quotient has more than 64 bits
+                let shift = ga.leading_zeros() as i32 + 62;
+                let dividend = u128::from(ga) << shift;
+                let (quotient, remainder) = (dividend / u128::from(gb), dividend % u128::from(gb));
+                let sig = quotient | u128::from(remainder != 0);
+                self.round_pack(sign, ea - eb - shift, sig, rm, flags)
+            }
+        }
+    }
+
+    pub fn sqrt(&self, a: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        match self.unpack(a) {
+            (_, Class::Nan) => self.propagate_nan(&[a], flags),
+            (_, Class::Zero) => a,
+            (true, _) => self.invalid(flags),
+            (false, Class::Infinite) => a,
+            (false, Class::Finite { exp, sig }) => {
+                // an even exponent, and the significand scaled up to about 125 bits
+                let mut shift = sig.leading_zeros() as i32 + 60;
+                if (exp - shift) % 2 != 0 {
+                    shift += 1;
+                }
+                let (root, inexact) = isqrt(u128::from(sig) << shift);
+                let sig = root | u128::from(inexact);
+                self.round_pack(false, (exp - shift) / 2, sig, rm, flags)
+            }
+        }
+    }
+
+    /// Computes `a * b + c` with a single rounding.
+    pub fn fma(&self, a: u64, b: u64, c: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        let ((sa, ca), (sb, cb), (sc, cc)) = (self.unpack(a), self.unpack(b), self.unpack(c));
+        let sign = sa != sb;
+        match (ca, cb, cc) {
+            (Class::Infinite, Class::Zero, _) | (Class::Zero, Class::Infinite, _) => {
+                self.invalid(flags)
+            }
+            (Class::Nan, _, _) | (_, Class::Nan, _) | (_, _, Class::Nan) => {
+                self.propagate_nan(&[a, b, c], flags)
+            }
+            (Class::Infinite, _, Class::Infinite) | (_, Class::Infinite, Class::Infinite)
+                if sign != sc =>
+            {
+                self.invalid(flags)
+            }
+            (Class::Infinite, _, _) | (_, Class::Infinite, _) => self.infinity(sign),
+            (_, _, Class::Infinite) => c,
+            (Class::Zero, _, Class::Zero) | (_, Class::Zero, Class::Zero) if sign != sc => {
+                self.zero(rm == RoundingMode::Rdn)
+            }
+            (Class::Zero, _, _) | (_, Class::Zero, _) => c,
+            (Class::Finite { exp: ea, sig: ga }, Class::Finite { exp: eb, sig: gb }, cc) => {
+                let product = (sign, ea + eb, u128::from(ga) * u128::from(gb));
+                match cc {
+                    Class::Finite { exp, sig } => {
+                        self.add_finite(product, (sc, exp, u128::from(sig)), rm, flags)
+                    }
+                    _ => self.round_pack(product.0, product.1, product.2, rm, flags),
+                }
+            }
+        }
+    }
+
+    /// Orders values which aren't NaNs, with -0 equal to +0.
+    fn compare(&self, a: u64, b: u64) -> Ordering {
+        let key = |x: u64| {
+            let magnitude = i128::from(x & !self.sign_bit());
+            if self.sign(x) {
+                -magnitude
+            } else {
+                magnitude
+            }
+        };
+        key(a).cmp(&key(b))
+    }
+
+    /// Checks the equality quietly: only signaling NaNs are invalid.
+    pub fn eq(&self, a: u64, b: u64, flags: &mut FFlags) -> bool {
+        if self.is_nan(a) || self.is_nan(b) {
+            self.propagate_nan(&[a, b], flags);
+            return false;
+        }
+        self.compare(a, b) == Ordering::Equal
+    }
+
+    /// Compares the values with a signaling comparison: any NaN is invalid.
+    pub fn lt(&self, a: u64, b: u64, flags: &mut FFlags) -> bool {
+        if self.is_nan(a) || self.is_nan(b) {
+            *flags |= FFlags::NV;
+            return false;
+        }
+        self.compare(a, b) == Ordering::Less
+    }
+
+    pub fn le(&self, a: u64, b: u64, flags: &mut FFlags) -> bool {
+        if self.is_nan(a) || self.is_nan(b) {
+            *flags |= FFlags::NV;
+            return false;
+        }
+        self.compare(a, b) != Ordering::Greater
+    }
+
+    /// Returns the smaller value, or the larger if `max`. -0 is smaller than +0, and
+    /// a NaN is only returned (canonical) if both values are NaNs.
+    pub fn min_max(&self, a: u64, b: u64, max: bool, flags: &mut FFlags) -> u64 {
+        match (self.is_nan(a), self.is_nan(b)) {
+            (true, true) => self.propagate_nan(&[a, b], flags),
+            (true, false) => {
+                self.propagate_nan(&[a], flags);
+                b
+            }
+            (false, true) => {
+                self.propagate_nan(&[b], flags);
+                a
+            }
+            _ => {
+                let ordering = match self.compare(a, b) {
+                    // -0 and +0
+                    Ordering::Equal => self.sign(b).cmp(&self.sign(a)),
+                    ordering => ordering,
+                };
+                if (ordering == Ordering::Less) != max {
+                    a
+                } else {
+                    b
+                }
+            }
+        }
+    }
+
+    /// Returns the mask of `fclass`, with one of the 10 lowest bits set.
+    pub fn classify(&self, a: u64) -> u64 {
+        let sign = self.sign(a);
+        let exp = (a >> self.frac_bits) & self.max_exp();
+        let bit = match self.unpack(a).1 {
+            Class::Infinite => 7,
+            Class::Finite { .. } if exp != 0 => 6,
+            Class::Finite { .. } => 5,
+            Class::Zero => 4,
+            Class::Nan if self.is_signaling(a) => return 1 << 8,
+            Class::Nan => return 1 << 9,
+        };
+        1 << if sign { 7 - bit } else { bit }
+    }
+
+    /// Converts the value to an integer of `bits` bits, returned sign-extended (or
+    /// zero-extended if unsigned) to 64 bits. NaNs and values out of range are invalid,
+    /// and give the largest integer (the smallest for negative values).
+    pub fn convert_to_int(
+        &self,
+        a: u64,
+        signed: bool,
+        bits: u32,
+        rm: RoundingMode,
+        flags: &mut FFlags,
+    ) -> u64 {
+        let (min, max) = if signed {
+            (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+        } else {
+            (0, (1i128 << bits) - 1)
+        };
+        let (sign, class) = self.unpack(a);
+        let value = match class {
+            Class::Nan => None,
+            Class::Infinite => None,
+            Class::Zero => Some(0),
+            Class::Finite { exp, .. } if exp > 64 => None,
+            Class::Finite { exp, sig } => {
+                let (magnitude, inexact) = round(u128::from(sig), -exp, sign, rm);
+                let value = if sign {
+                    -(magnitude as i128)
+                } else {
+                    magnitude as i128
+                };
+                Some((value, inexact))
+                    .filter(|(value, _)| (min..=max).contains(value))
+                    .map(|(value, inexact)| {
+                        if inexact {
+                            *flags |= FFlags::NX;
+                        }
+                        value
+                    })
+            }
+        };
+        let value = value.unwrap_or_else(|| {
+            *flags |= FFlags::NV;
+            if sign && class != Class::Nan {
+                min
+            } else {
+                max
+            }
+        });
+        value as u64
+    }
+
+    /// Converts an integer of `bits` bits (the lowest bits of the value) to the format.
+    pub fn convert_from_int(
+        &self,
+        value: u64,
+        signed: bool,
+        bits: u32,
+        rm: RoundingMode,
+        flags: &mut FFlags,
+    ) -> u64 {
+        let shift = 64 - bits;
+        let (sign, magnitude) = if signed {
+            let value = (value << shift) as i64 >> shift;
+            (value < 0, value.unsigned_abs())
+        } else {
+            (false, value << shift >> shift)
+        };
+        self.round_pack(sign, 0, u128::from(magnitude), rm, flags)
+    }
+
+    /// Converts a value of another format to this one.
+    pub fn convert(&self, from: Format, a: u64, rm: RoundingMode, flags: &mut FFlags) -> u64 {
+        match from.unpack(a) {
+            (_, Class::Nan) => {
+                from.propagate_nan(&[a], flags);
+                self.canonical_nan()
+            }
+            (sign, Class::Infinite) => self.infinity(sign),
+            (sign, Class::Zero) => self.zero(sign),
+            (sign, Class::Finite { exp, sig }) => {
+                self.round_pack(sign, exp, u128::from(sig), rm, flags)
+            }
+        }
+    }
+}
+
+/// Shifts the significand right by `shift` bits (left if negative), returning it
+/// rounded to an integer and whether any discarded bit was set.
+fn round(sig: u128, shift: i32, sign: bool, rm: RoundingMode) -> (u128, bool) {
+    if shift <= 0 {
+        return (sig << -shift, false);
+    }
+    let (kept, rest) = if shift >= 128 {
+        // the significands never reach bit 127, so the rest is below half
+        (0, Ordering::Less)
+    } else {
+        let rest = sig & ((1 << shift) - 1);
+        (sig >> shift, rest.cmp(&(1 << (shift - 1))))
+    };
+    let inexact = if shift >= 128 {
+        sig != 0
+    } else {
+        sig & ((1 << shift) - 1) != 0
+    };
+    let up = inexact
+        && match rm {
+            RoundingMode::Rtz => false,
+            RoundingMode::Rdn => sign,
+            RoundingMode::Rup => !sign,
+            RoundingMode::Rmm => rest != Ordering::Less,
+            // RNE (the dynamic mode is resolved by the caller)
+            _ => rest == Ordering::Greater || (rest == Ordering::Equal && kept & 1 == 1),
+        };
+    (kept + u128::from(up), inexact)
+}
+
+/// Shifts right, setting the lowest bit if any discarded bit was set.
+fn shift_right_jam(sig: u128, shift: i32) -> u128 {
+    match shift {
+        0 => sig,
+        1..=127 => sig >> shift | u128::from(sig & ((1 << shift) - 1) != 0),
+        _ => u128::from(sig != 0),
+    }
+}
+
+/// Returns the integer square root and whether it's inexact.
+fn isqrt(n: u128) -> (u128, bool) {
+    let mut rest = n;
+    let mut root = 0;
+    let mut bit = 1u128 << 126;
+    while bit > rest {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rest >= root + bit {
+            rest -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+    (root, rest != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use RoundingMode::*;
+
+    const S: Format = Format::SINGLE;
+    const D: Format = Format::DOUBLE;
+    const H: Format = Format::HALF;
+
+    /// Returns the bits of a native value, where NaNs are canonical as in RISC-V.
+    fn single(f: f32) -> u64 {
+        if f.is_nan() {
+            S.canonical_nan()
+        } else {
+            u64::from(f.to_bits())
+        }
+    }
+
+    fn double(f: f64) -> u64 {
+        if f.is_nan() {
+            D.canonical_nan()
+        } else {
+            f.to_bits()
+        }
+    }
+
+    /// Runs the operation, returning the result and the raised flags.
+    fn with_flags(op: impl FnOnce(&mut FFlags) -> u64) -> (u64, FFlags) {
+        let mut flags = FFlags::default();
+        let result = op(&mut flags);
+        (result, flags)
+    }
+
+    #[test]
+    fn test_matches_native_rne() {
+        let values = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.5,
+            0.1,
+            3.0,
+            1e-40,
+            -7e-39,
+            3.4e38,
+            1e-45,
+            123.456,
+            -0.3,
+            f32::MAX,
+            f32::MIN_POSITIVE,
+        ];
+        for a in values {
+            for b in values {
+                let mut flags = FFlags::default();
+                assert_eq!(S.add(single(a), single(b), Rne, &mut flags), single(a + b));
+                assert_eq!(S.sub(single(a), single(b), Rne, &mut flags), single(a - b));
+                assert_eq!(S.mul(single(a), single(b), Rne, &mut flags), single(a * b));
+                if b != 0.0 {
+                    assert_eq!(
+                        S.div(single(a), single(b), Rne, &mut flags),
+                        single(a / b),
+                        "{} / {}",
+                        a,
+                        b
+                    );
+                }
+                let (da, db) = (f64::from(a) * 1e300, f64::from(b) * 1e-10);
+                assert_eq!(
+                    D.mul(double(da), double(db), Rne, &mut flags),
+                    double(da * db)
+                );
+                assert_eq!(
+                    D.add(double(da), double(db), Rne, &mut flags),
+                    double(da + db)
+                );
+                for c in [0.5f64, -1e-300, 1e300] {
+                    assert_eq!(
+                        D.fma(double(da), double(db), double(c), Rne, &mut flags),
+                        double(da.mul_add(db, c)),
+                    );
+                }
+            }
+            if a >= 0.0 {
+                let mut flags = FFlags::default();
+                assert_eq!(S.sqrt(single(a), Rne, &mut flags), single(a.sqrt()));
+                let a = f64::from(a) * 3.0;
+                assert_eq!(D.sqrt(double(a), Rne, &mut flags), double(a.sqrt()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        let (one, three) = (single(1.0), single(3.0));
+        let third = |rm| with_flags(|flags| S.div(one, three, rm, flags));
+        assert_eq!(third(Rne), (0x3eaaaaab, FFlags::NX));
+        assert_eq!(third(Rtz), (0x3eaaaaaa, FFlags::NX));
+        assert_eq!(third(Rdn), (0x3eaaaaaa, FFlags::NX));
+        assert_eq!(third(Rup), (0x3eaaaaab, FFlags::NX));
+        assert_eq!(third(Rmm), (0x3eaaaaab, FFlags::NX));
+        let minus_third = |rm| with_flags(|flags| S.div(S.negate(one), three, rm, flags)).0;
+        assert_eq!(minus_third(Rdn), 0xbeaaaaab);
+        assert_eq!(minus_third(Rup), 0xbeaaaaaa);
+
+        // 1 + 2^-24 is a tie between 1 and the next value
+        let tie = |rm| with_flags(|flags| S.add(one, single(f32::EPSILON / 2.0), rm, flags)).0;
+        assert_eq!(tie(Rne), one);
+        assert_eq!(tie(Rmm), one + 1);
+        let tie = |rm| with_flags(|flags| S.add(one + 1, single(f32::EPSILON / 2.0), rm, flags)).0;
+        assert_eq!(tie(Rne), one + 2);
+
+        // exact cancellation gives -0 only when rounding down
+        assert_eq!(
+            with_flags(|flags| S.sub(one, one, Rne, flags)),
+            (0, FFlags::default())
+        );
+        assert_eq!(
+            with_flags(|flags| S.sub(one, one, Rdn, flags)).0,
+            0x8000_0000
+        );
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let max = single(f32::MAX);
+        assert_eq!(
+            with_flags(|flags| S.mul(max, single(2.0), Rne, flags)),
+            (single(f32::INFINITY), FFlags::OF | FFlags::NX)
+        );
+        assert_eq!(
+            with_flags(|flags| S.mul(max, single(2.0), Rtz, flags)).0,
+            max
+        );
+        assert_eq!(
+            with_flags(|flags| S.mul(S.negate(max), single(2.0), Rup, flags)).0,
+            S.negate(max)
+        );
+        assert_eq!(
+            with_flags(|flags| S.div(single(1.0), 0, Rne, flags)),
+            (single(f32::INFINITY), FFlags::DZ)
+        );
+        assert_eq!(
+            with_flags(|flags| S.div(0, 0, Rne, flags)),
+            (S.canonical_nan(), FFlags::NV)
+        );
+        assert_eq!(
+            with_flags(|flags| S.sqrt(single(-1.0), Rne, flags)),
+            (0x7fc00000, FFlags::NV)
+        );
+        assert_eq!(
+            with_flags(|flags| S.sub(single(f32::INFINITY), single(f32::INFINITY), Rne, flags)),
+            (0x7fc00000, FFlags::NV)
+        );
+
+        // NaNs are canonical, and only signaling ones are invalid
+        let (qnan, snan) = (0xffc00001, 0x7f800001);
+        assert_eq!(
+            with_flags(|flags| S.add(qnan, single(1.0), Rne, flags)),
+            (0x7fc00000, FFlags::default())
+        );
+        assert_eq!(
+            with_flags(|flags| S.add(snan, single(1.0), Rne, flags)),
+            (0x7fc00000, FFlags::NV)
+        );
+        assert_eq!(
+            with_flags(|flags| S.fma(single(f32::INFINITY), 0, qnan, Rne, flags)),
+            (0x7fc00000, FFlags::NV)
+        );
+    }
+
+    #[test]
+    fn test_underflow() {
+        let min = single(f32::MIN_POSITIVE);
+        // exact subnormal results don't underflow
+        assert_eq!(
+            with_flags(|flags| S.mul(min, single(0.5), Rne, flags)),
+            (0x0040_0000, FFlags::default())
+        );
+        assert_eq!(
+            with_flags(|flags| S.mul(single(1e-45), single(0.5), Rne, flags)),
+            (0, FFlags::UF | FFlags::NX)
+        );
+        // tininess is detected after rounding: (2^-126 - 2^-150) rounds up to 2^-126
+        let below_min = S.sub(min, single(1e-45), Rtz, &mut FFlags::default());
+        assert_eq!(
+            with_flags(|flags| S.mul(below_min, single(1.0 + f32::EPSILON), Rne, flags)).1,
+            FFlags::NX
+        );
+        assert_eq!(
+            with_flags(|flags| S.mul(below_min, single(1.0 + f32::EPSILON), Rtz, flags)).1,
+            FFlags::UF | FFlags::NX
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let (qnan, snan) = (0x7fc00000, 0x7f800001);
+        let mut flags = FFlags::default();
+        assert!(S.eq(0, 0x8000_0000, &mut flags));
+        assert!(S.le(single(-1.0), single(-1.0), &mut flags));
+        assert!(S.lt(single(-2.0), single(-1.0), &mut flags));
+        assert!(!S.eq(qnan, qnan, &mut flags));
+        assert!(flags.is_empty());
+        assert!(!S.eq(snan, qnan, &mut flags));
+        assert_eq!(flags, FFlags::NV);
+        assert_eq!(
+            with_flags(|flags| u64::from(S.lt(qnan, 0, flags))).1,
+            FFlags::NV
+        );
+
+        assert_eq!(S.min_max(0, 0x8000_0000, false, &mut flags), 0x8000_0000);
+        assert_eq!(S.min_max(0x8000_0000, 0, true, &mut flags), 0);
+        assert_eq!(
+            with_flags(|flags| S.min_max(qnan, single(2.0), false, flags)),
+            (single(2.0), FFlags::default())
+        );
+        assert_eq!(
+            with_flags(|flags| S.min_max(snan, snan, true, flags)),
+            (qnan, FFlags::NV)
+        );
+    }
+
+    #[test]
+    fn test_classify() {
+        let classes = [
+            (single(f32::NEG_INFINITY), 0),
+            (single(-1.0), 1),
+            (0x8000_0001, 2),
+            (0x8000_0000, 3),
+            (0, 4),
+            (1, 5),
+            (single(1.0), 6),
+            (single(f32::INFINITY), 7),
+            (0x7f80_0001, 8),
+            (0x7fc0_0000, 9),
+        ];
+        for (value, bit) in classes {
+            assert_eq!(S.classify(value), 1 << bit, "0x{:x}", value);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let to_int =
+            |a, signed, bits, rm| with_flags(|flags| S.convert_to_int(a, signed, bits, rm, flags));
+        assert_eq!(
+            to_int(single(-2.5), true, 32, Rne),
+            (-2i64 as u64, FFlags::NX)
+        );
+        assert_eq!(
+            to_int(single(-2.5), true, 32, Rmm),
+            (-3i64 as u64, FFlags::NX)
+        );
+        assert_eq!(to_int(single(2.5), true, 32, Rup), (3, FFlags::NX));
+        assert_eq!(
+            to_int(single(3e9), true, 32, Rne),
+            (0x7fff_ffff, FFlags::NV)
+        );
+        assert_eq!(
+            to_int(single(3e9), false, 32, Rne),
+            (3_000_000_000, FFlags::default())
+        );
+        assert_eq!(
+            to_int(single(-3e9), true, 32, Rne),
+            (-(1i64 << 31) as u64, FFlags::NV)
+        );
+        assert_eq!(to_int(single(-0.3), false, 32, Rtz), (0, FFlags::NX));
+        assert_eq!(to_int(single(-0.7), false, 32, Rne), (0, FFlags::NV));
+        assert_eq!(to_int(0x7fc00000, false, 64, Rne), (u64::MAX, FFlags::NV));
+        assert_eq!(
+            to_int(0xffc00000, true, 64, Rne),
+            (i64::MAX as u64, FFlags::NV)
+        );
+        assert_eq!(
+            to_int(single(f32::NEG_INFINITY), true, 64, Rne),
+            (i64::MIN as u64, FFlags::NV)
+        );
+
+        let from_int = |value, signed, bits| {
+            with_flags(|flags| S.convert_from_int(value, signed, bits, Rne, flags))
+        };
+        assert_eq!(
+            from_int(0xffff_ffff, true, 32),
+            (single(-1.0), FFlags::default())
+        );
+        assert_eq!(
+            from_int(0xffff_ffff, false, 32),
+            (single(4294967296.0), FFlags::NX)
+        );
+        assert_eq!(
+            from_int(u64::MAX, false, 64),
+            (single(1.8446744e19), FFlags::NX)
+        );
+        assert_eq!(from_int(0, true, 64), (0, FFlags::default()));
+
+        let mut flags = FFlags::default();
+        assert_eq!(
+            D.convert(S, single(0.1), Rne, &mut flags),
+            double(f64::from(0.1f32))
+        );
+        assert_eq!(S.convert(D, double(0.1), Rne, &mut flags), single(0.1));
+        assert_eq!(H.convert(S, single(1.0), Rne, &mut flags), 0x3c00);
+        assert_eq!(H.convert(S, single(65520.0), Rne, &mut flags), 0x7c00);
+        assert_eq!(
+            S.convert(H, 0x0001, Rne, &mut flags),
+            single(2f32.powi(-24))
+        );
+        assert_eq!(flags, FFlags::OF | FFlags::NX);
+        assert_eq!(
+            with_flags(|flags| S.convert(D, 0x7ff0_0000_0000_0001, Rne, flags)),
+            (0x7fc00000, FFlags::NV)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use super::{Cpu, FlatMemory, Trap};
+use crate::asm::Assembler;
+use crate::model::{Register, Xlen};
+
+/// Assembles the source at 0x100 and runs it from its entry point until it traps,
+/// with `a0` holding the given value (e.g. the address of the data to work on).
+pub(super) fn run(xlen: Xlen, a0: u64, source: &str) -> (Cpu<FlatMemory>, Trap) {
+    let program = Assembler::new(xlen)
+        .with_text_address(0x100)
+        .assemble(source)
+        .unwrap();
+    let memory = FlatMemory::new(0, 0x1000)
+        .with_contents(0x100, &program.to_bytes())
+        .unwrap();
+    let mut cpu = Cpu::new(xlen, memory).with_pc(program.entry());
+    cpu.set_register(Register::a0(), a0);
+    let trap = cpu.run(1000).expect("the program should trap");
+    (cpu, trap)
+}
@@ -0,0 +1,70 @@
+use thiserror::Error;
+
+use crate::disasm::Address;
+
+/// A synchronous exception raised by an instruction. Traps are precise: the
+/// [`Cpu`](super::Cpu) stops at the trapping instruction, which doesn't change
+/// its state.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum Trap {
+    #[error("Instruction address misaligned: 0x{0:x}")]
+    InstructionAddressMisaligned(Address),
+
+    #[error("Instruction access fault: 0x{0:x}")]
+    InstructionAccessFault(Address),
+
+    #[error("Illegal instruction: 0x{0:08x}")]
+    IllegalInstruction(u32),
+
+    #[error("Breakpoint at 0x{0:x}")]
+    Breakpoint(Address),
+
+    #[error("Load address misaligned: 0x{0:x}")]
+    LoadAddressMisaligned(Address),
+
+    #[error("Load access fault: 0x{0:x}")]
+    LoadAccessFault(Address),
+
+    #[error("Store address misaligned: 0x{0:x}")]
+    StoreAddressMisaligned(Address),
+
+    #[error("Store access fault: 0x{0:x}")]
+    StoreAccessFault(Address),
+
+    #[error("Environment call at 0x{0:x}")]
+    EnvironmentCall(Address),
+}
+
+impl Trap {
+    /// Returns the exception code, as written to `mcause` (environment calls are
+    /// made from user mode).
+    pub fn cause(&self) -> u64 {
+        match self {
+            Trap::InstructionAddressMisaligned(_) => 0,
+            Trap::InstructionAccessFault(_) => 1,
+            Trap::IllegalInstruction(_) => 2,
+            Trap::Breakpoint(_) => 3,
+            Trap::LoadAddressMisaligned(_) => 4,
+            Trap::LoadAccessFault(_) => 5,
+            Trap::StoreAddressMisaligned(_) => 6,
+            Trap::StoreAccessFault(_) => 7,
+            Trap::EnvironmentCall(_) => 8,
+        }
+    }
+
+    /// Returns the value written to `mtval`: the faulting address, the bits of an
+    /// illegal instruction, or zero for environment calls.
+    pub fn value(&self) -> u64 {
+        match self {
+            Trap::IllegalInstruction(bits) => u64::from(*bits),
+            Trap::EnvironmentCall(_) => 0,
+            Trap::InstructionAddressMisaligned(a)
+            | Trap::InstructionAccessFault(a)
+            | Trap::Breakpoint(a)
+            | Trap::LoadAddressMisaligned(a)
+            | Trap::LoadAccessFault(a)
+            | Trap::StoreAddressMisaligned(a)
+            | Trap::StoreAccessFault(a) => *a,
+        }
+    }
+}